@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use ruint::algorithms::LehmerMatrix as Matrix;
+use ruint::algorithms::{LehmerMatrix as Matrix, gcd, gcd_extended};
 
 pub fn group(criterion: &mut Criterion) {
     bench_from_u64(criterion);
@@ -8,6 +8,37 @@ pub fn group(criterion: &mut Criterion) {
         const LIMBS: usize = nlimbs(BITS);
         bench_apply::<BITS, LIMBS>(criterion);
     });
+    const_for!(BITS in [256, 1024, 2048, 4096, 8192] {
+        const LIMBS: usize = nlimbs(BITS);
+        bench_gcd::<BITS, LIMBS>(criterion);
+    });
+}
+
+fn bench_gcd<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    bench_arbitrary_with(
+        criterion,
+        &format!("algorithms/gcd/lehmer/{BITS}"),
+        input::<Uint<BITS, LIMBS>>(),
+        |(a, b)| gcd(a, b),
+    );
+    bench_arbitrary_with(
+        criterion,
+        &format!("algorithms/gcd/euclid/{BITS}"),
+        input::<Uint<BITS, LIMBS>>(),
+        |(mut a, mut b)| {
+            while !b.is_zero() {
+                a %= b;
+                std::mem::swap(&mut a, &mut b);
+            }
+            a
+        },
+    );
+    bench_arbitrary_with(
+        criterion,
+        &format!("algorithms/gcd/lehmer_extended/{BITS}"),
+        input::<Uint<BITS, LIMBS>>(),
+        |(a, b)| gcd_extended(a, b),
+    );
 }
 
 fn bench_from_u64(criterion: &mut Criterion) {
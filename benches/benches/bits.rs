@@ -1,6 +1,11 @@
 use crate::prelude::*;
 
 pub fn group(criterion: &mut Criterion) {
+    // Wide enough for the `portable-simd` feature to matter.
+    const_for!(BITS in [1024, 4096, 16384] {
+        const LIMBS: usize = nlimbs(BITS);
+        bench_bitwise::<BITS, LIMBS>(criterion);
+    });
     const_for!(BITS in BENCH {
         const LIMBS: usize = nlimbs(BITS);
         let name = |n: &str| format!("{n}/{BITS}");
@@ -22,3 +27,12 @@ pub fn group(criterion: &mut Criterion) {
         });
     });
 }
+
+fn bench_bitwise<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    bench_binop::<BITS, LIMBS, _>(criterion, "bitand", |a, b| a & b);
+    bench_binop::<BITS, LIMBS, _>(criterion, "bitor", |a, b| a | b);
+    bench_binop::<BITS, LIMBS, _>(criterion, "bitxor", |a, b| a ^ b);
+    bench_unop::<BITS, LIMBS, _>(criterion, "not", |a| !a);
+    bench_unop::<BITS, LIMBS, _>(criterion, "count_ones", |a| a.count_ones());
+    bench_binop::<BITS, LIMBS, _>(criterion, "const_eq", |a, b| a.const_eq(&b));
+}
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use ruint::nbytes;
+
+pub fn group(criterion: &mut Criterion) {
+    // The compact encoding has a one byte length prefix, which excludes 4096.
+    const_for!(BITS in [64, 128, 192, 256, 384, 512] {
+        const LIMBS: usize = nlimbs(BITS);
+        const BYTES: usize = nbytes(BITS);
+        bench_unop::<BITS, LIMBS, _>(criterion, "bytes/to_be", |a| a.to_be_bytes::<BYTES>());
+        bench_unop::<BITS, LIMBS, _>(criterion, "bytes/to_compact", |a| {
+            a.to_compact_bytes::<{ BYTES + 1 }>()
+        });
+        bench_arbitrary_with(
+            criterion,
+            &format!("bytes/from_be/{BITS}"),
+            Uint::<BITS, LIMBS>::arbitrary().prop_map(|a| a.to_be_bytes::<BYTES>()),
+            Uint::<BITS, LIMBS>::from_be_bytes::<BYTES>,
+        );
+        bench_arbitrary_with(
+            criterion,
+            &format!("bytes/from_compact/{BITS}"),
+            Uint::<BITS, LIMBS>::arbitrary().prop_map(|a| a.to_compact_bytes::<{ BYTES + 1 }>().0),
+            |bytes| Uint::<BITS, LIMBS>::from_compact_bytes(&bytes),
+        );
+    });
+}
@@ -6,6 +6,7 @@ pub fn group(criterion: &mut Criterion) {
         bench_unop::<BITS, LIMBS, _>(criterion, "is_zero", |a| a.is_zero());
         bench_binop::<BITS, LIMBS, _>(criterion, "eq", |a, b| a == b);
         bench_binop::<BITS, LIMBS, _>(criterion, "cmp", |a, b| a.cmp(&b));
+        bench_binop::<BITS, LIMBS, _>(criterion, "cmp_branchless", |a, b| a.cmp_branchless(&b));
         bench_binop::<BITS, LIMBS, _>(criterion, "lt", |a, b| a < b);
         bench_binop::<BITS, LIMBS, _>(criterion, "gt", |a, b| a > b);
         bench_binop::<BITS, LIMBS, _>(criterion, "le", |a, b| a <= b);
@@ -13,4 +14,24 @@ pub fn group(criterion: &mut Criterion) {
         bench_binop::<BITS, LIMBS, _>(criterion, "min", |a, b| a.min(b));
         bench_binop::<BITS, LIMBS, _>(criterion, "max", |a, b| a.max(b));
     });
+    bench_sort::<256, 4>(criterion);
+}
+
+/// Sorts a million values, which is dominated by `Ord::cmp`.
+fn bench_sort<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    let mut runner = TestRunner::deterministic();
+    let strategy = Uint::<BITS, LIMBS>::arbitrary();
+    let values = (0..1_000_000)
+        .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+        .collect::<Vec<_>>();
+    criterion.bench_function(&format!("sort/{BITS}"), |bencher| {
+        bencher.iter_batched(
+            || values.clone(),
+            |mut values| {
+                values.sort_unstable();
+                values
+            },
+            BatchSize::LargeInput,
+        );
+    });
 }
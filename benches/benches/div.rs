@@ -7,6 +7,7 @@ pub fn group(criterion: &mut Criterion) {
         for rhs_bits in BTreeSet::from([64, BITS - BITS / 2, BITS]) {
             bench_div_rem::<BITS, LIMBS>(criterion, rhs_bits);
         }
+        bench_rem_power_of_two::<BITS, LIMBS>(criterion);
     });
 }
 
@@ -30,3 +31,12 @@ fn bench_div_rem<const BITS: usize, const LIMBS: usize>(
         |(a, b)| a.div_rem(b),
     );
 }
+
+fn bench_rem_power_of_two<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    bench_arbitrary_with(
+        criterion,
+        &format!("rem/{BITS}/power_of_two"),
+        (Uint::<BITS, LIMBS>::arbitrary(), 0..BITS),
+        |(a, k)| a % (Uint::ONE << k),
+    );
+}
@@ -18,6 +18,9 @@ pub fn group(criterion: &mut Criterion) {
             write!(buf, "{n:x}").unwrap()
         });
     });
+    bench_fmt::<16384, 256, _>(criterion, "fmt/decimal", |n, buf| {
+        write!(buf, "{n}").unwrap()
+    });
 }
 
 fn bench_fmt<const BITS: usize, const LIMBS: usize, T>(
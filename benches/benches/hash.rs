@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use nohash_hasher::BuildNoHashHasher;
+use rustc_hash::FxBuildHasher;
+use std::{
+    collections::{HashMap, hash_map::RandomState},
+    hash::BuildHasher,
+};
+
+const KEYS: usize = 1_000_000;
+
+type U64 = Uint<64, 1>;
+
+pub fn group(criterion: &mut Criterion) {
+    let mut runner = TestRunner::deterministic();
+    let strategy = U64::arbitrary();
+    let keys = (0..KEYS)
+        .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+        .collect::<Vec<_>>();
+    bench_map::<RandomState>(criterion, "default", &keys);
+    bench_map::<FxBuildHasher>(criterion, "fx", &keys);
+    bench_map::<BuildNoHashHasher<U64>>(criterion, "nohash", &keys);
+}
+
+/// Inserts and then looks up a million keys.
+fn bench_map<S: BuildHasher + Default>(criterion: &mut Criterion, name: &str, keys: &[U64]) {
+    criterion.bench_function(&format!("hash_map/insert/{name}"), |bencher| {
+        bencher.iter(|| {
+            let mut map = HashMap::<U64, u32, S>::with_capacity_and_hasher(KEYS, S::default());
+            for (i, &key) in keys.iter().enumerate() {
+                map.insert(key, i as u32);
+            }
+            map
+        });
+    });
+    let map = keys
+        .iter()
+        .enumerate()
+        .map(|(i, &key)| (key, i as u32))
+        .collect::<HashMap<_, _, S>>();
+    criterion.bench_function(&format!("hash_map/lookup/{name}"), |bencher| {
+        bencher.iter(|| {
+            keys.iter()
+                .map(|key| map[black_box(key)])
+                .fold(0, u32::wrapping_add)
+        });
+    });
+}
@@ -2,10 +2,12 @@ mod add;
 mod algorithms;
 mod base_convert;
 mod bits;
+mod bytes;
 mod cmp;
 mod div;
 mod fmt;
 mod from;
+mod hash;
 mod log;
 mod modular;
 mod mul;
@@ -27,8 +29,10 @@ pub fn group(c: &mut criterion::Criterion) {
     modular::group(c);
 
     cmp::group(c);
+    hash::group(c);
 
     base_convert::group(c);
+    bytes::group(c);
     from::group(c);
     fmt::group(c);
     string::group(c);
@@ -7,6 +7,24 @@ pub fn group(criterion: &mut Criterion) {
         bench_binop::<BITS, LIMBS, _>(criterion, "reduce_mod", |a, m| a.reduce_mod(m));
         bench_ternary::<BITS, LIMBS, _>(criterion, "add_mod", |a, b, m| a.add_mod(b, m));
         bench_ternary::<BITS, LIMBS, _>(criterion, "mul_mod", |a, b, m| a.mul_mod(b, m));
+        // Field elements are already reduced, so order the inputs.
+        let reduced = |a: Uint<BITS, LIMBS>, b: Uint<BITS, LIMBS>| (a.min(b), a.max(b) | Uint::ONE);
+        bench_binop::<BITS, LIMBS, _>(criterion, "mul_mod_2", |a, b| {
+            let (a, m) = reduced(a, b);
+            a.mul_mod(Uint::from(2), m)
+        });
+        bench_binop::<BITS, LIMBS, _>(criterion, "double_mod", |a, b| {
+            let (a, m) = reduced(a, b);
+            a.double_mod(m)
+        });
+        bench_binop::<BITS, LIMBS, _>(criterion, "halve_mod", |a, b| {
+            let (a, m) = reduced(a, b);
+            a.halve_mod(m)
+        });
+        bench_binop::<BITS, LIMBS, _>(criterion, "shl_mod_100", |a, b| {
+            let (a, m) = reduced(a, b);
+            a.shl_mod(100, m)
+        });
         bench_ternary::<BITS, LIMBS, _>(criterion, "pow_mod", |a, b, m| a.pow_mod(b, m));
         bench_binop::<BITS, LIMBS, _>(criterion, "inv_mod", |a, m| a.inv_mod(m));
     });
@@ -5,5 +5,14 @@ pub fn group(criterion: &mut Criterion) {
         const LIMBS: usize = nlimbs(BITS);
         bench_binop::<BITS, LIMBS, _>(criterion, "pow", |a, b| a.pow(b));
         bench_binop::<BITS, LIMBS, _>(criterion, "overflowing_pow", |a, b| a.overflowing_pow(b));
+        bench_arbitrary_with(
+            criterion,
+            &format!("pow_u64/{BITS}"),
+            (Uint::<BITS, LIMBS>::arbitrary(), 0_u64..100),
+            |(a, e)| a.pow_u64(e),
+        );
+        bench_arbitrary_with(criterion, &format!("pow10/{BITS}"), 0..BITS, |n| {
+            Uint::<BITS, LIMBS>::pow10(n)
+        });
     });
 }
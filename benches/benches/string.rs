@@ -9,6 +9,25 @@ pub fn group(criterion: &mut Criterion) {
         bench_parse::<BITS, LIMBS, 10>(criterion, "parse/decimal");
         bench_parse::<BITS, LIMBS, 16>(criterion, "parse/hex");
     });
+    bench_parse::<16384, 256, 10>(criterion, "parse/decimal");
+    bench_dec_40(criterion);
+}
+
+/// A 40 digit number, parsed by the decimal fast path and by the per-digit
+/// loop that `from_str_radix` used before it.
+fn bench_dec_40(criterion: &mut Criterion) {
+    type U = Uint<256, 4>;
+    let s = black_box("1234567890123456789012345678901234567890");
+    bench_arbitrary::<(), _>(criterion, "parse/decimal/40/from_str_radix", |()| {
+        U::from_str_radix(black_box(s), 10).unwrap()
+    });
+    bench_arbitrary::<(), _>(criterion, "parse/decimal/40/per_digit", |()| {
+        let digits = black_box(s)
+            .chars()
+            .filter(|&c| c != '_')
+            .map(|c| u64::from(c.to_digit(10).unwrap()));
+        U::from_base_be(10, digits).unwrap()
+    });
 }
 
 fn bench_parse<const BITS: usize, const LIMBS: usize, const RADIX: u64>(
@@ -0,0 +1,72 @@
+//! Exercises the `alloc` APIs of `ruint` in a `no_std` crate.
+//!
+//! Run on its own with `cargo test -p ruint-alloc-test` so that `ruint` is
+//! built with `alloc` but without `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use ruint::{Endian, Order, ParseError, aliases::U256};
+
+/// Round-trips `value` through the allocating conversions and returns the
+/// decimal string.
+pub fn roundtrip(value: U256) -> Result<String, ParseError> {
+    let be: Vec<u8> = value.to_be_bytes_vec();
+    assert_eq!(U256::try_from_be_slice(&be), Some(value));
+    assert_eq!(
+        U256::try_from_le_slice(&value.to_le_bytes_trimmed_vec()),
+        Some(value)
+    );
+
+    let words = value.to_words(4, Order::MostSignificantFirst, Endian::Little);
+    assert_eq!(
+        U256::from_words(&words, 4, Order::MostSignificantFirst, Endian::Little),
+        Ok(value)
+    );
+    let digits = value.to_u32_digits_le_trimmed_vec();
+    assert_eq!(U256::from_u32_digits_le(&digits), Ok(value));
+
+    let base36 = value
+        .to_alphabet(b"0123456789abcdefghijklmnopqrstuvwxyz")
+        .unwrap();
+    assert_eq!(U256::from_str_radix(&base36, 36)?, value);
+
+    let decimal = value.to_string();
+    assert_eq!(decimal.parse::<U256>()?, value);
+    Ok(decimal)
+}
+
+/// Errors implement [`core::error::Error`] without `std`.
+pub fn error_source(error: &ParseError) -> Option<String> {
+    core::error::Error::source(error).map(ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        assert_eq!(roundtrip(U256::ZERO).unwrap(), "0");
+        assert_eq!(roundtrip(U256::from(1234)).unwrap(), "1234");
+        assert_eq!(
+            roundtrip(U256::MAX).unwrap(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+    }
+
+    #[test]
+    fn test_error() {
+        let error = U256::from_str_radix("z", 10).unwrap_err();
+        assert_eq!(
+            error_source(&error).unwrap(),
+            "digit 35 is out of range for base 10"
+        );
+        assert_eq!(error_source(&ParseError::InvalidRadix(100)), None);
+    }
+}
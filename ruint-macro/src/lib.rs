@@ -21,15 +21,70 @@ pub fn uint(stream: TokenStream) -> TokenStream {
 #[proc_macro]
 #[doc(hidden)]
 pub fn uint_with_path(stream: TokenStream) -> TokenStream {
+    match split_path(stream) {
+        Ok((path, stream)) => Transformer::new(Some(path)).transform_stream(stream),
+        Err(error) => error,
+    }
+}
+
+/// Creates a [`Uint`] or [`Bits`] constant from a string of hex digits, which
+/// may be split into groups by whitespace and underscores.
+///
+/// The second argument is the type, either an alias like `U256` or
+/// `aliases::B160`, or spelled out like `Uint<256, 4>`. The digits must form
+/// whole bytes and the value must fit the type, otherwise compilation fails
+/// with an error pointing at the string. The expansion is a `const`
+/// expression.
+///
+/// [`Uint`]: ../uint/struct.Uint.html
+/// [`Bits`]: ../uint/struct.Bits.html
+///
+/// ```rust
+/// # use ruint::{aliases::U256, uint_hex};
+/// const MERKLE_ROOT: U256 = uint_hex!(
+///     "7f9c2ba4 e88f827d 61604550 76055863
+///      84f4df1a 2a9e7a85 0b8e4b8e 1f0a9d1c",
+///     U256
+/// );
+/// assert_eq!(MERKLE_ROOT.byte(31), 0x7f);
+/// assert_eq!(uint_hex!("00_ff", U256), U256::from(255));
+/// ```
+///
+/// ```rust,compile_fail
+/// # use ruint::{aliases::U8, uint_hex};
+/// // An odd number of digits does not form whole bytes.
+/// let nibble = uint_hex!("fff", U8);
+/// ```
+#[proc_macro]
+pub fn uint_hex(stream: TokenStream) -> TokenStream {
+    Transformer::new(None).transform_hex(stream)
+}
+
+/// Same as [`uint_hex`], but with the first token always being a
+/// [group](proc_macro::Group) containing the `ruint` crate path.
+///
+/// This is an implementation detail and should not be used directly.
+#[proc_macro]
+#[doc(hidden)]
+pub fn uint_hex_with_path(stream: TokenStream) -> TokenStream {
+    match split_path(stream) {
+        Ok((path, stream)) => Transformer::new(Some(path)).transform_hex(stream),
+        Err(error) => error,
+    }
+}
+
+/// Splits the leading group containing the `ruint` crate path from the
+/// arguments.
+fn split_path(stream: TokenStream) -> Result<(TokenStream, TokenStream), TokenStream> {
     let mut stream_iter = stream.into_iter();
     let Some(TokenTree::Group(group)) = stream_iter.next() else {
-        return error(
+        return Err(error(
             Span::call_site(),
             "Expected a group containing the `ruint` crate path",
         )
-        .into();
+        .into());
     };
-    Transformer::new(Some(group.stream())).transform_stream(stream_iter.collect())
+    Ok((group.stream(), stream_iter.collect()))
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -171,6 +226,116 @@ fn parse_suffix(source: &str) -> Option<(LiteralBaseType, usize, &str)> {
     Some((base_type, bits, value))
 }
 
+/// Parses a hexadecimal literal with a binary exponent,
+/// `0x{mantissa}p{exponent}`, into the limbs of `mantissa << exponent`. Returns
+/// `Ok(None)` for literals without an exponent.
+///
+/// Exponents of `bits` or more are clamped to `bits`, which still overflows a
+/// non-zero mantissa, so huge exponents do not allocate huge limb vectors.
+fn parse_hex_power(value: &str, bits: usize) -> Result<Option<Vec<u64>>, String> {
+    let Some((mantissa, exponent)) = value
+        .strip_prefix("0x")
+        .and_then(|digits| digits.split_once(['p', 'P']))
+    else {
+        return Ok(None);
+    };
+    let limbs = parse_digits(&format!("0x{mantissa}"))?;
+    let exponent = exponent.trim_end_matches('_');
+    let shift = exponent
+        .replace('_', "")
+        .parse::<usize>()
+        .map_err(|_| format!("Invalid binary exponent '{exponent}'"))?
+        .min(bits);
+
+    let (words, shift) = (shift / 64, shift % 64);
+    let mut shifted = vec![0; words];
+    let mut carry = 0;
+    for limb in limbs {
+        shifted.push((limb << shift) | carry);
+        carry = if shift == 0 { 0 } else { limb >> (64 - shift) };
+    }
+    shifted.push(carry);
+    Ok(Some(shifted))
+}
+
+/// Returns the contents of a string literal, or [`None`] if `source` is not
+/// one.
+///
+/// Escaped whitespace is replaced by a space, other escapes are kept as is so
+/// they are reported as invalid characters.
+fn parse_string(source: &str) -> Option<String> {
+    if let Some(raw) = source.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let raw = raw.get(hashes..raw.len() - hashes)?;
+        return Some(raw.strip_prefix('"')?.strip_suffix('"')?.to_owned());
+    }
+    let mut chars = source.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'r' | 't') => value.push(' '),
+            Some(c) if c.is_whitespace() => value.push(' '),
+            Some(c) => {
+                value.push('\\');
+                value.push(c);
+            }
+            None => value.push('\\'),
+        }
+    }
+    Some(value)
+}
+
+/// Parses the hex digits of [`uint_hex!`], ignoring whitespace and
+/// underscores, into little-endian limbs.
+fn parse_hex(value: &str) -> Result<Vec<u64>, String> {
+    let digits = value.trim_start().strip_prefix("0x").unwrap_or(value);
+    let mut hex = String::from("0x");
+    for c in digits.chars() {
+        match c {
+            '0'..='9' | 'a'..='f' | 'A'..='F' => hex.push(c),
+            '_' => {}
+            c if c.is_whitespace() => {}
+            _ => return Err(format!("Invalid character {c:?} in hex string")),
+        }
+    }
+    let count = hex.len() - 2;
+    if count == 0 {
+        return Err("Expected at least one byte of hex digits".to_owned());
+    }
+    if count % 2 != 0 {
+        return Err(format!(
+            "Odd number of hex digits ({count}), expected two digits per byte"
+        ));
+    }
+    parse_digits(&hex)
+}
+
+/// Parses the type argument of [`uint_hex!`]: an alias like `U256` or `B256`,
+/// optionally with a path, or `Uint<BITS, LIMBS>` or `Bits<BITS, LIMBS>`.
+fn parse_type(source: &str) -> Option<(LiteralBaseType, usize)> {
+    let source = source.replace(char::is_whitespace, "");
+    let name = source.split("::").last()?;
+    if let Some((base_type, args)) = name.split_once('<') {
+        let base_type = match base_type {
+            "Uint" => LiteralBaseType::Uint,
+            "Bits" => LiteralBaseType::Bits,
+            _ => return None,
+        };
+        let (bits, limbs) = args.strip_suffix('>')?.split_once(',')?;
+        let bits = bits.parse::<usize>().ok()?;
+        let limbs = limbs.trim_end_matches(',').parse::<usize>().ok()?;
+        return (limbs == bits.div_ceil(64)).then_some((base_type, bits));
+    }
+    let (base_type, bits) = name.split_at_checked(1)?;
+    let base_type = base_type.parse::<LiteralBaseType>().ok()?;
+    let bits = bits.parse::<usize>().ok()?;
+    Some((base_type, bits))
+}
+
 struct Transformer {
     /// The `ruint` crate path.
     /// Note that this stream's span must be used in order for the `$crate` to
@@ -209,7 +374,10 @@ impl Transformer {
 
         // Parse `value` into limbs.
         // At this point we are confident the literal was for us, so we throw errors.
-        let limbs = parse_digits(value)?;
+        let limbs = match parse_hex_power(value, bits)? {
+            Some(limbs) => limbs,
+            None => parse_digits(value)?,
+        };
 
         // Pad limbs to the correct length.
         let Some(limbs) = pad_limbs(bits, limbs) else {
@@ -250,6 +418,56 @@ impl Transformer {
         }
     }
 
+    /// Expands the `"digits", Type` arguments of [`uint_hex!`].
+    fn transform_hex(&self, stream: TokenStream) -> TokenStream {
+        let mut tokens = stream.into_iter().map(|tree| match tree {
+            // Arguments forwarded as `$lit:literal` arrive in invisible groups.
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                let mut inner = group.stream().into_iter();
+                match (inner.next(), inner.next()) {
+                    (Some(tree), None) => tree,
+                    _ => TokenTree::Group(group),
+                }
+            }
+            tree => tree,
+        });
+        let usage = "Expected a string of hex digits and a type, like `uint_hex!(\"00ff\", U256)`";
+        let (Some(TokenTree::Literal(literal)), Some(TokenTree::Punct(comma))) =
+            (tokens.next(), tokens.next())
+        else {
+            return error(Span::call_site(), usage).into();
+        };
+        if comma.as_char() != ',' {
+            return error(comma.span(), usage).into();
+        }
+        let ty = tokens.collect::<Vec<_>>();
+        let ty = match ty.as_slice() {
+            [ty @ .., TokenTree::Punct(comma)] if comma.as_char() == ',' => ty,
+            ty => ty,
+        };
+        let Some(ty_span) = ty.first().map(TokenTree::span) else {
+            return error(comma.span(), usage).into();
+        };
+
+        let Some(value) = parse_string(&literal.to_string()) else {
+            return error(literal.span(), usage).into();
+        };
+        let ty_source = ty.iter().cloned().collect::<TokenStream>().to_string();
+        let Some((base_type, bits)) = parse_type(&ty_source) else {
+            let message = "Expected a `Uint` or `Bits` type like `U256` or `Uint<256, 4>`";
+            return error(ty_span, message).into();
+        };
+        let limbs = match parse_hex(&value) {
+            Ok(limbs) => limbs,
+            Err(message) => return error(literal.span(), &message).into(),
+        };
+        let Some(limbs) = pad_limbs(bits, limbs) else {
+            let message = format!("Value too large for {base_type}<{bits}>");
+            return error(literal.span(), &message).into();
+        };
+        self.construct(base_type, bits, &limbs)
+    }
+
     /// Iterate over a [`TokenStream`] and transform all [`TokenTree`]s.
     fn transform_stream(&self, stream: TokenStream) -> TokenStream {
         stream
@@ -286,6 +504,108 @@ mod tests {
         assert_eq!(parse_digits("0o10"), Ok(vec![8]));
     }
 
+    /// `Debug` for `Uint` prints `0x{hex}_U{BITS}`, which must stay valid
+    /// literal syntax.
+    #[test]
+    fn test_debug_form() {
+        assert_eq!(
+            parse_suffix("0x4d2_U256"),
+            Some((LiteralBaseType::Uint, 256, "0x4d2_"))
+        );
+        assert_eq!(
+            parse_suffix("0x0_U0"),
+            Some((LiteralBaseType::Uint, 0, "0x0_"))
+        );
+        assert_eq!(parse_digits("0x4d2_"), Ok(vec![0x4d2]));
+        assert_eq!(
+            pad_limbs(7, parse_digits("0x7f_").unwrap()),
+            Some(vec![0x7f])
+        );
+        assert_eq!(pad_limbs(0, parse_digits("0x0_").unwrap()), Some(vec![]));
+    }
+
+    #[test]
+    fn test_hex_power() {
+        assert_eq!(parse_hex_power("0x10", 256), Ok(None));
+        assert_eq!(parse_hex_power("1024", 256), Ok(None));
+        assert_eq!(parse_hex_power("0x3p4_", 256), Ok(Some(vec![48, 0])));
+        assert_eq!(parse_hex_power("0x1P64_", 256), Ok(Some(vec![0, 1, 0])));
+        assert_eq!(
+            parse_hex_power("0x1p255_", 256),
+            Ok(Some(vec![0, 0, 0, 1 << 63, 0]))
+        );
+        assert_eq!(
+            parse_hex_power("0xffp1_00", 256),
+            Ok(Some(vec![0, 0xff << 36, 0]))
+        );
+        let clamped = parse_hex_power("0x1p99999999999_", 256).unwrap().unwrap();
+        assert_eq!(pad_limbs(256, clamped), None);
+        assert!(
+            parse_hex_power("0x1.8p4", 256)
+                .unwrap_err()
+                .contains("Invalid character '.'")
+        );
+        assert!(
+            parse_hex_power("0x1p_", 256)
+                .unwrap_err()
+                .contains("Invalid binary exponent")
+        );
+        assert!(
+            parse_hex_power("0x1pa_", 256)
+                .unwrap_err()
+                .contains("Invalid binary exponent")
+        );
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(parse_string(r#""00 ff""#).as_deref(), Some("00 ff"));
+        assert_eq!(
+            parse_string(r###"r##"00"ff"##"###).as_deref(),
+            Some("00\"ff")
+        );
+        assert_eq!(parse_string(r#""00\n\tff""#).as_deref(), Some("00  ff"));
+        assert_eq!(parse_string("\"00\\\n ff\"").as_deref(), Some("00  ff"));
+        assert_eq!(parse_string("1234"), None);
+
+        assert_eq!(parse_hex("00ff_0102"), Ok(vec![0x00ff_0102]));
+        assert_eq!(parse_hex(" 0x00\n ff"), Ok(vec![0xff]));
+        assert_eq!(
+            parse_hex("0000000000000001 0000000000000000"),
+            Ok(vec![0, 1])
+        );
+        assert!(
+            parse_hex("fff")
+                .unwrap_err()
+                .contains("Odd number of hex digits (3)")
+        );
+        assert!(parse_hex("0x_").unwrap_err().contains("at least one byte"));
+        assert!(
+            parse_hex("00\\\"")
+                .unwrap_err()
+                .contains("Invalid character '\\\\'")
+        );
+        assert!(
+            parse_hex("0g")
+                .unwrap_err()
+                .contains("Invalid character 'g'")
+        );
+
+        assert_eq!(parse_type("U256"), Some((LiteralBaseType::Uint, 256)));
+        assert_eq!(
+            parse_type("ruint :: aliases :: B160"),
+            Some((LiteralBaseType::Bits, 160))
+        );
+        assert_eq!(
+            parse_type("Uint < 65 , 2 >"),
+            Some((LiteralBaseType::Uint, 65))
+        );
+        assert_eq!(parse_type("Bits<0, 0>"), Some((LiteralBaseType::Bits, 0)));
+        assert_eq!(parse_type("Uint<65, 1>"), None);
+        assert_eq!(parse_type("u64"), None);
+        assert_eq!(parse_type("Hash"), None);
+    }
+
     #[test]
     #[allow(clippy::unreadable_literal)]
     fn test_overflow_during_parsing() {
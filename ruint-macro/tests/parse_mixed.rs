@@ -19,13 +19,30 @@ fn test_parse_mixed() {
         assert_eq!(0o10_U256, "0o10".parse::<U256>().unwrap());
         assert_eq!(0b10_U256, "0b10".parse::<U256>().unwrap());
 
-        assert_eq!(0x10_B256, "0x10".parse::<B256>().unwrap());
-        assert_eq!(0o10B256, "0o10".parse::<B256>().unwrap());
-        assert_eq!(0b10B256, "0b10".parse::<B256>().unwrap());
+        assert_eq!(0x10_B256, B256::from("0x10".parse::<U256>().unwrap()));
+        assert_eq!(0o10B256, B256::from("0o10".parse::<U256>().unwrap()));
+        assert_eq!(0b10B256, B256::from("0b10".parse::<U256>().unwrap()));
 
-        assert_eq!(0o10_B256, "0o10".parse::<B256>().unwrap());
-        assert_eq!(0b10_B256, "0b10".parse::<B256>().unwrap());
+        assert_eq!(0o10_B256, B256::from("0o10".parse::<U256>().unwrap()));
+        assert_eq!(0b10_B256, B256::from("0b10".parse::<U256>().unwrap()));
 
         assert_eq!(2, 2);
     }
 }
+
+#[test]
+fn test_hex_power() {
+    uint! {
+        assert_eq!(0x1p255_U256, "0x1p255".parse::<U256>().unwrap());
+        assert_eq!(0x1P255_U256, U256::ONE << 255);
+        assert_eq!(0x3p4_U256, 48_U256);
+        assert_eq!(0xffp1_00_U256, "0xffp100".parse::<U256>().unwrap());
+        assert_eq!(0x0p1000_U256, 0_U256);
+        assert_eq!(
+            0x1p255_U256 - 0x13_U256,
+            "0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+                .parse::<U256>()
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,6 @@
+/// Checks the compile errors of the macros.
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,9 @@
+use ruint::{aliases::U64, uint_hex};
+
+const DASHED: U64 = uint_hex!("dead-beef", U64);
+
+const NOT_A_STRING: U64 = uint_hex!(0xdeadbeef, U64);
+
+const EMPTY: U64 = uint_hex!("", U64);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ruint::{aliases::U256, uint_hex};
+
+const ROOT: U256 = uint_hex!(
+    "7f9c2ba4 e88f827d 61604550 76055863
+     84f4df1a 2a9e7a85 0b8e4b8e 1f0a9d1",
+    U256
+);
+
+fn main() {}
@@ -0,0 +1,5 @@
+use ruint::{aliases::U64, uint_hex};
+
+const VALUE: U64 = uint_hex!("01 00000000 00000000", U64);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use ruint::uint_hex;
+
+type Hash = ruint::aliases::U256;
+
+const ROOT: Hash = uint_hex!("00", Hash);
+
+const MISSING: u64 = uint_hex!("00");
+
+const LIMBS: ruint::Uint<65, 1> = uint_hex!("00", Uint<65, 1>);
+
+fn main() {}
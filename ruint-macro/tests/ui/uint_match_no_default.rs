@@ -0,0 +1,8 @@
+use ruint::{aliases::U64, uint_match};
+
+fn main() {
+    let _ = uint_match!(U64::ZERO, {
+        0_U64 => "zero",
+        1_U64 => "one",
+    });
+}
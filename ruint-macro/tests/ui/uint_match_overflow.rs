@@ -0,0 +1,8 @@
+use ruint::{aliases::U8, uint_match};
+
+fn main() {
+    let _ = uint_match!(U8::ZERO, {
+        256_U8 => "overflow",
+        _ => "other",
+    });
+}
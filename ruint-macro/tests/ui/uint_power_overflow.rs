@@ -0,0 +1,5 @@
+use ruint::{aliases::U64, uint};
+
+const VALUE: U64 = uint!(0x2p63_U64);
+
+fn main() {}
@@ -0,0 +1,79 @@
+//! Exercises the parsing and formatting of `ruint` in a `no_std` crate without
+//! `alloc`.
+//!
+//! Run on its own with `cargo test -p ruint-no-alloc-test` so that `ruint` is
+//! built without `alloc`.
+
+#![no_std]
+
+use core::str::FromStr;
+use ruint::{ParseError, aliases::U256};
+
+/// Parses `src` like [`FromStr`] and formats the value back into `buf` in
+/// decimal.
+pub fn reformat<'a>(src: &str, buf: &'a mut [u8]) -> Result<&'a str, ParseError> {
+    let value = U256::from_str(src)?;
+    Ok(value.to_dec_str(buf).expect("buffer too small"))
+}
+
+/// Parses decimal digits with [`U256::from_base_be`], the parser that
+/// `FromStr` used to build on.
+pub fn from_base_be(src: &str) -> Result<U256, ParseError> {
+    let mut invalid = None;
+    // Letters are digits in larger radixes, which `from_base_be` rejects.
+    let digits = src.chars().map_while(|c| {
+        let digit = c.to_digit(36);
+        if digit.is_none() {
+            invalid = Some(ParseError::InvalidDigit(c));
+        }
+        digit.map(u64::from)
+    });
+    let value = U256::from_base_be(10, digits)?;
+    invalid.map_or(Ok(value), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruint::BaseConvertError;
+
+    const MAX: &str =
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+    #[test]
+    fn test_reformat() {
+        let mut buf = [0_u8; U256::max_dec_len()];
+        assert_eq!(reformat("0", &mut buf), Ok("0"));
+        assert_eq!(reformat("1_000", &mut buf), Ok("1000"));
+        assert_eq!(reformat("0x2a", &mut buf), Ok("42"));
+        assert!(reformat("0b1p255", &mut buf).is_err());
+        assert_eq!(reformat("0x1p255", &mut buf).map(str::len), Ok(77));
+        assert_eq!(reformat(MAX, &mut buf), Ok(MAX));
+    }
+
+    #[test]
+    fn test_from_base_be() {
+        for src in [
+            "",
+            "0",
+            "42",
+            "00000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+            "12345678901234567890123456789",
+            MAX,
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+            "999999999999999999999999999999999999999999999999999999999999999999999999999999",
+            "1157920892373161954235709850086879078532699846656405640394575840079131296399351",
+            "12a",
+            "9999999999999999999999999999999999999999999999999999999999999999999999999999999x",
+        ] {
+            assert_eq!(U256::from_dec_str(src), from_base_be(src), "{src}");
+            assert_eq!(U256::from_str(src), from_base_be(src), "{src}");
+        }
+        assert_eq!(
+            U256::from_str(
+                "999999999999999999999999999999999999999999999999999999999999999999999999999999"
+            ),
+            Err(BaseConvertError::Overflow(77).into())
+        );
+    }
+}
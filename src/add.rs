@@ -3,10 +3,41 @@ use crate::{
     algorithms::{borrowing_sub, carrying_add},
 };
 use core::{
+    fmt,
     iter::Sum,
     ops::{Add, AddAssign, Neg, Sub, SubAssign},
 };
 
+/// Error for [`Uint::try_sum`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SumError<E> {
+    /// An item of the iterator was an error.
+    Item(E),
+
+    /// The sum does not fit in the [`Uint`].
+    Overflow,
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for SumError<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Item(e) => Some(e),
+            Self::Overflow => None,
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for SumError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Item(e) => e.fmt(f),
+            Self::Overflow => f.write_str("the sum is too large to fit the target type"),
+        }
+    }
+}
+
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Computes the absolute difference between `self` and `other`.
     ///
@@ -21,6 +52,58 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Sums the items of an iterator, returning [`None`] if overflow
+    /// occurred.
+    ///
+    /// Unlike [`Sum`], which wraps on overflow, this stops at the first
+    /// overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_sum<I: IntoIterator<Item = Self>>(iter: I) -> Option<Self> {
+        iter.into_iter().try_fold(Self::ZERO, Self::checked_add)
+    }
+
+    /// Sums an iterator of fallible items, stopping at the first item error or
+    /// overflow.
+    ///
+    /// # Errors
+    ///
+    /// * [`SumError::Item`] with the first item error.
+    /// * [`SumError::Overflow`] if the sum does not fit in the [`Uint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{SumError, uint, aliases::{U8, U256}};
+    /// # uint! {
+    /// let fees = ["1000", "250", "0x10"];
+    /// assert_eq!(
+    ///     U256::try_sum(fees.iter().map(|s| s.parse::<U256>())),
+    ///     Ok(1266_U256)
+    /// );
+    ///
+    /// let fees = ["200", "100"];
+    /// assert_eq!(
+    ///     U8::try_sum(fees.iter().map(|s| s.parse::<U8>())),
+    ///     Err(SumError::Overflow)
+    /// );
+    ///
+    /// let fees = ["200", "fee"];
+    /// let sum = U8::try_sum(fees.iter().map(|s| s.parse::<U8>()));
+    /// assert!(matches!(sum, Err(SumError::Item(_))));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_sum<I, E>(iter: I) -> Result<Self, SumError<E>>
+    where
+        I: IntoIterator<Item = Result<Self, E>>,
+    {
+        iter.into_iter().try_fold(Self::ZERO, |sum, item| {
+            sum.checked_add(item.map_err(SumError::Item)?)
+                .ok_or(SumError::Overflow)
+        })
+    }
+
     /// Computes `self + rhs`, returning [`None`] if overflow occurred.
     #[inline(always)]
     #[must_use]
@@ -59,17 +142,49 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[inline]
     #[must_use]
     pub const fn overflowing_add(mut self, rhs: Self) -> (Self, bool) {
+        let overflow = self.overflowing_add_assign(&rhs);
+        (self, overflow)
+    }
+
+    /// Computes `self += rhs` in place, wrapping around at the boundary of the
+    /// type.
+    ///
+    /// Returns whether an arithmetic overflow occurred. This is what `+=`
+    /// uses.
+    #[inline]
+    pub const fn overflowing_add_assign(&mut self, rhs: &Self) -> bool {
+        self.carrying_add_assign(rhs, false)
+    }
+
+    /// Computes `self += rhs + carry` in place, wrapping around at the
+    /// boundary of the type.
+    ///
+    /// Returns the carry out, i.e. whether the true sum does not fit. Chaining
+    /// the carry through the halves of a wider number adds the whole number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// // Add two 512-bit numbers stored as (low, high) halves.
+    /// let (mut low, mut high) = (U256::MAX, U256::ZERO);
+    /// let carry = low.carrying_add_assign(&U256::ONE, false);
+    /// let carry = high.carrying_add_assign(&U256::ZERO, carry);
+    /// assert_eq!((low, high, carry), (U256::ZERO, U256::ONE, false));
+    /// ```
+    #[inline]
+    pub const fn carrying_add_assign(&mut self, rhs: &Self, mut carry: bool) -> bool {
         if BITS == 0 {
-            return (Self::ZERO, false);
+            return carry;
         }
-        let mut carry = false;
         let mut i = 0;
         while i < LIMBS {
             (self.limbs[i], carry) = carrying_add(self.limbs[i], rhs.limbs[i], carry);
             i += 1;
         }
         let overflow = carry | (self.limbs[LIMBS - 1] > Self::MASK);
-        (self.masked(), overflow)
+        self.apply_mask();
+        overflow
     }
 
     /// Calculates $\mod{-\mathtt{self}}_{2^{BITS}}$.
@@ -92,8 +207,19 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[inline]
     #[must_use]
     pub const fn overflowing_sub(mut self, rhs: Self) -> (Self, bool) {
+        let overflow = self.overflowing_sub_assign(&rhs);
+        (self, overflow)
+    }
+
+    /// Computes `self -= rhs` in place, wrapping around at the boundary of the
+    /// type.
+    ///
+    /// Returns whether an arithmetic overflow occurred. This is what `-=`
+    /// uses.
+    #[inline]
+    pub const fn overflowing_sub_assign(&mut self, rhs: &Self) -> bool {
         if BITS == 0 {
-            return (Self::ZERO, false);
+            return false;
         }
         let mut borrow = false;
         let mut i = 0;
@@ -102,7 +228,8 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             i += 1;
         }
         let overflow = borrow | (self.limbs[LIMBS - 1] > Self::MASK);
-        (self.masked(), overflow)
+        self.apply_mask();
+        overflow
     }
 
     /// Computes `self + rhs`, saturating at the numeric bounds instead of
@@ -127,6 +254,77 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Calculates $\mod{\mathtt{self} + \mathtt{delta}}_{2^{BITS}}$ for a
+    /// signed `delta`.
+    ///
+    /// Returns a tuple of the result along with a boolean indicating whether
+    /// the true result is negative or does not fit. If so then the wrapped
+    /// value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U8;
+    /// assert_eq!(U8::from(5).overflowing_add_signed(-2), (U8::from(3), false));
+    /// assert_eq!(U8::from(1).overflowing_add_signed(-2), (U8::MAX, true));
+    /// assert_eq!(U8::from(1).overflowing_add_signed(256), (U8::from(1), true));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // Intentional.
+    pub fn overflowing_add_signed(self, delta: i128) -> (Self, bool) {
+        let magnitude = delta.unsigned_abs();
+        let (magnitude, too_large) =
+            Self::overflowing_from_limbs_slice(&[magnitude as u64, (magnitude >> 64) as u64]);
+        let (value, overflow) = if delta < 0 {
+            self.overflowing_sub(magnitude)
+        } else {
+            self.overflowing_add(magnitude)
+        };
+        (value, overflow | too_large)
+    }
+
+    /// Computes `self + delta` for a signed `delta`, returning [`None`] if the
+    /// result is negative or does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// let balance = U256::from(100);
+    /// assert_eq!(balance.checked_add_signed(-30), Some(U256::from(70)));
+    /// assert_eq!(balance.checked_add_signed(-101), None);
+    /// assert_eq!(U256::MAX.checked_add_signed(1), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_add_signed(self, delta: i128) -> Option<Self> {
+        match self.overflowing_add_signed(delta) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Computes `self + delta` for a signed `delta`, saturating at zero and
+    /// [`Self::MAX`].
+    #[inline]
+    #[must_use]
+    pub fn saturating_add_signed(self, delta: i128) -> Self {
+        match self.overflowing_add_signed(delta) {
+            (value, false) => value,
+            _ if delta < 0 => Self::ZERO,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Computes `self + delta` for a signed `delta`, wrapping around at the
+    /// boundary of the type.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_add_signed(self, delta: i128) -> Self {
+        self.overflowing_add_signed(delta).0
+    }
+
     /// Computes `self + rhs`, wrapping around at the boundary of the type.
     #[inline(always)]
     #[must_use]
@@ -187,8 +385,22 @@ impl<'a, const BITS: usize, const LIMBS: usize> Sum<&'a Self> for Uint<BITS, LIM
     }
 }
 
-impl_bin_op!(Add, add, AddAssign, add_assign, wrapping_add);
-impl_bin_op!(Sub, sub, SubAssign, sub_assign, wrapping_sub);
+impl_bin_op!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    wrapping_add,
+    overflowing_add_assign
+);
+impl_bin_op!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    wrapping_sub,
+    overflowing_sub_assign
+);
 
 #[cfg(test)]
 mod tests {
@@ -205,6 +417,215 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_add_signed() {
+        use crate::aliases::{U8, U256};
+
+        let balance = U256::from(u128::MAX) << 64_usize;
+        let expected = balance - (U256::ONE << 127_usize);
+        assert_eq!(balance.checked_add_signed(i128::MIN), Some(expected));
+        assert_eq!(U256::ONE.checked_add_signed(i128::MIN), None);
+        assert_eq!(U256::ONE.saturating_add_signed(i128::MIN), U256::ZERO);
+        assert_eq!(U256::from(42).checked_add_signed(-42), Some(U256::ZERO));
+        assert_eq!(U256::MAX.checked_add_signed(0), Some(U256::MAX));
+        assert_eq!(U256::MAX.checked_add_signed(1), None);
+        assert_eq!(U256::MAX.saturating_add_signed(1), U256::MAX);
+        assert_eq!(U256::MAX.wrapping_add_signed(1), U256::ZERO);
+        assert_eq!(U256::ZERO.wrapping_add_signed(-1), U256::MAX);
+        assert_eq!(U8::MAX.checked_add_signed(-255), Some(U8::ZERO));
+        assert_eq!(U8::ZERO.checked_add_signed(i128::MAX), None);
+        assert_eq!(U8::ZERO.saturating_add_signed(i128::MAX), U8::MAX);
+        assert_eq!(
+            Uint::<0, 0>::ZERO.overflowing_add_signed(0),
+            (Uint::ZERO, false)
+        );
+        assert_eq!(
+            Uint::<0, 0>::ZERO.overflowing_add_signed(-1),
+            (Uint::ZERO, true)
+        );
+
+        proptest!(|(value: u64, delta: i64)| {
+            let uint = Uint::<64, 1>::from(value);
+            let (result, overflow) = uint.overflowing_add_signed(delta.into());
+            assert_eq!((result.to::<u64>(), overflow), value.overflowing_add_signed(delta));
+            assert_eq!(
+                uint.saturating_add_signed(delta.into()),
+                Uint::from(value.saturating_add_signed(delta))
+            );
+        });
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, delta: i128)| {
+                let magnitude = U::wrapping_from(delta.unsigned_abs());
+                let wrapped = if delta < 0 {
+                    value.wrapping_sub(magnitude)
+                } else {
+                    value.wrapping_add(magnitude)
+                };
+                assert_eq!(value.wrapping_add_signed(delta), wrapped);
+                let exact = if delta < 0 {
+                    U::checked_from(delta.unsigned_abs()).and_then(|m| value.checked_sub(m))
+                } else {
+                    U::checked_from(delta.unsigned_abs()).and_then(|m| value.checked_add(m))
+                };
+                assert_eq!(value.checked_add_signed(delta), exact);
+            });
+        });
+    }
+
+    #[test]
+    fn test_carry_across_limbs() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            for i in 0..BITS {
+                // All ones below bit `i`, so adding one carries up to bit `i`.
+                let ones = (U::ONE << i).wrapping_sub(U::ONE);
+                let mut value = ones;
+                assert!(!value.overflowing_add_assign(&U::ONE));
+                assert_eq!(value, U::ONE << i);
+                assert!(!value.overflowing_sub_assign(&U::ONE));
+                assert_eq!(value, ones);
+            }
+            let mut value = U::MAX;
+            assert!(value.overflowing_add_assign(&U::ONE));
+            assert_eq!(value, U::ZERO);
+            assert!(value.overflowing_sub_assign(&U::ONE));
+            assert_eq!(value, U::MAX);
+            assert!(value.carrying_add_assign(&U::ZERO, true));
+            assert_eq!(value, U::ZERO);
+        });
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, carry: bool)| {
+                let mut value = a;
+                let overflow = value.overflowing_add_assign(&b);
+                assert_eq!((value, overflow), a.overflowing_add(b));
+
+                let mut value = a;
+                let overflow = value.overflowing_sub_assign(&b);
+                assert_eq!((value, overflow), a.overflowing_sub(b));
+
+                let mut value = a;
+                let overflow = value.carrying_add_assign(&b, carry);
+                if BITS == 0 {
+                    assert_eq!((value, overflow), (U::ZERO, carry));
+                } else {
+                    let (sum, o1) = a.overflowing_add(b);
+                    let (sum, o2) = sum.overflowing_add(if carry { U::ONE } else { U::ZERO });
+                    assert_eq!((value, overflow), (sum, o1 | o2));
+                }
+
+                let mut value = a;
+                value += b;
+                value -= &a;
+                assert_eq!(value, b);
+            });
+        });
+    }
+
+    /// Adds two full-width numbers as two halves with the carry chained
+    /// through, and compares to adding them in one go.
+    fn check_split_add<const H: usize, const HL: usize, const F: usize, const FL: usize>(
+        a: Uint<F, FL>,
+        b: Uint<F, FL>,
+        carry: bool,
+    ) {
+        let half = |x: &Uint<F, FL>, i: usize| {
+            Uint::<H, HL>::from_limbs_slice(&x.as_limbs()[i * HL..(i + 1) * HL])
+        };
+        let (mut low, mut high) = (half(&a, 0), half(&a, 1));
+        let carry_mid = low.carrying_add_assign(&half(&b, 0), carry);
+        let carry_out = high.carrying_add_assign(&half(&b, 1), carry_mid);
+
+        let mut full = a;
+        let expected = full.carrying_add_assign(&b, carry);
+        assert_eq!((low, high), (half(&full, 0), half(&full, 1)));
+        assert_eq!(carry_out, expected);
+    }
+
+    #[test]
+    fn test_carrying_add_split() {
+        proptest!(|(a: Uint<128, 2>, b: Uint<128, 2>, carry: bool)| {
+            check_split_add::<64, 1, 128, 2>(a, b, carry);
+        });
+        proptest!(|(a: Uint<512, 8>, b: Uint<512, 8>, carry: bool)| {
+            check_split_add::<256, 4, 512, 8>(a, b, carry);
+        });
+        check_split_add::<256, 4, 512, 8>(Uint::MAX, Uint::ZERO, true);
+        check_split_add::<256, 4, 512, 8>(Uint::MAX >> 256, Uint::ONE, false);
+    }
+
+    #[test]
+    fn test_checked_sum() {
+        type U = Uint<8, 1>;
+        assert_eq!(U::checked_sum([]), Some(U::ZERO));
+        assert_eq!(U::checked_sum([100, 155].map(U::from)), Some(U::MAX));
+        assert_eq!(U::checked_sum([100, 156].map(U::from)), None);
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(values: Vec<U>)| {
+                let expected = values.iter().try_fold(U::ZERO, |a, &b| a.checked_add(b));
+                assert_eq!(U::checked_sum(values.iter().copied()), expected);
+                assert_eq!(
+                    U::try_sum(values.iter().copied().map(Ok::<_, ()>)),
+                    expected.ok_or(SumError::Overflow)
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn test_try_sum() {
+        type U = Uint<8, 1>;
+        assert_eq!(U::try_sum::<_, ()>([]), Ok(U::ZERO));
+        assert_eq!(
+            U::try_sum([Ok(U::from(1)), Ok(U::from(2))]),
+            Ok::<_, SumError<()>>(U::from(3))
+        );
+
+        // The first failure wins, whichever kind it is.
+        assert_eq!(
+            U::try_sum([Ok(U::MAX), Ok(U::ONE), Err("item")]),
+            Err(SumError::Overflow)
+        );
+        assert_eq!(
+            U::try_sum([Ok(U::MAX), Err("item"), Ok(U::ONE)]),
+            Err(SumError::Item("item"))
+        );
+
+        // Items after the first failure are not consumed.
+        let mut consumed = 0;
+        let items = [Ok(U::ONE), Err(1), Ok(U::ONE), Err(2)]
+            .into_iter()
+            .inspect(|_| consumed += 1);
+        assert_eq!(U::try_sum(items), Err(SumError::Item(1)));
+        assert_eq!(consumed, 2);
+
+        // Composes with conversions.
+        let values = [200_u64, 55];
+        assert_eq!(U::try_sum(values.into_iter().map(U::try_from)), Ok(U::MAX));
+        let values = [200_u64, 256];
+        assert_eq!(
+            U::try_sum(values.into_iter().map(U::try_from)),
+            Err(SumError::Item(crate::ToUintError::ValueTooLarge(
+                8,
+                U::ZERO
+            )))
+        );
+        assert_eq!(
+            SumError::<&str>::Overflow.to_string(),
+            "the sum is too large to fit the target type"
+        );
+    }
+
     #[test]
     fn test_commutative() {
         const_for!(BITS in SIZES {
@@ -40,6 +40,7 @@ pub const fn borrowing_sub(lhs: u64, rhs: u64, borrow: bool) -> (u64, bool) {
 pub fn carrying_add_n(lhs: &mut [u64], rhs: &[u64], mut carry: bool) -> bool {
     debug_assert!(lhs.len() == rhs.len());
     for i in 0..lhs.len() {
+        count_op!(add64);
         (lhs[i], carry) = carrying_add(lhs[i], rhs[i], carry);
     }
     carry
@@ -51,6 +52,7 @@ pub fn carrying_add_n(lhs: &mut [u64], rhs: &[u64], mut carry: bool) -> bool {
 pub fn borrowing_sub_n(lhs: &mut [u64], rhs: &[u64], mut borrow: bool) -> bool {
     debug_assert!(lhs.len() == rhs.len());
     for i in 0..lhs.len() {
+        count_op!(add64);
         (lhs[i], borrow) = borrowing_sub(lhs[i], rhs[i], borrow);
     }
     borrow
@@ -196,6 +196,7 @@ pub fn div_2x1_mg10(u: u128, d: u64, v: u64) -> (u64, u64) {
     debug_assert!(d >= (1 << 63));
     debug_assert!((u >> 64) < u128::from(d));
     debug_assert_eq!(v, reciprocal(d));
+    count_op!(div64);
 
     let q = u + (u >> 64) * u128::from(v);
     let q0 = q as u64;
@@ -276,6 +277,7 @@ pub fn div_3x2_mg10(u21: u128, u0: u64, d: u128, v: u64) -> (u64, u128) {
     debug_assert!(d >= (1 << 127));
     debug_assert!(u21 < d);
     debug_assert_eq!(v, reciprocal_2(d));
+    count_op!(div64);
 
     let q = u128::mul(u21.high(), v) + u21;
     let r1 = u21.low().wrapping_sub(q.high().wrapping_mul(d.high()));
@@ -1,6 +1,9 @@
 #![allow(clippy::use_self)]
 
-use crate::Uint;
+use crate::{
+    Uint,
+    algorithms::{addmul_nx1, submul_nx1},
+};
 
 /// ⚠️ Lehmer update matrix
 #[doc = crate::algorithms::unstable_warning!()]
@@ -44,21 +47,24 @@ impl Matrix {
         if BITS == 0 {
             return;
         }
-        // OPT: We can avoid the temporary if we implement a dedicated matrix
-        // multiplication.
-        let (c, d) = if self.4 {
-            (
-                Uint::from(self.0) * *a - Uint::from(self.1) * *b,
-                Uint::from(self.3) * *b - Uint::from(self.2) * *a,
-            )
+        // Each output takes one multiply-add and one multiply-subtract pass over
+        // the limbs, which is linear in `LIMBS`.
+        let (a_limbs, b_limbs) = (a.as_limbs(), b.as_limbs());
+        let mut c = [0; LIMBS];
+        let mut d = [0; LIMBS];
+        if self.4 {
+            addmul_nx1(&mut c, a_limbs, self.0);
+            submul_nx1(&mut c, b_limbs, self.1);
+            addmul_nx1(&mut d, b_limbs, self.3);
+            submul_nx1(&mut d, a_limbs, self.2);
         } else {
-            (
-                Uint::from(self.1) * *b - Uint::from(self.0) * *a,
-                Uint::from(self.2) * *a - Uint::from(self.3) * *b,
-            )
-        };
-        *a = c;
-        *b = d;
+            addmul_nx1(&mut c, b_limbs, self.1);
+            submul_nx1(&mut c, a_limbs, self.0);
+            addmul_nx1(&mut d, a_limbs, self.2);
+            submul_nx1(&mut d, b_limbs, self.3);
+        }
+        *a = Uint::from_limbs_unmasked(c);
+        *b = Uint::from_limbs_unmasked(d);
     }
 
     /// ⚠️ Applies the matrix to a `u128`.
@@ -227,6 +227,37 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_gcd_large() {
+        const_for!(BITS in [1024, 2048, 4096, 8192] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let config = Config { cases: 5, ..Default::default() };
+            proptest!(config, |(a: U, b: U, g: U, shift in 0..BITS)| {
+                assert_eq!(gcd(a, b), gcd_ref(a, b));
+                // Large common factors and operands of very different sizes.
+                let g = g >> shift;
+                let (a, b) = (a >> (BITS - shift) | U::ONE, b >> (BITS - shift) | U::ONE);
+                assert_eq!(gcd(a * g, b * g), gcd_ref(a * g, b * g));
+                assert_eq!(gcd(a, b * g), gcd_ref(a, b * g));
+            });
+
+            // Consecutive Fibonacci numbers take the most Euclidean steps.
+            let (mut a, mut b) = (U::ONE, U::ONE);
+            while let Some(c) = a.checked_add(b) {
+                (a, b) = (b, c);
+            }
+            assert_eq!(gcd(b, a), U::ONE);
+            let (g, x, y, sign) = gcd_extended(b, a);
+            assert_eq!(g, U::ONE);
+            if sign {
+                assert_eq!(b * x - a * y, g);
+            } else {
+                assert_eq!(a * y - b * x, g);
+            }
+        });
+    }
+
     #[test]
     fn test_gcd_extended() {
         const_for!(BITS in SIZES {
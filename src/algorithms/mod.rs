@@ -68,6 +68,24 @@ pub(crate) trait DoubleWord<T: Default>: Sized + Copy {
     }
 }
 
+#[allow(clippy::cast_possible_truncation)]
+impl DoubleWord<u32> for u64 {
+    #[inline(always)]
+    fn join(high: u32, low: u32) -> Self {
+        (Self::from(high) << 32) | Self::from(low)
+    }
+
+    #[inline(always)]
+    fn split(self) -> (u32, u32) {
+        (self as u32, (self >> 32) as u32)
+    }
+
+    #[inline(always)]
+    fn muladd2(a: u32, b: u32, c: u32, d: u32) -> Self {
+        Self::from(a) * Self::from(b) + Self::from(c) + Self::from(d)
+    }
+}
+
 #[allow(clippy::cast_possible_truncation)]
 impl DoubleWord<u64> for u128 {
     #[inline(always)]
@@ -80,8 +98,15 @@ impl DoubleWord<u64> for u128 {
         (self as u64, (self >> 64) as u64)
     }
 
+    #[inline(always)]
+    fn add(a: u64, b: u64) -> Self {
+        count_op!(add64);
+        Self::from(a) + Self::from(b)
+    }
+
     #[inline(always)]
     fn muladd2(a: u64, b: u64, c: u64, d: u64) -> Self {
+        count_op!(mul64);
         #[cfg(feature = "nightly")]
         {
             let (low, high) = u64::carrying_mul_add(a, b, c, d);
@@ -82,25 +82,58 @@ const ADDMUL_N_SMALL_LIMIT: usize = 8;
 pub fn addmul_n(lhs: &mut [u64], a: &[u64], b: &[u64]) {
     let n = lhs.len();
     if n <= ADDMUL_N_SMALL_LIMIT && a.len() == n && b.len() == n {
-        addmul_n_small(lhs, a, b);
+        #[cfg(not(ruint_limb32))]
+        addmul_n_small::<u64, u128>(lhs, a, b);
+        #[cfg(ruint_limb32)]
+        addmul_n_small_split(lhs, a, b);
     } else {
         let _ = addmul(lhs, a, b);
     }
 }
 
+/// Schoolbook wrapping `lhs += a * b` over words `T` with products `D`.
 #[inline(always)]
-fn addmul_n_small(lhs: &mut [u64], a: &[u64], b: &[u64]) {
+fn addmul_n_small<T: Copy + Default, D: DoubleWord<T>>(lhs: &mut [T], a: &[T], b: &[T]) {
     let n = lhs.len();
-    assume!(n <= ADDMUL_N_SMALL_LIMIT);
+    assume!(size_of_val(lhs) <= ADDMUL_N_SMALL_LIMIT * size_of::<u64>());
     assume!(a.len() == n);
     assume!(b.len() == n);
 
     for j in 0..n {
-        let mut carry = 0;
+        let mut carry = T::default();
         for i in 0..(n - j) {
-            (lhs[j + i], carry) = u128::muladd2(a[i], b[j], carry, lhs[j + i]).split();
+            (lhs[j + i], carry) = D::muladd2(a[i], b[j], carry, lhs[j + i]).split();
+        }
+    }
+}
+
+/// [`addmul_n_small`] on the limbs split into `u32` words, used with
+/// `--cfg ruint_limb32`.
+///
+/// This is for targets like `wasm32` and Cortex-M that emulate the 64×64→128
+/// bit multiplication with native 32×32→64 bit ones. It is not benchmarked
+/// there yet.
+#[cfg(any(test, ruint_limb32))]
+#[inline(always)]
+fn addmul_n_small_split(lhs: &mut [u64], a: &[u64], b: &[u64]) {
+    const WORDS: usize = 2 * ADDMUL_N_SMALL_LIMIT;
+    let n = lhs.len();
+    assume!(n <= ADDMUL_N_SMALL_LIMIT);
+    let mut words = [[0_u32; WORDS]; 3];
+    let [lhs_words, a_words, b_words] = &mut words;
+    for (limbs, words) in [(&*lhs, &mut *lhs_words), (a, a_words), (b, b_words)] {
+        for (limb, pair) in limbs.iter().zip(words.chunks_exact_mut(2)) {
+            (pair[0], pair[1]) = limb.split();
         }
     }
+    addmul_n_small::<u32, u64>(
+        &mut lhs_words[..2 * n],
+        &a_words[..2 * n],
+        &b_words[..2 * n],
+    );
+    for (limb, pair) in lhs.iter_mut().zip(lhs_words.chunks_exact(2)) {
+        *limb = u64::join(pair[1], pair[0]);
+    }
 }
 
 /// ⚠️ Computes `lhs += a` and returns the carry.
@@ -235,6 +268,27 @@ mod tests {
         });
     }
 
+    /// The `u32` word kernel used with `--cfg ruint_limb32`, against the
+    /// `u64` one. Runs in both configurations.
+    #[test]
+    fn test_addmul_n_small_split() {
+        let any_vec = collection::vec(u64::ANY, 0..=3 * ADDMUL_N_SMALL_LIMIT);
+        proptest!(|(words in &any_vec, n in 0..=ADDMUL_N_SMALL_LIMIT)| {
+            let n = n.min(words.len() / 3);
+            let (a, b) = (&words[n..2 * n], &words[2 * n..3 * n]);
+            let mut expected = words[..n].to_vec();
+            addmul_n_small::<u64, u128>(&mut expected, a, b);
+            let mut lhs = words[..n].to_vec();
+            addmul_n_small_split(&mut lhs, a, b);
+            assert_eq!(lhs, expected);
+        });
+        let mut lhs = [u64::MAX; ADDMUL_N_SMALL_LIMIT];
+        let max = [u64::MAX; ADDMUL_N_SMALL_LIMIT];
+        addmul_n_small_split(&mut lhs, &max, &max);
+        // `(2^k - 1) + (2^k - 1)^2 = 2^k (2^k - 1)`, which wraps to zero.
+        assert_eq!(lhs, [0; ADDMUL_N_SMALL_LIMIT]);
+    }
+
     fn test_vals(lhs: &[u64], rhs: &[u64], expected: &[u64], expected_overflow: bool) {
         let mut result = vec![0; expected.len()];
         let overflow = addmul(&mut result, lhs, rhs);
@@ -62,6 +62,33 @@ alias! {
     U4096 B4096 (4096, 64);
 }
 
+// The blanket `From<Uint> for Uint` is blocked (see `from.rs`), so widening
+// conversions between the alias widths are implemented concretely instead.
+// This also gives infallible `TryFrom` through the std blanket impl.
+//
+// FEATURE: (BLOCKED) Narrowing `TryFrom` impls, with any error type, overlap
+// with the blanket `UintTryFrom` implementation in coherence. Use
+// `Uint::try_to` instead.
+macro_rules! alias_conversions {
+    () => {};
+    ($narrow:ident $(, $wide:ident)*) => {
+        $(
+            impl From<$narrow> for $wide {
+                #[inline]
+                fn from(value: $narrow) -> Self {
+                    Self::from_limbs_slice(value.as_limbs())
+                }
+            }
+        )*
+        alias_conversions!($($wide),*);
+    };
+}
+
+alias_conversions!(
+    U0, U1, U8, U16, U32, U64, U128, U160, U192, U256, U320, U384, U448, U512, U768, U1024, U2048,
+    U4096
+);
+
 // TODO: I0, I1, I8, ... I4096
 
 #[cfg(test)]
@@ -89,4 +116,28 @@ mod tests {
         let _ = (U2048::ZERO, U2048::MAX, B2048::ZERO);
         let _ = (U4096::ZERO, U4096::MAX, B4096::ZERO);
     }
+
+    #[test]
+    fn test_conversions() {
+        macro_rules! check {
+            () => {};
+            ($narrow:ident $(, $wide:ident)*) => {
+                $(
+                    for value in [$narrow::ZERO, $narrow::ONE, $narrow::MAX] {
+                        let wide: $wide = value.into();
+                        assert_eq!(wide, value.to::<$wide>());
+                        assert_eq!(<$wide as From<$narrow>>::from(value), wide);
+                        assert_eq!(wide.try_to::<$narrow>(), Ok(value));
+                        let wide: Result<$wide, _> = value.try_into();
+                        assert_eq!(wide.ok(), value.checked_to::<$wide>());
+                    }
+                )*
+                check!($($wide),*);
+            };
+        }
+        check!(
+            U0, U1, U8, U16, U32, U64, U128, U160, U192, U256, U320, U384, U448, U512, U768, U1024,
+            U2048, U4096
+        );
+    }
 }
@@ -0,0 +1,300 @@
+//! Encoding into arbitrary caller-supplied digit alphabets.
+//!
+//! An alphabet is a list of distinct ASCII symbols, the `i`-th symbol standing
+//! for the digit `i`. This covers the usual positional encodings like base36
+//! or base58 (without its leading-zero convention) and lets applications pick
+//! their own for human-readable identifiers.
+
+use crate::Uint;
+use core::fmt;
+
+/// The [Crockford base32](https://www.crockford.com/base32.html) alphabet.
+///
+/// Use it with [`Uint::to_alphabet`] to encode and with
+/// [`Uint::from_crockford_base32`] to decode with folding of confusable
+/// characters.
+pub const CROCKFORD_BASE32: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Error for [`Uint::to_alphabet`] and [`Uint::from_alphabet`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlphabetError {
+    /// The alphabet has fewer than two symbols. `.0` is its length.
+    TooShort(usize),
+
+    /// The alphabet contains the non-ASCII byte `.0`.
+    NonAscii(u8),
+
+    /// The alphabet contains the symbol `.0` more than once.
+    Duplicate(u8),
+
+    /// The string contains the character `.0`, which is not in the alphabet.
+    InvalidChar(char),
+
+    /// The value is too large to fit the target type.
+    Overflow,
+}
+
+impl core::error::Error for AlphabetError {}
+
+impl fmt::Display for AlphabetError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort(len) => write!(f, "alphabet has {len} symbols, expected at least 2"),
+            Self::NonAscii(byte) => write!(f, "alphabet contains non-ASCII byte {byte:#04x}"),
+            Self::Duplicate(byte) => {
+                write!(
+                    f,
+                    "alphabet contains {:?} more than once",
+                    char::from(*byte)
+                )
+            }
+            Self::InvalidChar(c) => write!(f, "character {c:?} is not in the alphabet"),
+            Self::Overflow => f.write_str("the value is too large to fit the target type"),
+        }
+    }
+}
+
+/// Marks symbols that are not in the alphabet.
+const NONE: u8 = u8::MAX;
+
+/// Validates the alphabet and returns the digit for each ASCII symbol.
+fn digits(alphabet: &[u8]) -> Result<[u8; 128], AlphabetError> {
+    if alphabet.len() < 2 {
+        return Err(AlphabetError::TooShort(alphabet.len()));
+    }
+    let mut digits = [NONE; 128];
+    for (digit, &symbol) in alphabet.iter().enumerate() {
+        let slot = digits
+            .get_mut(usize::from(symbol))
+            .ok_or(AlphabetError::NonAscii(symbol))?;
+        if *slot != NONE {
+            return Err(AlphabetError::Duplicate(symbol));
+        }
+        // At most 128 distinct ASCII symbols, so this does not truncate.
+        #[allow(clippy::cast_possible_truncation)]
+        let digit = digit as u8;
+        *slot = digit;
+    }
+    Ok(digits)
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Encodes the value in the positional system whose digits are the
+    /// symbols of `alphabet`, most significant first.
+    ///
+    /// Zero is encoded as the first symbol. There are no leading zeros
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// * [`AlphabetError::TooShort`] if the alphabet has fewer than two
+    ///   symbols.
+    /// * [`AlphabetError::NonAscii`] if a symbol is not ASCII.
+    /// * [`AlphabetError::Duplicate`] if a symbol is repeated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{CROCKFORD_BASE32, aliases::U64};
+    /// let base36 = b"0123456789abcdefghijklmnopqrstuvwxyz";
+    /// assert_eq!(U64::from(1_295).to_alphabet(base36).unwrap(), "zz");
+    /// assert_eq!(
+    ///     U64::from(32_767).to_alphabet(CROCKFORD_BASE32).unwrap(),
+    ///     "ZZZ"
+    /// );
+    /// assert_eq!(U64::ZERO.to_alphabet(b"ab").unwrap(), "a");
+    /// assert!(U64::ZERO.to_alphabet(b"aa").is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_alphabet(&self, alphabet: &[u8]) -> Result<alloc::string::String, AlphabetError> {
+        digits(alphabet)?;
+        let base = alphabet.len() as u64;
+        let mut result = self
+            .to_base_le(base)
+            .map(|digit| char::from(alphabet[digit as usize]))
+            .collect::<alloc::vec::Vec<_>>();
+        if result.is_empty() {
+            result.push(char::from(alphabet[0]));
+        }
+        Ok(result.into_iter().rev().collect())
+    }
+
+    /// Decodes a string produced by [`Self::to_alphabet`].
+    ///
+    /// Leading zero symbols are accepted and an empty string is zero.
+    ///
+    /// # Errors
+    ///
+    /// * [`AlphabetError::TooShort`], [`AlphabetError::NonAscii`] or
+    ///   [`AlphabetError::Duplicate`] if the alphabet is invalid.
+    /// * [`AlphabetError::InvalidChar`] if a character is not in the alphabet.
+    /// * [`AlphabetError::Overflow`] if the value does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{AlphabetError, aliases::U8};
+    /// let base36 = b"0123456789abcdefghijklmnopqrstuvwxyz";
+    /// assert_eq!(U8::from_alphabet("73", base36), Ok(U8::from(255)));
+    /// assert_eq!(
+    ///     U8::from_alphabet("74", base36),
+    ///     Err(AlphabetError::Overflow)
+    /// );
+    /// assert_eq!(
+    ///     U8::from_alphabet("7Z", base36),
+    ///     Err(AlphabetError::InvalidChar('Z'))
+    /// );
+    /// ```
+    pub fn from_alphabet(s: &str, alphabet: &[u8]) -> Result<Self, AlphabetError> {
+        Self::from_alphabet_folded(s, alphabet, Some)
+    }
+
+    /// Decodes a [Crockford base32](https://www.crockford.com/base32.html)
+    /// string, as encoded by [`Self::to_alphabet`] with [`CROCKFORD_BASE32`].
+    ///
+    /// Decoding folds the confusable characters: lowercase is accepted, `I`
+    /// and `L` read as `1`, `O` reads as `0` and `-` separators are ignored.
+    ///
+    /// # Errors
+    ///
+    /// * [`AlphabetError::InvalidChar`] if a character is not in the alphabet.
+    /// * [`AlphabetError::Overflow`] if the value does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U64;
+    /// let value = U64::from_crockford_base32("ZZZ").unwrap();
+    /// assert_eq!(U64::from_crockford_base32("zz-z"), Ok(value));
+    /// assert_eq!(
+    ///     U64::from_crockford_base32("1O"),
+    ///     U64::from_crockford_base32("l0")
+    /// );
+    /// assert!(U64::from_crockford_base32("U").is_err());
+    /// ```
+    pub fn from_crockford_base32(s: &str) -> Result<Self, AlphabetError> {
+        Self::from_alphabet_folded(s, CROCKFORD_BASE32, |c| match c.to_ascii_uppercase() {
+            '-' => None,
+            'I' | 'L' => Some('1'),
+            'O' => Some('0'),
+            c => Some(c),
+        })
+    }
+
+    /// Decodes `s` after mapping each character through `fold`, skipping the
+    /// characters it maps to `None`.
+    fn from_alphabet_folded(
+        s: &str,
+        alphabet: &[u8],
+        fold: impl Fn(char) -> Option<char>,
+    ) -> Result<Self, AlphabetError> {
+        let digits = digits(alphabet)?;
+        let base = alphabet.len() as u64;
+        let mut result = Self::ZERO;
+        let mut used = 0;
+        for c in s.chars() {
+            let Some(folded) = fold(c) else {
+                continue;
+            };
+            let digit = match digits.get(folded as usize) {
+                Some(&digit) if digit != NONE => digit,
+                _ => return Err(AlphabetError::InvalidChar(c)),
+            };
+            result
+                .push_digit(&mut used, base, digit.into())
+                .map_err(|_| AlphabetError::Overflow)?;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::{prelude::*, proptest, sample::subsequence};
+
+    fn alphabets() -> impl Strategy<Value = Vec<u8>> {
+        (2_usize..=128)
+            .prop_flat_map(|len| subsequence((0..128).collect::<Vec<u8>>(), len))
+            .prop_shuffle()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, alphabet in alphabets())| {
+                let encoded = value.to_alphabet(&alphabet).unwrap();
+                assert_eq!(U::from_alphabet(&encoded, &alphabet), Ok(value));
+                if alphabet.len() <= 36 {
+                    let base = alphabet.len() as u64;
+                    assert_eq!(encoded.len(), value.to_base_le(base).count().max(1));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_crockford() {
+        proptest!(|(value: U256)| {
+            let encoded = value.to_alphabet(CROCKFORD_BASE32).unwrap();
+            assert_eq!(U256::from_crockford_base32(&encoded), Ok(value));
+            let folded = encoded.to_lowercase().replace('1', "l").replace('0', "O");
+            assert_eq!(U256::from_crockford_base32(&folded), Ok(value));
+        });
+        assert_eq!(U256::from_crockford_base32("1-0"), Ok(U256::from(32)));
+        assert_eq!(
+            U256::from_crockford_base32("U"),
+            Err(AlphabetError::InvalidChar('U'))
+        );
+        assert_eq!(
+            U256::from_crockford_base32("é"),
+            Err(AlphabetError::InvalidChar('é'))
+        );
+    }
+
+    #[test]
+    fn test_invalid_alphabet() {
+        // A single symbol has no positional encoding, it must not loop or
+        // produce an empty string.
+        assert_eq!(U256::ONE.to_alphabet(b"a"), Err(AlphabetError::TooShort(1)));
+        assert_eq!(
+            U256::from_alphabet("aaa", b"a"),
+            Err(AlphabetError::TooShort(1))
+        );
+        assert_eq!(U256::ONE.to_alphabet(b""), Err(AlphabetError::TooShort(0)));
+        assert_eq!(
+            U256::ONE.to_alphabet(b"aba"),
+            Err(AlphabetError::Duplicate(b'a'))
+        );
+        assert_eq!(
+            U256::ONE.to_alphabet(b"a\xff"),
+            Err(AlphabetError::NonAscii(0xff))
+        );
+        assert_eq!(
+            U256::from_alphabet("ab", "aé".as_bytes()),
+            Err(AlphabetError::NonAscii(0xc3))
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        type U = Uint<65, 2>;
+        let hex = b"0123456789abcdef";
+        assert_eq!(U::from_alphabet("1ffffffffffffffff", hex), Ok(U::MAX));
+        assert_eq!(
+            U::from_alphabet("20000000000000000", hex),
+            Err(AlphabetError::Overflow)
+        );
+        assert_eq!(U::from_alphabet("", hex), Ok(U::ZERO));
+        assert_eq!(Uint::<0, 0>::from_alphabet("000", hex), Ok(Uint::ZERO));
+        assert_eq!(
+            Uint::<0, 0>::from_alphabet("1", hex),
+            Err(AlphabetError::Overflow)
+        );
+        assert_eq!(Uint::<0, 0>::ZERO.to_alphabet(hex).unwrap(), "0");
+    }
+}
@@ -0,0 +1,370 @@
+//! A [`Uint`] shared between threads without a [`Mutex`](std::sync::Mutex).
+//!
+//! The limbs are stored in individual [`AtomicU64`]s and guarded by a
+//! sequence lock: a version counter that is odd while a writer is updating
+//! the limbs. Writers serialize on the counter, readers never write to shared
+//! memory and simply retry when the version changed under them.
+
+#[cfg(all(test, ruint_loom))]
+use loom::{
+    hint::spin_loop,
+    sync::atomic::{AtomicU64, Ordering, fence},
+};
+
+#[cfg(not(all(test, ruint_loom)))]
+use core::{
+    hint::spin_loop,
+    sync::atomic::{AtomicU64, Ordering, fence},
+};
+
+use crate::Uint;
+use core::fmt;
+
+/// A [`Uint`] which can be safely shared between threads.
+///
+/// Every operation is linearizable on the full value. Since a [`Uint`] is
+/// wider than the hardware atomics, atomicity across limbs is achieved with a
+/// spinning sequence lock:
+///
+/// * Writers ([`store`](Self::store), [`fetch_add`](Self::fetch_add),
+///   [`compare_exchange`](Self::compare_exchange), ...) take turns and spin
+///   while another writer is active. The critical section is a handful of
+///   relaxed stores, so contention is short.
+/// * Readers ([`load`](Self::load)) never block writers. They retry when a
+///   write happened concurrently, so a reader can starve under a constant
+///   stream of writes.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::{AtomicUint, aliases::U256};
+/// let volume = AtomicUint::new(U256::MAX >> 1);
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| volume.fetch_add(U256::from(5)));
+///     }
+/// });
+/// assert_eq!(volume.load(), (U256::MAX >> 1) + U256::from(20));
+/// ```
+pub struct AtomicUint<const BITS: usize, const LIMBS: usize> {
+    /// Even when the limbs are consistent, odd while a writer holds the lock.
+    seq:   AtomicU64,
+    limbs: [AtomicU64; LIMBS],
+}
+
+impl<const BITS: usize, const LIMBS: usize> AtomicUint<BITS, LIMBS> {
+    /// Creates a new atomic holding `value`.
+    #[must_use]
+    pub fn new(value: Uint<BITS, LIMBS>) -> Self {
+        let limbs = value.as_limbs();
+        Self {
+            seq:   AtomicU64::new(0),
+            limbs: core::array::from_fn(|i| AtomicU64::new(limbs[i])),
+        }
+    }
+
+    /// Consumes the atomic and returns the contained value.
+    #[must_use]
+    pub fn into_inner(self) -> Uint<BITS, LIMBS> {
+        // Exclusive access, no other thread can hold the lock.
+        self.load()
+    }
+
+    /// Returns the current value.
+    ///
+    /// Never blocks writers. Spins while a write is in progress and retries
+    /// if one completed during the read.
+    #[must_use]
+    pub fn load(&self) -> Uint<BITS, LIMBS> {
+        loop {
+            let before = self.seq.load(Ordering::Acquire);
+            if before & 1 == 0 {
+                let limbs = self.read_limbs();
+                // Orders the limb reads before the second version read.
+                fence(Ordering::Acquire);
+                if self.seq.load(Ordering::Relaxed) == before {
+                    return Uint::from_limbs(limbs);
+                }
+            }
+            spin_loop();
+        }
+    }
+
+    /// Replaces the value with `value`.
+    pub fn store(&self, value: Uint<BITS, LIMBS>) {
+        self.swap(value);
+    }
+
+    /// Replaces the value with `value` and returns the previous value.
+    pub fn swap(&self, value: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        self.update(|_| Some(value)).0
+    }
+
+    /// Adds `value` to the current value, wrapping on overflow, and returns
+    /// the previous value.
+    ///
+    /// The addition is atomic across all limbs: no thread observes a value
+    /// with a carry only partially applied.
+    pub fn fetch_add(&self, value: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        self.update(|old| Some(old.wrapping_add(value))).0
+    }
+
+    /// Subtracts `value` from the current value, wrapping on underflow, and
+    /// returns the previous value.
+    pub fn fetch_sub(&self, value: Uint<BITS, LIMBS>) -> Uint<BITS, LIMBS> {
+        self.update(|old| Some(old.wrapping_sub(value))).0
+    }
+
+    /// Stores `new` if the current value equals `current`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the current value if it is not equal to `current`.
+    pub fn compare_exchange(
+        &self,
+        current: Uint<BITS, LIMBS>,
+        new: Uint<BITS, LIMBS>,
+    ) -> Result<Uint<BITS, LIMBS>, Uint<BITS, LIMBS>> {
+        let (old, stored) = self.update(|old| (old == current).then_some(new));
+        if stored { Ok(old) } else { Err(old) }
+    }
+
+    /// Applies `f` to the current value and stores the result if it is
+    /// [`Some`].
+    ///
+    /// Like [`AtomicU64::fetch_update`], `f` runs without any lock held and is
+    /// called again if another thread changed the value in the meantime.
+    ///
+    /// # Errors
+    ///
+    /// Returns the current value if `f` returned [`None`].
+    pub fn fetch_update<F>(&self, mut f: F) -> Result<Uint<BITS, LIMBS>, Uint<BITS, LIMBS>>
+    where
+        F: FnMut(Uint<BITS, LIMBS>) -> Option<Uint<BITS, LIMBS>>,
+    {
+        let mut current = self.load();
+        while let Some(new) = f(current) {
+            match self.compare_exchange(current, new) {
+                Ok(old) => return Ok(old),
+                Err(old) => current = old,
+            }
+        }
+        Err(current)
+    }
+
+    /// Takes the writer lock, applies `f` and publishes its result, if any.
+    /// Returns the previous value and whether a new one was stored.
+    ///
+    /// `f` runs with the lock held, so it must not panic: an unwind would
+    /// leave the version odd and block all other threads forever.
+    fn update<F>(&self, f: F) -> (Uint<BITS, LIMBS>, bool)
+    where
+        F: FnOnce(Uint<BITS, LIMBS>) -> Option<Uint<BITS, LIMBS>>,
+    {
+        let seq = self.lock();
+        let old = Uint::from_limbs(self.read_limbs());
+        let new = f(old);
+        if let Some(new) = new {
+            for (limb, value) in self.limbs.iter().zip(new.as_limbs()) {
+                limb.store(*value, Ordering::Relaxed);
+            }
+        }
+        self.seq.store(seq.wrapping_add(2), Ordering::Release);
+        (old, new.is_some())
+    }
+
+    /// Spins until no other writer is active and marks the limbs as being
+    /// written. Returns the (even) version before the write.
+    fn lock(&self) -> u64 {
+        let mut seq = self.seq.load(Ordering::Relaxed);
+        loop {
+            if seq & 1 == 0 {
+                match self.seq.compare_exchange_weak(
+                    seq,
+                    seq.wrapping_add(1),
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => break,
+                    Err(current) => seq = current,
+                }
+            } else {
+                spin_loop();
+                seq = self.seq.load(Ordering::Relaxed);
+            }
+        }
+        // Orders the odd version before the limb stores, so a reader that
+        // sees any of them also sees the version change.
+        fence(Ordering::Release);
+        seq
+    }
+
+    fn read_limbs(&self) -> [u64; LIMBS] {
+        core::array::from_fn(|i| self.limbs[i].load(Ordering::Relaxed))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Default for AtomicUint<BITS, LIMBS> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Uint::ZERO)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for AtomicUint<BITS, LIMBS> {
+    #[inline]
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> fmt::Debug for AtomicUint<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(), f)
+    }
+}
+
+#[cfg(all(test, not(ruint_loom)))]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{U0, U64, U256},
+        const_for, nlimbs,
+    };
+    use proptest::proptest;
+    use std::thread;
+
+    #[test]
+    fn test_single_thread() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, c: U)| {
+                let atomic = AtomicUint::new(a);
+                assert_eq!(atomic.load(), a);
+                assert_eq!(atomic.fetch_add(b), a);
+                assert_eq!(atomic.load(), a.wrapping_add(b));
+                assert_eq!(atomic.fetch_sub(b), a.wrapping_add(b));
+                assert_eq!(atomic.swap(b), a);
+                assert_eq!(atomic.compare_exchange(b, c), Ok(b));
+                if c != a {
+                    assert_eq!(atomic.compare_exchange(a, b), Err(c));
+                }
+                assert_eq!(atomic.fetch_update(|_| None), Err(c));
+                assert_eq!(atomic.fetch_update(|x| Some(!x)), Ok(c));
+                atomic.store(a);
+                assert_eq!(atomic.into_inner(), a);
+            });
+        });
+        assert_eq!(AtomicUint::<0, 0>::default().fetch_add(U0::ZERO), U0::ZERO);
+        assert_eq!(format!("{:?}", AtomicUint::from(U64::from(1))), "0x1_U64");
+    }
+
+    #[test]
+    fn test_fetch_update_panic() {
+        let atomic = AtomicUint::new(U64::from(1));
+        let result = std::panic::catch_unwind(|| atomic.fetch_update(|_| panic!("update")));
+        assert!(result.is_err());
+        assert_eq!(atomic.fetch_add(U64::from(1)), U64::from(1));
+        assert_eq!(atomic.load(), U64::from(2));
+    }
+
+    #[test]
+    fn test_fetch_add_stress() {
+        const THREADS: u64 = 8;
+        const ITERATIONS: u64 = 10_000;
+        // Starts just below a limb boundary so nearly every addition carries
+        // into the next limbs.
+        let start = U256::from(u64::MAX - ITERATIONS);
+        let atomic = AtomicUint::new(start);
+        let step = U256::from(1) | (U256::from(1) << 192);
+        thread::scope(|s| {
+            for _ in 0..THREADS {
+                s.spawn(|| {
+                    for _ in 0..ITERATIONS {
+                        atomic.fetch_add(step);
+                    }
+                });
+            }
+            // A torn read would show up as a value that is not `start` plus a
+            // whole number of steps.
+            s.spawn(|| {
+                for _ in 0..ITERATIONS {
+                    let diff = atomic.load() - start;
+                    assert_eq!(diff, (diff >> 192) * step);
+                }
+            });
+        });
+        let total = U256::from(THREADS * ITERATIONS);
+        assert_eq!(atomic.into_inner(), start + total * step);
+    }
+}
+
+#[cfg(all(test, ruint_loom))]
+mod loom_tests {
+    use super::*;
+    use crate::aliases::U128;
+    use loom::{sync::Arc, thread};
+
+    #[test]
+    fn test_fetch_add() {
+        loom::model(|| {
+            let atomic = Arc::new(AtomicUint::new(U128::from(u64::MAX)));
+            let handles = (0..2)
+                .map(|_| {
+                    let atomic = Arc::clone(&atomic);
+                    thread::spawn(move || atomic.fetch_add(U128::from(1)))
+                })
+                .collect::<Vec<_>>();
+            let mut previous = handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>();
+            previous.sort();
+            assert_eq!(previous, [
+                U128::from(u64::MAX),
+                U128::from(u64::MAX) + U128::from(1)
+            ]);
+            assert_eq!(atomic.load(), U128::from(u64::MAX) + U128::from(2));
+        });
+    }
+
+    #[test]
+    fn test_load_is_not_torn() {
+        loom::model(|| {
+            let atomic = Arc::new(AtomicUint::new(U128::ZERO));
+            let writer = {
+                let atomic = Arc::clone(&atomic);
+                thread::spawn(move || atomic.store(U128::MAX))
+            };
+            let value = atomic.load();
+            assert!(
+                value == U128::ZERO || value == U128::MAX,
+                "torn read {value:?}"
+            );
+            writer.join().unwrap();
+            assert_eq!(atomic.load(), U128::MAX);
+        });
+    }
+
+    #[test]
+    fn test_compare_exchange() {
+        loom::model(|| {
+            let atomic = Arc::new(AtomicUint::new(U128::ZERO));
+            let handles = (0..2)
+                .map(|i| {
+                    let atomic = Arc::clone(&atomic);
+                    let new = U128::MAX - U128::from(i);
+                    thread::spawn(move || atomic.compare_exchange(U128::ZERO, new))
+                })
+                .collect::<Vec<_>>();
+            let results = handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+            let winner = results.iter().find_map(|result| result.err()).unwrap();
+            assert_eq!(atomic.load(), winner);
+        });
+    }
+}
@@ -10,7 +10,10 @@ use core::{fmt, iter::FusedIterator, mem::MaybeUninit};
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BaseConvertError {
     /// The value is too large to fit the target type.
-    Overflow,
+    ///
+    /// `.0` is the index of the digit at which the value first overflows. When
+    /// parsing a string, it is the byte offset of that digit in the input.
+    Overflow(usize),
 
     /// The requested number base `.0` is less than two.
     InvalidBase(u64),
@@ -19,14 +22,16 @@ pub enum BaseConvertError {
     InvalidDigit(u64, u64),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for BaseConvertError {}
+impl core::error::Error for BaseConvertError {}
 
 impl fmt::Display for BaseConvertError {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Overflow => f.write_str("the value is too large to fit the target type"),
+            Self::Overflow(index) => write!(
+                f,
+                "the value is too large to fit the target type at digit {index}"
+            ),
             Self::InvalidBase(base) => {
                 write!(f, "the requested number base {base} is less than two")
             }
@@ -138,21 +143,21 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             return Err(BaseConvertError::InvalidBase(base));
         }
         if BITS == 0 {
-            for digit in digits {
+            for (index, digit) in digits.into_iter().enumerate() {
                 if digit >= base {
                     return Err(BaseConvertError::InvalidDigit(digit, base));
                 }
                 if digit != 0 {
-                    return Err(BaseConvertError::Overflow);
+                    return Err(BaseConvertError::Overflow(index));
                 }
             }
             return Ok(Self::ZERO);
         }
 
-        let mut iter = digits.into_iter();
+        let mut iter = digits.into_iter().enumerate();
         let mut result = Self::ZERO;
         let mut power = Self::ONE;
-        for digit in iter.by_ref() {
+        for (index, digit) in iter.by_ref() {
             if digit >= base {
                 return Err(BaseConvertError::InvalidDigit(digit, base));
             }
@@ -160,7 +165,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             // Add digit to result
             let overflow = addmul_nx1(&mut result.limbs, power.as_limbs(), digit);
             if overflow != 0 || result.limbs[LIMBS - 1] > Self::MASK {
-                return Err(BaseConvertError::Overflow);
+                return Err(BaseConvertError::Overflow(index));
             }
 
             // Update power
@@ -170,12 +175,12 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 break;
             }
         }
-        for digit in iter {
+        for (index, digit) in iter {
             if digit >= base {
                 return Err(BaseConvertError::InvalidDigit(digit, base));
             }
             if digit != 0 {
-                return Err(BaseConvertError::Overflow);
+                return Err(BaseConvertError::Overflow(index));
             }
         }
         Ok(result)
@@ -202,7 +207,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
 
         let mut result = Self::ZERO;
-        for digit in digits {
+        for (index, digit) in digits.into_iter().enumerate() {
             if digit >= base {
                 return Err(BaseConvertError::InvalidDigit(digit, base));
             }
@@ -216,7 +221,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 carry >>= 64;
             }
             if carry > 0 || (LIMBS != 0 && result.limbs[LIMBS - 1] > Self::MASK) {
-                return Err(BaseConvertError::Overflow);
+                return Err(BaseConvertError::Overflow(index));
             }
         }
 
@@ -543,11 +548,27 @@ mod tests {
         );
         assert_eq!(
             Uint::<0, 0>::from_base_be(10, core::iter::once(1)),
-            Err(BaseConvertError::Overflow)
+            Err(BaseConvertError::Overflow(0))
         );
         assert_eq!(
             Uint::<1, 1>::from_base_be(10, [1, 0, 0].into_iter()),
-            Err(BaseConvertError::Overflow)
+            Err(BaseConvertError::Overflow(1))
+        );
+        assert_eq!(
+            Uint::<8, 1>::from_base_be(10, [0, 2, 5, 5, 0]),
+            Err(BaseConvertError::Overflow(4))
+        );
+        assert_eq!(
+            Uint::<8, 1>::from_base_le(10, [6, 5, 2]),
+            Err(BaseConvertError::Overflow(2))
+        );
+        assert_eq!(
+            Uint::<8, 1>::from_base_le(16, [0, 0, 1]),
+            Err(BaseConvertError::Overflow(2))
+        );
+        assert_eq!(
+            Uint::<0, 0>::from_base_le(10, [0, 0, 3]),
+            Err(BaseConvertError::Overflow(2))
         );
     }
 
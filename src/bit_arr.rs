@@ -1,5 +1,6 @@
-use crate::{ParseError, Uint};
+use crate::{ParseError, Uint, UintTryFrom, from::ToUintError};
 use core::{
+    fmt,
     ops::{
         BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Not, Shl, ShlAssign,
         Shr, ShrAssign,
@@ -13,10 +14,54 @@ use alloc::{borrow::Cow, vec::Vec};
 
 /// A newtype wrapper around [`Uint`] that restricts operations to those
 /// relevant for bit arrays.
-#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "alloc", derive(Debug))]
+///
+/// Unlike [`Uint`], bit arrays are formatted and parsed as fixed-width,
+/// `0x` prefixed hex strings. Parsing rejects any other form, so that e.g. an
+/// address with its leading zeros stripped can not be mistaken for a valid
+/// one.
+///
+/// Equality, ordering and hashing are those of the wrapped [`Uint`].
+#[derive(Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Bits<const BITS: usize, const LIMBS: usize>(Uint<BITS, LIMBS>);
 
+/// Error for parsing a [`Bits`] from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseBitsError {
+    /// The string does not start with `0x`.
+    MissingPrefix,
+
+    /// The string does not have exactly two hex digits per byte.
+    InvalidLength {
+        /// The required number of hex digits.
+        expected: usize,
+        /// The number of hex digits found.
+        actual:   usize,
+    },
+
+    /// Invalid hex digit in the string.
+    InvalidDigit(char),
+
+    /// The value has bits set above `BITS`. Only possible if `BITS` is not a
+    /// multiple of 8.
+    ValueTooLarge,
+}
+
+impl core::error::Error for ParseBitsError {}
+
+impl fmt::Display for ParseBitsError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => f.write_str("missing 0x prefix"),
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "expected {expected} hex digits, got {actual}")
+            }
+            Self::InvalidDigit(c) => write!(f, "invalid hex digit: {c}"),
+            Self::ValueTooLarge => f.write_str("value is too large for the bit size"),
+        }
+    }
+}
+
 impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Bits<BITS, LIMBS> {
     #[inline]
     fn from(value: Uint<BITS, LIMBS>) -> Self {
@@ -31,12 +76,70 @@ impl<const BITS: usize, const LIMBS: usize> From<Bits<BITS, LIMBS>> for Uint<BIT
     }
 }
 
+impl<const BITS: usize, const LIMBS: usize, const BITS_SRC: usize, const LIMBS_SRC: usize>
+    UintTryFrom<Bits<BITS_SRC, LIMBS_SRC>> for Uint<BITS, LIMBS>
+{
+    #[inline]
+    fn uint_try_from(value: Bits<BITS_SRC, LIMBS_SRC>) -> Result<Self, ToUintError<Self>> {
+        Self::uint_try_from(value.into_inner())
+    }
+}
+
+/// Parses a `0x` prefixed hex string with exactly two digits per byte, the
+/// format of [`Display`](fmt::Display). Upper, lower and mixed case digits are
+/// accepted.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::{ParseBitsError, aliases::B16};
+/// assert_eq!("0x00fF".parse::<B16>(), Ok(B16::from_limbs([0xff])));
+/// assert_eq!(
+///     "0xff".parse::<B16>(),
+///     Err(ParseBitsError::InvalidLength {
+///         expected: 4,
+///         actual:   2,
+///     })
+/// );
+/// assert_eq!("00ff".parse::<B16>(), Err(ParseBitsError::MissingPrefix));
+/// assert_eq!(
+///     "0x00fg".parse::<B16>(),
+///     Err(ParseBitsError::InvalidDigit('g'))
+/// );
+/// ```
 impl<const BITS: usize, const LIMBS: usize> FromStr for Bits<BITS, LIMBS> {
-    type Err = <Uint<BITS, LIMBS> as FromStr>::Err;
+    type Err = ParseBitsError;
 
     #[inline]
     fn from_str(src: &str) -> Result<Self, Self::Err> {
-        src.parse().map(Self)
+        let digits = src
+            .strip_prefix("0x")
+            .ok_or(ParseBitsError::MissingPrefix)?;
+        if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ParseBitsError::InvalidDigit(c));
+        }
+        let expected = 2 * Self::BYTES;
+        if digits.len() != expected {
+            return Err(ParseBitsError::InvalidLength {
+                expected,
+                actual: digits.len(),
+            });
+        }
+        if BITS == 0 {
+            return Ok(Self::ZERO);
+        }
+        Uint::from_str_radix(digits, 16)
+            .map(Self)
+            .map_err(|_| ParseBitsError::ValueTooLarge)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&str> for Bits<BITS, LIMBS> {
+    type Error = ParseBitsError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
     }
 }
 
@@ -54,6 +157,15 @@ impl<const BITS: usize, const LIMBS: usize> Bits<BITS, LIMBS> {
     /// types.
     pub const ZERO: Self = Self(Uint::<BITS, LIMBS>::ZERO);
 
+    /// Wraps a [Uint].
+    ///
+    /// This is the `const` equivalent of [`Bits::from`].
+    #[must_use]
+    #[inline(always)]
+    pub const fn from_uint(value: Uint<BITS, LIMBS>) -> Self {
+        Self(value)
+    }
+
     /// Returns the inner [Uint].
     #[must_use]
     #[inline(always)]
@@ -74,6 +186,47 @@ impl<const BITS: usize, const LIMBS: usize> Bits<BITS, LIMBS> {
     pub const fn as_uint_mut(&mut self) -> &mut Uint<BITS, LIMBS> {
         &mut self.0
     }
+
+    /// Returns the `N` least significant bits. See [`Uint::truncate_low`].
+    ///
+    /// # Examples
+    ///
+    /// The Ethereum address of a public key is the low 160 bits of its
+    /// Keccak-256 hash:
+    ///
+    /// ```
+    /// # use ruint::aliases::*;
+    /// let hash: B256 = "0x37ee7f1bb2a9c1e2fa9f4e1eb5d6a3b84fcc00b53d5e4fa3c1c68fda0df0b78c"
+    ///     .parse()
+    ///     .unwrap();
+    /// let address: B160 = hash.truncate_low();
+    /// assert_eq!(
+    ///     address,
+    ///     "0xb5d6a3b84fcc00b53d5e4fa3c1c68fda0df0b78c"
+    ///         .parse()
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn truncate_low<const N: usize, const L: usize>(&self) -> Bits<N, L> {
+        Bits(self.0.truncate_low())
+    }
+
+    /// Returns the `N` most significant bits. See [`Uint::truncate_high`].
+    #[must_use]
+    #[inline(always)]
+    pub const fn truncate_high<const N: usize, const L: usize>(&self) -> Bits<N, L> {
+        Bits(self.0.truncate_high())
+    }
+
+    /// Widens to `N` bits, filling the new most significant bits with zeros.
+    /// See [`Uint::zero_extend`].
+    #[must_use]
+    #[inline(always)]
+    pub const fn zero_extend<const N: usize, const L: usize>(&self) -> Bits<N, L> {
+        Bits(self.0.zero_extend())
+    }
 }
 
 macro_rules! forward_attributes {
@@ -217,13 +370,13 @@ impl<const BITS: usize, const LIMBS: usize> Bits<BITS, LIMBS> {
         fn as_le_bytes(&self) -> Cow<'_, [u8]>;
         fn to_be_bytes_vec(&self) -> Vec<u8>;
     }
+    #[cfg(feature = "alloc")]
+    forward! {
+        fn to_le_bytes_vec(&self) -> Vec<u8>;
+    }
     forward! {
         fn to_le_bytes<const BYTES: usize>(&self) -> [u8; BYTES];
         fn to_be_bytes<const BYTES: usize>(&self) -> [u8; BYTES];
-        fn leading_zeros(&self) -> usize;
-        fn leading_ones(&self) -> usize;
-        fn trailing_zeros(&self) -> usize;
-        fn trailing_ones(&self) -> usize;
     }
     forward! {
         unsafe fn as_limbs_mut(&mut self) -> &mut [u64; LIMBS];
@@ -255,12 +408,58 @@ impl<const BITS: usize, const LIMBS: usize> Bits<BITS, LIMBS> {
     }
     forward! {
         const fn from_limbs(limbs: [u64; LIMBS]) -> Self;
+        const fn from_be_slice(bytes: &[u8]) -> Self;
+        const fn from_le_slice(bytes: &[u8]) -> Self;
+        const fn ones(n: usize) -> Self;
+        const fn repeat_byte(byte: u8) -> Self;
+        const fn repeat_limb(limb: u64) -> Self;
     }
     forward! {
         const fn as_limbs(&self) -> &[u64; LIMBS];
+        const fn leading_zeros(&self) -> usize;
+        const fn trailing_zeros(&self) -> usize;
+        const fn leading_ones(&self) -> usize;
+        const fn trailing_ones(&self) -> usize;
+        const fn count_ones(&self) -> usize;
+        const fn count_zeros(&self) -> usize;
+        const fn bit_len(&self) -> usize;
+        const fn byte_len(&self) -> usize;
+    }
+}
+
+/// Formats the bits as a `0x` prefixed lower case hex string, including
+/// leading zeros.
+impl<const BITS: usize, const LIMBS: usize> fmt::Display for Bits<BITS, LIMBS> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if BITS == 0 {
+            return f.write_str("0x");
+        }
+        write!(f, "{:#0w$x}", self.0, w = 2 + Self::BYTES * 2)
     }
 }
 
+/// Formats the same as [`Display`](fmt::Display).
+impl<const BITS: usize, const LIMBS: usize> fmt::Debug for Bits<BITS, LIMBS> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+macro_rules! impl_fmt {
+    ($($tr:ident),*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> fmt::$tr for Bits<BITS, LIMBS> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$tr::fmt(&self.0, f)
+            }
+        }
+    )*};
+}
+
+impl_fmt!(Binary, Octal, LowerHex, UpperHex);
+
 impl<const BITS: usize, const LIMBS: usize> Index<usize> for Bits<BITS, LIMBS> {
     type Output = bool;
 
@@ -412,3 +611,190 @@ macro_rules! impl_shift {
 
 impl_shift!(Shl, shl, ShlAssign, shl_assign);
 impl_shift!(Shr, shr, ShrAssign, shr_assign);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    /// Checks that `$op` gives the same result on [`Bits`] as on [`Uint`].
+    macro_rules! assert_parity {
+        ($a:ident, $b:ident, $ua:ident, $ub:ident, $($op:tt)*) => {{
+            let expected = {
+                let ($a, $b) = ($ua, $ub);
+                $($op)*
+            };
+            let actual = {
+                let ($a, $b) = (Bits::from($ua), Bits::from($ub));
+                $($op)*
+            };
+            assert_eq!(Uint::from(actual), expected);
+        }};
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)] // Reference impls are tested on purpose.
+    fn test_bit_ops() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(ua: U, ub: U, s in 0..=BITS + 2)| {
+                assert_parity!(a, b, ua, ub, a & b);
+                assert_parity!(a, b, ua, ub, a | b);
+                assert_parity!(a, b, ua, ub, a ^ b);
+                assert_parity!(a, b, ua, ub, &a & &b);
+                assert_parity!(a, b, ua, ub, &a | b);
+                assert_parity!(a, b, ua, ub, a ^ &b);
+                assert_parity!(a, b, ua, ub, { let _ = b; !a });
+                assert_parity!(a, b, ua, ub, { let mut a = a; a &= b; a });
+                assert_parity!(a, b, ua, ub, { let mut a = a; a |= &b; a });
+                assert_parity!(a, b, ua, ub, { let mut a = a; a ^= b; a });
+                assert_parity!(a, b, ua, ub, { let _ = b; a << s });
+                assert_parity!(a, b, ua, ub, { let _ = b; a >> s });
+                assert_parity!(a, b, ua, ub, { let _ = b; a.rotate_left(s) });
+                assert_parity!(a, b, ua, ub, { let _ = b; a.rotate_right(s) });
+                assert_parity!(a, b, ua, ub, { let _ = b; a.reverse_bits() });
+            });
+        });
+    }
+
+    #[test]
+    fn test_cmp() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert_eq!(Bits::<BITS, LIMBS>::default(), Bits::ZERO);
+            proptest!(|(ua: U, ub: U)| {
+                let (a, b) = (Bits::from(ua), Bits::from(ub));
+                assert_eq!(a.cmp(&b), ua.cmp(&ub));
+                assert_eq!(a.partial_cmp(&b), ua.partial_cmp(&ub));
+                assert_eq!(a == b, ua == ub);
+            });
+        });
+    }
+
+    #[test]
+    fn test_bit_counts() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let bits = Bits::from(value);
+                assert_eq!(bits.leading_zeros(), value.leading_zeros());
+                assert_eq!(bits.leading_ones(), value.leading_ones());
+                assert_eq!(bits.trailing_zeros(), value.trailing_zeros());
+                assert_eq!(bits.trailing_ones(), value.trailing_ones());
+                assert_eq!(bits.count_ones(), value.count_ones());
+                assert_eq!(bits.count_zeros(), value.count_zeros());
+                assert_eq!(bits.bit_len(), value.bit_len());
+                assert_eq!(bits.byte_len(), value.byte_len());
+                for i in 0..=BITS {
+                    assert_eq!(bits[i], value.bit(i));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_bytes() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let bits = Bits::from(value);
+                assert_eq!(Bits::from_be_slice(&bits.to_be_bytes_vec()), bits);
+                assert_eq!(Bits::from_le_slice(&bits.to_le_bytes_vec()), bits);
+                assert_eq!(Bits::try_from_be_slice(&value.to_be_bytes_vec()), Some(bits));
+                assert_eq!(Bits::try_from_le_slice(&value.to_le_bytes_vec()), Some(bits));
+            });
+        });
+    }
+
+    #[test]
+    fn test_conversions() {
+        const B: Bits<64, 1> = Bits::from_uint(Uint::from_limbs([42]));
+        const U: Uint<64, 1> = B.into_inner();
+        assert_eq!(U, Uint::<64, 1>::from(B));
+        assert_eq!(Uint::<64, 1>::from(42_u64), Uint::from(Bits::from(U)));
+        assert_eq!(Uint::<128, 2>::from(B), Uint::from(42_u64));
+        assert_eq!(
+            Uint::<8, 1>::saturating_from(Bits::from(Uint::<64, 1>::MAX)),
+            Uint::MAX
+        );
+    }
+
+    #[test]
+    fn test_resize() {
+        let bits = Bits::<72, 2>::from_limbs([0x0123_4567_89ab_cdef, 0xf0]);
+        assert_eq!(bits.truncate_low::<8, 1>(), Bits::from_limbs([0xef]));
+        assert_eq!(bits.truncate_high::<8, 1>(), Bits::from_limbs([0xf0]));
+        assert_eq!(bits.truncate_high::<12, 1>(), Bits::from_limbs([0xf00]));
+        assert_eq!(bits.zero_extend::<128, 2>().into_inner(), Uint::from(bits));
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(format!("{}", Bits::<0, 0>::ZERO), "0x");
+        assert_eq!(format!("{}", Bits::<8, 1>::ZERO), "0x00");
+        assert_eq!(format!("{}", Bits::<12, 1>::from_limbs([0xabc])), "0x0abc");
+        assert_eq!(
+            format!("{}", Bits::<64, 1>::from_limbs([0x1234])),
+            "0x0000000000001234"
+        );
+        assert_eq!(format!("{:x}", Bits::<64, 1>::from_limbs([0x1234])), "1234");
+        assert_eq!(format!("{:#X}", Bits::<64, 1>::from_limbs([0xab])), "0xAB");
+        assert_eq!(format!("{:b}", Bits::<64, 1>::from_limbs([5])), "101");
+        assert_eq!(format!("{:o}", Bits::<64, 1>::from_limbs([8])), "10");
+        assert_eq!(
+            format!("{:?}", Bits::<12, 1>::from_limbs([0xabc])),
+            "0x0abc"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        type B = Bits<16, 1>;
+        assert_eq!("0x00ff".parse::<B>(), Ok(B::from_limbs([0xff])));
+        assert_eq!("0xABCD".parse::<B>(), Ok(B::from_limbs([0xabcd])));
+        assert_eq!(B::try_from("0xaBcD"), Ok(B::from_limbs([0xabcd])));
+        assert_eq!(
+            "0xff".parse::<B>(),
+            Err(ParseBitsError::InvalidLength {
+                expected: 4,
+                actual:   2,
+            })
+        );
+        assert_eq!(
+            "0x000ff".parse::<B>(),
+            Err(ParseBitsError::InvalidLength {
+                expected: 4,
+                actual:   5,
+            })
+        );
+        assert_eq!("00ff".parse::<B>(), Err(ParseBitsError::MissingPrefix));
+        assert_eq!("0X00ff".parse::<B>(), Err(ParseBitsError::MissingPrefix));
+        assert_eq!(
+            "0x+0ff".parse::<B>(),
+            Err(ParseBitsError::InvalidDigit('+'))
+        );
+        assert_eq!(
+            "0x00_ff".parse::<B>(),
+            Err(ParseBitsError::InvalidDigit('_'))
+        );
+        assert_eq!(
+            "0x1fff".parse::<Bits<12, 1>>(),
+            Err(ParseBitsError::ValueTooLarge)
+        );
+        assert_eq!("0x".parse::<Bits<0, 0>>(), Ok(Bits::ZERO));
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Bits<BITS, LIMBS>)| {
+                assert_eq!(value.to_string().parse(), Ok(value));
+                assert_eq!(format!("{value:?}").parse(), Ok(value));
+                let upper = format!("0x{}", value.to_string()[2..].to_uppercase());
+                assert_eq!(upper.parse(), Ok(value));
+            });
+        });
+    }
+}
@@ -19,10 +19,37 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     }
 
     /// Sets a specific bit to a value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than or equal to the bit width of the
+    /// number. Earlier versions silently ignored such indices; use
+    /// [`Self::checked_set_bit`] for that non-panicking behaviour.
     #[inline]
+    #[track_caller]
     pub const fn set_bit(&mut self, index: usize, value: bool) {
+        assert!(index < BITS, "bit index out of range");
+        self.checked_set_bit(index, value);
+    }
+
+    /// Sets a specific bit to a value, returning whether `index` was in range.
+    ///
+    /// If `index` is greater than or equal to the bit width of the number, the
+    /// value is left unchanged and `false` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U8;
+    /// let mut x = U8::ZERO;
+    /// assert!(x.checked_set_bit(7, true));
+    /// assert!(!x.checked_set_bit(8, true));
+    /// assert_eq!(x, U8::from(0x80));
+    /// ```
+    #[inline]
+    pub const fn checked_set_bit(&mut self, index: usize, value: bool) -> bool {
         if index >= BITS {
-            return;
+            return false;
         }
         let (limbs, bits) = (index / 64, index % 64);
         if value {
@@ -30,6 +57,56 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         } else {
             self.limbs[limbs] &= !(1 << bits);
         }
+        true
+    }
+
+    /// Returns `self` with a specific bit set to a value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than or equal to the bit width of the
+    /// number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U16;
+    /// let mask = U16::ZERO
+    ///     .with_bit(0, true)
+    ///     .with_bit(4, true)
+    ///     .with_bit(15, true);
+    /// assert_eq!(mask, U16::from(0x8011));
+    /// assert_eq!(mask.with_bit(4, false), U16::from(0x8001));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn with_bit(mut self, index: usize, value: bool) -> Self {
+        self.set_bit(index, value);
+        self
+    }
+
+    /// Flips a specific bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than or equal to the bit width of the
+    /// number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U8;
+    /// let mut x = U8::from(0b1010);
+    /// x.toggle_bit(1);
+    /// x.toggle_bit(2);
+    /// assert_eq!(x, U8::from(0b1100));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub const fn toggle_bit(&mut self, index: usize) {
+        assert!(index < BITS, "bit index out of range");
+        self.limbs[index / 64] ^= 1 << (index % 64);
     }
 
     /// Returns a specific byte. The byte at index `0` is the least significant
@@ -181,39 +258,74 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[inline]
     #[must_use]
     pub const fn leading_ones(&self) -> usize {
-        Self::not(*self).leading_zeros()
+        if BITS == 0 {
+            return 0;
+        }
+        // Align the top limb so its highest valid bit is bit 63.
+        let fixed = Self::MASK.leading_zeros();
+        let mut ones = (self.limbs[LIMBS - 1] << fixed).leading_ones() as usize;
+        if ones < (64 - fixed) as usize {
+            return ones;
+        }
+        let mut i = LIMBS - 1;
+        while i > 0 {
+            i -= 1;
+            let n = self.limbs[i].leading_ones() as usize;
+            ones += n;
+            if n < 64 {
+                break;
+            }
+        }
+        ones
     }
 
     /// Returns the number of trailing zeros in the binary representation of
     /// `self`.
     #[inline]
     #[must_use]
-    pub fn trailing_zeros(&self) -> usize {
-        self.as_limbs()
-            .iter()
-            .position(|&limb| limb != 0)
-            .map_or(BITS, |n| {
-                n * 64 + self.as_limbs()[n].trailing_zeros() as usize
-            })
+    pub const fn trailing_zeros(&self) -> usize {
+        let mut i = 0;
+        while i < LIMBS {
+            if self.limbs[i] != 0 {
+                return i * 64 + self.limbs[i].trailing_zeros() as usize;
+            }
+            i += 1;
+        }
+        BITS
     }
 
     /// Returns the number of trailing ones in the binary representation of
     /// `self`.
     #[inline]
     #[must_use]
-    pub fn trailing_ones(&self) -> usize {
-        self.as_limbs()
-            .iter()
-            .position(|&limb| limb != u64::MAX)
-            .map_or(BITS, |n| {
-                n * 64 + self.as_limbs()[n].trailing_ones() as usize
-            })
+    pub const fn trailing_ones(&self) -> usize {
+        let mut i = 0;
+        while i < LIMBS {
+            if self.limbs[i] != u64::MAX {
+                return i * 64 + self.limbs[i].trailing_ones() as usize;
+            }
+            i += 1;
+        }
+        BITS
     }
 
     /// Returns the number of ones in the binary representation of `self`.
     #[inline]
     #[must_use]
     pub const fn count_ones(&self) -> usize {
+        #[cfg(feature = "portable-simd")]
+        if LIMBS >= crate::simd::LANES {
+            return core::intrinsics::const_eval_select(
+                (self,),
+                Self::count_ones_scalar,
+                Self::count_ones_simd,
+            );
+        }
+        self.count_ones_scalar()
+    }
+
+    #[inline]
+    const fn count_ones_scalar(&self) -> usize {
         let mut ones = 0;
         let mut i = 0;
         while i < LIMBS {
@@ -223,6 +335,12 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         ones
     }
 
+    #[cfg(feature = "portable-simd")]
+    #[inline]
+    fn count_ones_simd(&self) -> usize {
+        crate::simd::count_ones(&self.limbs)
+    }
+
     /// Returns the number of zeros in the binary representation of `self`.
     #[must_use]
     #[inline]
@@ -250,6 +368,34 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         self.bit_len().div_ceil(8)
     }
 
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// This is equivalent to the index of the highest set bit.
+    ///
+    /// Returns None if the number is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// const P: U256 = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001_U256;
+    /// const SHIFT: usize = match P.checked_log2() {
+    ///     Some(log) => 2 * (log + 1),
+    ///     None => panic!("zero modulus"),
+    /// };
+    /// assert_eq!(SHIFT, 2 * P.bit_len());
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_log2(self) -> Option<usize> {
+        match self.bit_len() {
+            0 => None,
+            bit_len => Some(bit_len - 1),
+        }
+    }
+
     /// Returns the most significant 64 bits of the number and the exponent.
     ///
     /// Given return value $(\mathtt{bits}, \mathtt{exponent})$, the `self` can
@@ -369,6 +515,19 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     ///
     /// Note: This differs from [`u64::wrapping_shl`] which first reduces `rhs`
     /// by `BITS` (which is IMHO not very useful).
+    ///
+    /// The `<<` and `<<=` operators use this for every primitive integer shift
+    /// amount. Negative amounts and amounts that don't fit a `usize` shift
+    /// out all bits.
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// let x = U256::from(0x0f_u64);
+    /// assert_eq!(x << 4_u32, U256::from(0xf0_u64));
+    /// assert_eq!(x << 4_u8, x << 4_usize);
+    /// assert_eq!(x << 256_u64, U256::ZERO);
+    /// assert_eq!(x << u128::MAX, U256::ZERO);
+    /// ```
     #[inline(always)]
     #[must_use]
     pub const fn wrapping_shl(self, rhs: usize) -> Self {
@@ -455,6 +614,18 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     ///
     /// Note: This differs from [`u64::wrapping_shr`] which first reduces `rhs`
     /// by `BITS` (which is IMHO not very useful).
+    ///
+    /// The `>>` and `>>=` operators use this for every primitive integer shift
+    /// amount, with the same out-of-range handling as
+    /// [`wrapping_shl`](Self::wrapping_shl).
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// let mut x = U256::from(0xf0_u64);
+    /// x >>= 4_u32;
+    /// assert_eq!(x, U256::from(0x0f_u64));
+    /// assert_eq!(x >> 4_i64, U256::ZERO);
+    /// ```
     #[inline(always)]
     #[must_use]
     pub const fn wrapping_shr(self, rhs: usize) -> Self {
@@ -477,6 +648,259 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         r
     }
 
+    /// Sign extends the value from bit `bit`.
+    ///
+    /// Treats bit `bit` as the sign bit of a two's complement number and copies
+    /// it into all higher bits. Returns `self` unchanged if `bit` is greater
+    /// than or equal to `BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::uint;
+    /// # uint!{
+    /// assert_eq!(0x0f_U16.sign_extend_from_bit(3), 0xffff_U16);
+    /// assert_eq!(0x0f_U16.sign_extend_from_bit(4), 0x0f_U16);
+    /// assert_eq!(0xff0f_U16.sign_extend_from_bit(4), 0x0f_U16);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn sign_extend_from_bit(mut self, bit: usize) -> Self {
+        if bit >= BITS {
+            return self;
+        }
+        let (limb, bit) = (bit / 64, bit % 64);
+        // Mask of `bit` and all bits below it.
+        let low = u64::MAX >> (63 - bit);
+        let fill = if self.limbs[limb] & (1 << bit) != 0 {
+            u64::MAX
+        } else {
+            0
+        };
+        self.limbs[limb] = (self.limbs[limb] & low) | (fill & !low);
+        let mut i = limb + 1;
+        while i < LIMBS {
+            self.limbs[i] = fill;
+            i += 1;
+        }
+        self.masked()
+    }
+
+    /// Sign extends the value from the most significant bit of byte
+    /// `byte_index`, where byte `0` is the least significant byte.
+    ///
+    /// This is the EVM `SIGNEXTEND` operation. Returns `self` unchanged if
+    /// `byte_index` is greater than or equal to [`Self::BYTES`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(0xff_U256.sign_extend_from_byte(0), U256::MAX);
+    /// assert_eq!(0x7f_U256.sign_extend_from_byte(0), 0x7f_U256);
+    /// assert_eq!(0xff_U256.sign_extend_from_byte(1), 0xff_U256);
+    /// assert_eq!(0xff_U256.sign_extend_from_byte(32), 0xff_U256);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn sign_extend_from_byte(self, byte_index: usize) -> Self {
+        if byte_index >= Self::BYTES {
+            return self;
+        }
+        self.sign_extend_from_bit(byte_index * 8 + 7)
+    }
+
+    /// Keeps the `bits` least significant bits and clears the rest. Returns
+    /// `self` unchanged if `bits >= BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::uint;
+    /// # uint!{
+    /// assert_eq!(0xabcd_U16.truncated_to(4), 0xd_U16);
+    /// assert_eq!(0xabcd_U16.truncated_to(0), 0_U16);
+    /// assert_eq!(0xabcd_U16.truncated_to(16), 0xabcd_U16);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn truncated_to(mut self, bits: usize) -> Self {
+        if bits >= BITS {
+            return self;
+        }
+        let (limb, bit) = (bits / 64, bits % 64);
+        self.limbs[limb] &= (1 << bit) - 1;
+        let mut i = limb + 1;
+        while i < LIMBS {
+            self.limbs[i] = 0;
+            i += 1;
+        }
+        self
+    }
+
+    /// Wraps the value to a width of `bits`, the runtime analogue of
+    /// converting to `Uint<bits, _>` with [`Self::wrapping_to`].
+    ///
+    /// Together with [`Self::fits_bits`] this lets a single wide type emulate
+    /// any narrower width chosen at runtime, e.g. all the EVM integer widths
+    /// in a [`U256`](crate::aliases::U256):
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let (a, b) = (0xff_U256, 0x02_U256);
+    /// assert_eq!(
+    ///     (a * b).wrapping_into_bits(8),
+    ///     U256::from((0xff_U8 * 0x02_U8).to::<u8>())
+    /// );
+    /// assert!(!(a * b).fits_bits(8));
+    /// assert!((a * b).fits_bits(9));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_into_bits(&self, bits: usize) -> Self {
+        self.truncated_to(bits)
+    }
+
+    /// Returns `true` if the value fits in `bits` bits, i.e. if
+    /// [`Self::wrapping_into_bits`] would not change it.
+    #[inline]
+    #[must_use]
+    pub const fn fits_bits(&self, bits: usize) -> bool {
+        self.bit_len() <= bits
+    }
+
+    /// Splits the value into its high and low halves.
+    ///
+    /// This is the inverse of [`Self::concat`].
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `2 * B2 != BITS` or if `L2` is not the number of
+    /// limbs for `B2` bits.
+    ///
+    /// ```compile_fail
+    /// # use ruint::aliases::U512;
+    /// let _ = U512::ZERO.split::<128, 2>();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let (hi, lo): (U256, U256) = ((1_U512 << 256_usize) + 2_U512).split();
+    /// assert_eq!((hi, lo), (1_U256, 2_U256));
+    /// assert_eq!(U512::concat(hi, lo), (1_U512 << 256_usize) + 2_U512);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split<const B2: usize, const L2: usize>(&self) -> (Uint<B2, L2>, Uint<B2, L2>) {
+        const { Self::assert_halves(B2, L2) }
+        let hi = Uint::wrapping_from_limbs_slice(self.wrapping_shr(B2).as_limbs());
+        let lo = Uint::wrapping_from_limbs_slice(self.as_limbs());
+        (hi, lo)
+    }
+
+    /// Joins a high and a low half into a value of twice their width.
+    ///
+    /// This is the inverse of [`Self::split`].
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `2 * B2 != BITS` or if `L2` is not the number of
+    /// limbs for `B2` bits.
+    #[inline]
+    #[must_use]
+    pub fn concat<const B2: usize, const L2: usize>(hi: Uint<B2, L2>, lo: Uint<B2, L2>) -> Self {
+        const { Self::assert_halves(B2, L2) }
+        let hi = Self::from_limbs_slice(hi.as_limbs()).wrapping_shl(B2);
+        hi.bitor(Self::from_limbs_slice(lo.as_limbs()))
+    }
+
+    #[inline]
+    #[track_caller]
+    const fn assert_halves(bits: usize, limbs: usize) {
+        assert!(2 * bits == BITS, "BITS must be equal to 2 * B2");
+        assert!(
+            limbs == crate::nlimbs(bits),
+            "L2 must be equal to nlimbs(B2)"
+        );
+    }
+
+    /// Splits the value at a runtime bit position, returning `(self >> bit,
+    /// self mod 2^bit)` in the original width.
+    ///
+    /// If `bit >= BITS` the high part is zero and the low part is `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::uint;
+    /// # uint!{
+    /// assert_eq!(0xabcd_U16.split_at(4), (0xabc_U16, 0xd_U16));
+    /// assert_eq!(0xabcd_U16.split_at(0), (0xabcd_U16, 0_U16));
+    /// assert_eq!(0xabcd_U16.split_at(16), (0_U16, 0xabcd_U16));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn split_at(&self, bit: usize) -> (Self, Self) {
+        (self.wrapping_shr(bit), self.truncated_to(bit))
+    }
+
+    /// Returns the value with the low `n` bits set, i.e. $2^n - 1$.
+    ///
+    /// Saturates to [`Self::MAX`] if `n >= BITS`, like
+    /// [`Self::truncated_to`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint!{
+    /// const ADDRESS_MASK: U256 = U256::ones(160);
+    /// assert_eq!(ADDRESS_MASK, (1_U256 << 160) - 1_U256);
+    /// assert_eq!(U256::ones(300), U256::MAX);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ones(n: usize) -> Self {
+        Self::MAX.truncated_to(n)
+    }
+
+    /// Returns the value with `byte` in every byte, truncated to `BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(U256::repeat_byte(0xff), U256::MAX);
+    /// assert_eq!(U256::repeat_byte(0xaa).count_ones(), 128);
+    /// assert_eq!(uint!(0xaaa_U12), ruint::Uint::<12, 1>::repeat_byte(0xaa));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn repeat_byte(byte: u8) -> Self {
+        Self::repeat_limb(u64::from_ne_bytes([byte; 8]))
+    }
+
+    /// Returns the value with `limb` in every limb, truncated to `BITS`.
+    #[inline]
+    #[must_use]
+    pub const fn repeat_limb(limb: u64) -> Self {
+        Self::from_limbs_unmasked([limb; LIMBS])
+    }
+
     /// Shifts the bits to the left by a specified amount, `rhs`, wrapping the
     /// truncated bits to the end of the resulting integer.
     #[inline]
@@ -501,6 +925,84 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         let rhs = rhs % BITS;
         self.rotate_left(BITS - rhs)
     }
+
+    /// Transposes a bit matrix with `N` rows of `BITS` bits, returning its
+    /// `BITS` columns of `N` bits each.
+    ///
+    /// Bit `i` of column `j` is bit `j` of row `i`. The matrix is transposed
+    /// in 64 × 64 bit blocks of limbs rather than bit by bit.
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `L` is not the number of limbs for `N` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, aliases::U8};
+    /// let rows = [
+    ///     U8::from(0b0000_0011),
+    ///     U8::from(0b0000_0110),
+    ///     U8::from(0b0000_1100),
+    /// ];
+    /// let columns = U8::transpose_bits::<3, 1>(&rows);
+    /// assert_eq!(columns.len(), 8);
+    /// assert_eq!(
+    ///     columns[..5],
+    ///     [0b001, 0b011, 0b110, 0b100, 0].map(Uint::<3, 1>::from)
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn transpose_bits<const N: usize, const L: usize>(
+        rows: &[Self; N],
+    ) -> alloc::vec::Vec<Uint<N, L>> {
+        const { Self::assert_columns(N, L) }
+        let mut columns = alloc::vec![Uint::<N, L>::ZERO; BITS];
+        let mut block = [0; 64];
+        for (row_block, rows) in rows.chunks(64).enumerate() {
+            for limb in 0..LIMBS {
+                for (word, row) in block.iter_mut().zip(rows) {
+                    *word = row.limbs[limb];
+                }
+                block[rows.len()..].fill(0);
+                transpose_64x64(&mut block);
+                for (column, &word) in columns[limb * 64..].iter_mut().zip(&block) {
+                    column.limbs[row_block] = word;
+                }
+            }
+        }
+        columns
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[track_caller]
+    const fn assert_columns(bits: usize, limbs: usize) {
+        assert!(limbs == crate::nlimbs(bits), "L must be equal to nlimbs(N)");
+    }
+}
+
+/// Transposes a 64 × 64 bit matrix in place, where bit `j` of `matrix[i]` is
+/// the entry in row `i` and column `j`.
+///
+/// Swaps the off-diagonal 32 × 32 blocks, then the 16 × 16 blocks within
+/// each, and so on down to single bits.
+#[cfg(feature = "alloc")]
+fn transpose_64x64(matrix: &mut [u64; 64]) {
+    let mut width = 32;
+    let mut mask = 0x0000_0000_ffff_ffff_u64;
+    while width != 0 {
+        let mut k = 0;
+        while k < 64 {
+            let t = ((matrix[k] >> width) ^ matrix[k + width]) & mask;
+            matrix[k] ^= t << width;
+            matrix[k + width] ^= t;
+            k = (k + width + 1) & !width;
+        }
+        width >>= 1;
+        mask ^= mask << width;
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Not for Uint<BITS, LIMBS> {
@@ -508,7 +1010,13 @@ impl<const BITS: usize, const LIMBS: usize> Not for Uint<BITS, LIMBS> {
 
     #[inline]
     fn not(self) -> Self::Output {
-        self.not()
+        #[cfg(feature = "portable-simd")]
+        if LIMBS >= crate::simd::LANES {
+            let mut result = self;
+            crate::simd::not_assign(&mut result.limbs);
+            return result.masked();
+        }
+        Self::not(self)
     }
 }
 
@@ -537,6 +1045,11 @@ macro_rules! impl_bit_op {
         {
             #[inline]
             fn $fn_assign(&mut self, rhs: &Uint<BITS, LIMBS>) {
+                #[cfg(feature = "portable-simd")]
+                if LIMBS >= crate::simd::LANES {
+                    crate::simd::$fn_assign(&mut self.limbs, &rhs.limbs);
+                    return;
+                }
                 for i in 0..LIMBS {
                     u64::$fn_assign(&mut self.limbs[i], rhs.limbs[i]);
                 }
@@ -610,6 +1123,122 @@ impl_bit_op!(|, |=, BitOr,  bitor,  BitOrAssign,  bitor_assign);
 impl_bit_op!(&, &=, BitAnd, bitand, BitAndAssign, bitand_assign);
 impl_bit_op!(^, ^=, BitXor, bitxor, BitXorAssign, bitxor_assign);
 
+/// Returns limb `index` of a primitive bitwise operand, widened to `u128`.
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)] // Intentional.
+const fn primitive_limb(rhs: u128, index: usize) -> u64 {
+    match index {
+        0 => rhs as u64,
+        1 => (rhs >> 64) as u64,
+        _ => 0,
+    }
+}
+
+macro_rules! impl_bit_op_primitive {
+    (@impl $trait:ident, $fn:ident, $trait_assign:ident, $fn_assign:ident, $u:ty, $(#[$attr:meta])*) => {
+        impl<const BITS: usize, const LIMBS: usize> $trait_assign<$u> for Uint<BITS, LIMBS> {
+            #[inline]
+            fn $fn_assign(&mut self, rhs: $u) {
+                let rhs = u128::from(rhs);
+                for (i, limb) in self.limbs.iter_mut().enumerate() {
+                    u64::$fn_assign(limb, primitive_limb(rhs, i));
+                }
+                // Bits of `rhs` above `BITS` must not leak into the result.
+                self.apply_mask();
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> $trait_assign<&$u> for Uint<BITS, LIMBS> {
+            #[inline(always)]
+            fn $fn_assign(&mut self, rhs: &$u) {
+                self.$fn_assign(*rhs);
+            }
+        }
+
+        $(#[$attr])*
+        impl<const BITS: usize, const LIMBS: usize> $trait<$u> for Uint<BITS, LIMBS> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(mut self, rhs: $u) -> Self::Output {
+                self.$fn_assign(rhs);
+                self
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> $trait<&$u> for Uint<BITS, LIMBS> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(mut self, rhs: &$u) -> Self::Output {
+                self.$fn_assign(*rhs);
+                self
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> $trait<$u> for &Uint<BITS, LIMBS> {
+            type Output = Uint<BITS, LIMBS>;
+
+            #[inline(always)]
+            fn $fn(self, rhs: $u) -> Self::Output {
+                let mut result = *self;
+                result.$fn_assign(rhs);
+                result
+            }
+        }
+    };
+
+    ($trait:ident, $fn:ident, $trait_assign:ident, $fn_assign:ident, $(#[$attr:meta])*) => {
+        impl_bit_op_primitive!(@impl $trait, $fn, $trait_assign, $fn_assign, u8,);
+        impl_bit_op_primitive!(@impl $trait, $fn, $trait_assign, $fn_assign, u16,);
+        impl_bit_op_primitive!(@impl $trait, $fn, $trait_assign, $fn_assign, u32,);
+        impl_bit_op_primitive!(@impl $trait, $fn, $trait_assign, $fn_assign, u64, $(#[$attr])*);
+        impl_bit_op_primitive!(@impl $trait, $fn, $trait_assign, $fn_assign, u128,);
+    };
+}
+
+impl_bit_op_primitive!(BitOr, bitor, BitOrAssign, bitor_assign,
+    /// Bitwise `|` with a primitive, truncated to `BITS` first so that no bits
+    /// beyond the width of the number are set. All unsigned primitives and
+    /// their references are supported, with and without assignment.
+    ///
+    /// ```
+    /// # use ruint::{Uint, aliases::U256};
+    /// assert_eq!(U256::from(0x1234) | 1_u64, U256::from(0x1235));
+    ///
+    /// let mut x = Uint::<7, 1>::from(0x0f);
+    /// x |= 0xf0_u8;
+    /// assert_eq!(x, Uint::<7, 1>::MAX);
+    /// ```
+);
+impl_bit_op_primitive!(BitAnd, bitand, BitAndAssign, bitand_assign,
+    /// Bitwise `&` with a primitive. All unsigned primitives and their
+    /// references are supported, with and without assignment.
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// assert_eq!(U256::from(0x1234) & 0xff_u64, U256::from(0x34));
+    ///
+    /// let mut x = U256::MAX;
+    /// x &= u128::MAX;
+    /// assert_eq!(x, U256::from(u128::MAX));
+    /// ```
+);
+impl_bit_op_primitive!(BitXor, bitxor, BitXorAssign, bitxor_assign,
+    /// Bitwise `^` with a primitive, truncated to `BITS` first so that no bits
+    /// beyond the width of the number are set. All unsigned primitives and
+    /// their references are supported, with and without assignment.
+    ///
+    /// ```
+    /// # use ruint::{Uint, aliases::U256};
+    /// assert_eq!(&U256::from(0x1234) ^ 0x1200_u16, U256::from(0x34));
+    ///
+    /// let mut x = Uint::<7, 1>::MAX;
+    /// x ^= u64::MAX;
+    /// assert_eq!(x, Uint::<7, 1>::ZERO);
+    /// ```
+);
+
 impl<const BITS: usize, const LIMBS: usize> Shl<Self> for Uint<BITS, LIMBS> {
     type Output = Self;
 
@@ -674,15 +1303,25 @@ impl<const BITS: usize, const LIMBS: usize> ShrAssign<&Self> for Uint<BITS, LIMB
     }
 }
 
+/// Converts a primitive shift amount to `usize` for the shift operators.
+///
+/// All amount types share the semantics of [`Uint::wrapping_shl`] and
+/// [`Uint::wrapping_shr`]: shifting by `BITS` or more yields zero. Amounts
+/// that do not fit a `usize` (including negative ones) are therefore clamped
+/// to [`usize::MAX`], which shifts everything out, instead of being truncated.
+#[inline(always)]
+fn shift_amount<T: TryInto<usize>>(rhs: T) -> usize {
+    rhs.try_into().unwrap_or(usize::MAX)
+}
+
 macro_rules! impl_shift {
     (@main $u:ty) => {
         impl<const BITS: usize, const LIMBS: usize> Shl<$u> for Uint<BITS, LIMBS> {
             type Output = Self;
 
             #[inline(always)]
-            #[allow(clippy::cast_possible_truncation)]
             fn shl(self, rhs: $u) -> Self::Output {
-                self.wrapping_shl(rhs as usize)
+                self.wrapping_shl(shift_amount(rhs))
             }
         }
 
@@ -690,9 +1329,8 @@ macro_rules! impl_shift {
             type Output = Self;
 
             #[inline(always)]
-            #[allow(clippy::cast_possible_truncation)]
             fn shr(self, rhs: $u) -> Self::Output {
-                self.wrapping_shr(rhs as usize)
+                self.wrapping_shr(shift_amount(rhs))
             }
         }
     };
@@ -746,17 +1384,15 @@ macro_rules! impl_shift {
     };
 }
 
-impl_shift!(usize, u8, u16, u32, isize, i8, i16, i32);
-
-// Only when losslessy castable to usize.
-#[cfg(target_pointer_width = "64")]
-impl_shift!(u64, i64);
+impl_shift!(
+    usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128
+);
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        aliases::{U128, U256},
+        aliases::{U128, U256, U512},
         const_for, nlimbs,
     };
     use core::cmp::min;
@@ -807,6 +1443,115 @@ mod tests {
         assert_eq!(Uint::<1, 1>::ONE.leading_ones(), 1);
     }
 
+    #[test]
+    fn test_bit_counts_edges() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert_eq!(U::ZERO.leading_ones(), 0);
+            assert_eq!(U::ZERO.trailing_ones(), 0);
+            assert_eq!(U::ZERO.count_zeros(), BITS);
+            assert_eq!(U::MAX.leading_ones(), BITS);
+            assert_eq!(U::MAX.trailing_ones(), BITS);
+            assert_eq!(U::MAX.count_zeros(), 0);
+
+            // Clear a single bit on either side of each limb boundary.
+            for bit in [63, 64, 127, 128, BITS - 1] {
+                if bit >= BITS {
+                    continue;
+                }
+                let value = U::MAX ^ (U::ONE << bit);
+                assert_eq!(value.leading_ones(), BITS - bit - 1);
+                assert_eq!(value.trailing_ones(), bit);
+                assert_eq!(value.count_zeros(), 1);
+            }
+
+            proptest!(|(value: U)| {
+                assert_eq!(value.leading_ones(), (!value).leading_zeros());
+                assert_eq!(value.trailing_ones(), (!value).trailing_zeros());
+                assert_eq!(value.count_zeros(), (!value).count_ones());
+            });
+        });
+    }
+
+    #[test]
+    fn test_const_introspection() {
+        type U = Uint<130, 3>;
+        const P: U = U::from_limbs([0x30, 0, 0x2]);
+        const BIT_LEN: usize = P.bit_len();
+        const BYTE_LEN: usize = P.byte_len();
+        const LEADING_ZEROS: usize = P.leading_zeros();
+        const TRAILING_ZEROS: usize = P.trailing_zeros();
+        const COUNT_ONES: usize = P.count_ones();
+        const IS_ZERO: bool = P.is_zero();
+        const LOG2: Option<usize> = P.checked_log2();
+        const ZERO_LOG2: Option<usize> = U::ZERO.checked_log2();
+        assert_eq!(BIT_LEN, P.bit_len());
+        assert_eq!(BIT_LEN, 130);
+        assert_eq!(BYTE_LEN, 17);
+        assert_eq!(LEADING_ZEROS, 0);
+        assert_eq!(TRAILING_ZEROS, 4);
+        assert_eq!(COUNT_ONES, 3);
+        assert_eq!(IS_ZERO, P.is_zero());
+        assert_eq!(LOG2, Some(129));
+        assert_eq!(ZERO_LOG2, None);
+        assert_eq!(Uint::<1, 1>::ONE.checked_log2(), Some(0));
+        assert_eq!(Uint::<0, 0>::ZERO.checked_log2(), None);
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let zeros = value
+                    .as_limbs()
+                    .iter()
+                    .position(|&limb| limb != 0)
+                    .map_or(BITS, |n| n * 64 + value.as_limbs()[n].trailing_zeros() as usize);
+                assert_eq!(value.trailing_zeros(), zeros);
+                let log2 = (0..BITS).rev().find(|&i| value.bit(i));
+                assert_eq!(value.checked_log2(), log2);
+            });
+        });
+    }
+
+    #[test]
+    fn test_ones_repeat() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            const ONES: U = U::ones(BITS);
+            assert_eq!(ONES, U::MAX);
+            assert_eq!(U::ones(0), U::ZERO);
+            assert_eq!(U::ones(usize::MAX), U::MAX);
+            assert_eq!(U::repeat_byte(0xff), U::MAX);
+            assert_eq!(U::repeat_limb(u64::MAX), U::MAX);
+            assert_eq!(U::repeat_byte(0), U::ZERO);
+            proptest!(|(n in 0..=BITS + 1, byte: u8, limb: u64)| {
+                let ones = U::ones(n);
+                assert_eq!(ones.count_ones(), min(n, BITS));
+                assert_eq!(ones.trailing_ones(), min(n, BITS));
+                let repeated = U::repeat_byte(byte);
+                for (i, &actual) in repeated.as_le_bytes().iter().enumerate() {
+                    let expected = if BITS % 8 != 0 && i == U::BYTES - 1 {
+                        byte & ((1 << (BITS % 8)) - 1)
+                    } else {
+                        byte
+                    };
+                    assert_eq!(actual, expected);
+                }
+                assert_eq!(U::repeat_limb(limb), U::overflowing_from_limbs_slice(&[limb; LIMBS]).0);
+            });
+        });
+
+        // Masking of the top partial byte.
+        type U12 = Uint<12, 1>;
+        const PATTERN: U12 = U12::repeat_byte(0xaa);
+        assert_eq!(PATTERN, U12::from(0xaaa));
+        let repeated = Uint::<65, 2>::repeat_byte(0x81);
+        assert_eq!(repeated, Uint::from_limbs([0x8181_8181_8181_8181, 1]));
+        assert_eq!(Uint::<65, 2>::repeat_limb(2), Uint::from_limbs([2, 0]));
+    }
+
     #[test]
     fn test_most_significant_bits() {
         const_for!(BITS in NON_ZERO {
@@ -891,6 +1636,84 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_set_bit() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let mut indices = vec![0, BITS - 1, BITS / 2];
+            indices.extend([63, 64, 65, 127, 128].into_iter().filter(|&i| i < BITS));
+            for index in indices {
+                let one = U::ONE << index;
+                let mut x = U::ZERO;
+                assert!(x.checked_set_bit(index, true));
+                assert_eq!(x, one);
+                assert_eq!(U::ZERO.with_bit(index, true), one);
+                assert_eq!(U::MAX.with_bit(index, false), !one);
+                x.toggle_bit(index);
+                assert_eq!(x, U::ZERO);
+                x.toggle_bit(index);
+                assert_eq!(x, one);
+                x.set_bit(index, false);
+                assert_eq!(x, U::ZERO);
+            }
+            let mut x = U::MAX;
+            assert!(!x.checked_set_bit(BITS, false));
+            assert!(!x.checked_set_bit(BITS, true));
+            assert!(!x.checked_set_bit(LIMBS * 64, true));
+            assert_eq!(x, U::MAX);
+            let mut x = U::ZERO;
+            assert!(!x.checked_set_bit(BITS, true));
+            assert_eq!(x, U::ZERO);
+            assert!(std::panic::catch_unwind(|| U::ZERO.with_bit(BITS, true)).is_err());
+            assert!(std::panic::catch_unwind(|| U::MAX.with_bit(BITS, false)).is_err());
+            assert!(std::panic::catch_unwind(|| U::ZERO.clone().set_bit(BITS, true)).is_err());
+            assert!(std::panic::catch_unwind(|| U::ZERO.clone().toggle_bit(BITS)).is_err());
+        });
+        let mut x = Uint::<0, 0>::ZERO;
+        assert!(!x.checked_set_bit(0, true));
+    }
+
+    #[test]
+    #[allow(irrefutable_let_patterns)]
+    fn test_shift_amount_types() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let edges = [BITS / 2, BITS.saturating_sub(1), BITS, BITS + 1];
+                for amount in [0, 1, 7, 63, 64, 65, 127, 1000].into_iter().chain(edges) {
+                    let left = value.wrapping_shl(amount);
+                    let right = value.wrapping_shr(amount);
+                    macro_rules! check {
+                        ($($t:ty),*) => {$(
+                            if let Ok(rhs) = <$t>::try_from(amount) {
+                                assert_eq!(value << rhs, left);
+                                assert_eq!(value >> rhs, right);
+                                assert_eq!(value << &rhs, left);
+                                assert_eq!(value >> &rhs, right);
+                                let mut x = value;
+                                x <<= rhs;
+                                assert_eq!(x, left);
+                                let mut x = value;
+                                x >>= rhs;
+                                assert_eq!(x, right);
+                            }
+                        )*};
+                    }
+                    check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+                }
+                for rhs in [u64::MAX, u64::from(u32::MAX) + 1] {
+                    assert_eq!(value << rhs, U::ZERO);
+                    assert_eq!(value >> rhs, U::ZERO);
+                }
+                assert_eq!(value << u128::MAX, U::ZERO);
+                assert_eq!(value >> -1_i32, U::ZERO);
+                assert_eq!(value << i128::MIN, U::ZERO);
+            });
+        });
+    }
+
     #[test]
     fn test_shift_very_big_rhs() {
         type U = Uint<128, 2>;
@@ -934,6 +1757,115 @@ mod tests {
         });
     }
 
+    #[test]
+    #[allow(clippy::absurd_extreme_comparisons)] // Generated code
+    fn test_sign_extend() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U, bit in 0..=BITS + 2)| {
+                let extended = value.sign_extend_from_bit(bit);
+                if bit >= BITS {
+                    assert_eq!(extended, value);
+                } else {
+                    let shift = BITS - bit - 1;
+                    assert_eq!(extended, (value << shift).arithmetic_shr(shift));
+                }
+            });
+        });
+        proptest!(|(value: u128, bit in 0_usize..128)| {
+            let shift = 127 - bit;
+            #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+            let expected = (((value << shift) as i128) >> shift) as u128;
+            assert_eq!(U128::from(value).sign_extend_from_bit(bit), U128::from(expected));
+        });
+    }
+
+    #[test]
+    fn test_runtime_width() {
+        macro_rules! check {
+            ($($bits:literal),*) => {$({
+                type N = Uint<$bits, { nlimbs($bits) }>;
+                proptest!(|(a: U256, b: U256)| {
+                    let (na, nb) = (a.wrapping_to::<N>(), b.wrapping_to::<N>());
+                    let (a, b) = (a.wrapping_into_bits($bits), b.wrapping_into_bits($bits));
+                    assert_eq!(a, U256::from(na));
+                    assert!(a.fits_bits($bits));
+                    // Wide enough that the sum and product never wrap.
+                    let (a, b) = (U512::from(a), U512::from(b));
+                    assert_eq!((a + b).wrapping_into_bits($bits), U512::from(na + nb));
+                    assert_eq!((a * b).wrapping_into_bits($bits), U512::from(na * nb));
+                    assert_eq!((a + b).fits_bits($bits), na.checked_add(nb).is_some());
+                    assert_eq!((a * b).fits_bits($bits), na.checked_mul(nb).is_some());
+                });
+            })*};
+        }
+        check!(0, 1, 8, 63, 64, 65, 100, 128, 160, 200, 255, 256);
+
+        assert_eq!(U256::MAX.truncated_to(300), U256::MAX);
+        assert_eq!(U256::MAX.truncated_to(130), U256::MAX >> 126_usize);
+        assert!(U256::ZERO.fits_bits(0));
+        assert!(!U256::ONE.fits_bits(0));
+        assert!(U256::MAX.fits_bits(256));
+        assert_eq!(Uint::<0, 0>::ZERO.truncated_to(0), Uint::ZERO);
+    }
+
+    #[test]
+    fn test_transpose_bits() {
+        // Hand-checked 8 × 8: an upper triangle becomes a lower triangle, and
+        // a single off-diagonal bit moves across the diagonal.
+        type U8 = Uint<8, 1>;
+        let rows: [U8; 8] = core::array::from_fn(|i| U8::MAX << i);
+        let columns = U8::transpose_bits::<8, 1>(&rows);
+        let expected: [U8; 8] = core::array::from_fn(|j| U8::MAX >> (7 - j));
+        assert_eq!(columns, expected);
+        let mut rows = [U8::ZERO; 8];
+        rows[2] = U8::from(0b0010_0000);
+        let columns = U8::transpose_bits::<8, 1>(&rows);
+        assert_eq!(columns[5], U8::from(0b0000_0100));
+        assert_eq!(columns.iter().filter(|c| !c.is_zero()).count(), 1);
+
+        macro_rules! check {
+            ($($bits:literal x $n:literal),*) => {$({
+                type R = Uint<$bits, { nlimbs($bits) }>;
+                type C = Uint<$n, { nlimbs($n) }>;
+                proptest!(|(rows: [R; $n])| {
+                    let columns = R::transpose_bits::<$n, { nlimbs($n) }>(&rows);
+                    assert_eq!(columns.len(), $bits);
+                    for (j, column) in columns.iter().enumerate() {
+                        for (i, row) in rows.iter().enumerate() {
+                            assert_eq!(column.bit(i), row.bit(j));
+                        }
+                    }
+                    let columns: [C; $bits] = columns.try_into().unwrap();
+                    assert_eq!(C::transpose_bits::<$bits, { nlimbs($bits) }>(&columns), rows);
+                });
+            })*};
+        }
+        check!(256 x 64, 64 x 256, 65 x 3, 3 x 65, 130 x 70, 1 x 1, 0 x 5, 5 x 0);
+    }
+
+    #[test]
+    fn test_sign_extend_evm() {
+        // Vectors from the Ethereum execution spec tests for `SIGNEXTEND`.
+        uint! {
+            for (byte, value, expected) in [
+                (0_U256, 0xff_U256, U256::MAX),
+                (0_U256, 0x7f_U256, 0x7f_U256),
+                (0_U256, 0x0f0f_U256, 0x0f_U256),
+                (1_U256, 0x8000_U256, U256::MAX - 0x7fff_U256),
+                (1_U256, 0x7fff_U256, 0x7fff_U256),
+                (0_U256, 0x12345680_U256, U256::MAX - 0x7f_U256),
+                (31_U256, U256::MAX, U256::MAX),
+                (32_U256, 0xff_U256, 0xff_U256),
+                (U256::MAX, 0xff_U256, 0xff_U256),
+            ] {
+                let byte = byte.saturating_to::<usize>();
+                assert_eq!(value.sign_extend_from_byte(byte), expected);
+            }
+        }
+    }
+
     #[test]
     fn test_overflowing_shr() {
         // Test: Single limb right shift from 40u64 by 1 bit.
@@ -1047,4 +1979,68 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_bit_op_primitive() {
+        macro_rules! check {
+            ($($bits:literal),*) => {$({
+                type U = Uint<$bits, { nlimbs($bits) }>;
+                let valid = |x: U| assert_eq!(x, U::from_limbs(*x.as_limbs()));
+                valid(!U::ZERO);
+                assert_eq!(!U::ZERO, U::MAX);
+                proptest!(|(x: U, a: u8, b: u64, c: u128)| {
+                    valid(!x);
+                    assert_eq!(!!x, x);
+                    for (result, expected) in [
+                        (x & a, x & U::wrapping_from(a)),
+                        (x | a, x | U::wrapping_from(a)),
+                        (x ^ a, x ^ U::wrapping_from(a)),
+                        (x & b, x & U::wrapping_from(b)),
+                        (x | b, x | U::wrapping_from(b)),
+                        (x ^ b, x ^ U::wrapping_from(b)),
+                        (&x & c, x & U::wrapping_from(c)),
+                        (&x | c, x | U::wrapping_from(c)),
+                        (&x ^ c, x ^ U::wrapping_from(c)),
+                    ] {
+                        valid(result);
+                        assert_eq!(result, expected);
+                    }
+                    let mut y = x;
+                    y |= &b;
+                    valid(y);
+                    y ^= c;
+                    valid(y);
+                    y &= a;
+                    valid(y);
+                    let (a, b, c) = (U::wrapping_from(a), U::wrapping_from(b), U::wrapping_from(c));
+                    assert_eq!(y, ((x | b) ^ c) & a);
+                });
+            })*};
+        }
+        check!(7, 64, 65);
+        assert_eq!(Uint::<7, 1>::ZERO | u64::MAX, Uint::<7, 1>::MAX);
+        assert_eq!(Uint::<65, 2>::ZERO ^ u128::MAX, Uint::<65, 2>::MAX);
+        assert_eq!(Uint::<0, 0>::ZERO | u128::MAX, Uint::<0, 0>::ZERO);
+    }
+
+    #[test]
+    fn test_split_concat() {
+        macro_rules! check {
+            ($($bits:literal),*) => {$({
+                type U = Uint<{ 2 * $bits }, { nlimbs(2 * $bits) }>;
+                type H = Uint<$bits, { nlimbs($bits) }>;
+                proptest!(|(value: U, bit in 0..=2 * $bits + 1_usize)| {
+                    let (hi, lo): (H, H) = value.split();
+                    assert_eq!(U::from(hi), value >> $bits);
+                    assert_eq!(U::from(lo), value.truncated_to($bits));
+                    assert_eq!(U::concat(hi, lo), value);
+                    let (hi, lo) = value.split_at(bit);
+                    assert_eq!(hi, value >> bit);
+                    assert_eq!((hi << bit) | lo, value);
+                    assert!(lo.fits_bits(bit));
+                });
+            })*};
+        }
+        check!(0, 1, 7, 32, 64, 65, 128, 256);
+    }
 }
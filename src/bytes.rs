@@ -1,10 +1,178 @@
 use crate::Uint;
-use core::slice;
+use core::{fmt, slice};
 
 #[cfg(feature = "alloc")]
 #[allow(unused_imports)]
 use alloc::{borrow::Cow, vec::Vec};
 
+/// Error for [`Uint::from_be_slice_fallible`] and
+/// [`Uint::from_le_slice_fallible`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromBytesError {
+    /// The slice is longer than [`Uint::BYTES`].
+    TooManyBytes {
+        /// The number of bytes of the [`Uint`].
+        expected: usize,
+        /// The length of the slice.
+        got:      usize,
+    },
+
+    /// The most significant byte has bits set beyond `BITS`.
+    HighBitsSet {
+        /// The bit size of the [`Uint`].
+        expected: usize,
+        /// The bit length of the value.
+        bit_len:  usize,
+    },
+}
+
+impl core::error::Error for FromBytesError {}
+
+impl fmt::Display for FromBytesError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyBytes { expected, got } => {
+                write!(f, "expected at most {expected} bytes, got {got}")
+            }
+            Self::HighBitsSet { expected, bit_len } => {
+                write!(f, "expected at most {expected} bits, got {bit_len}")
+            }
+        }
+    }
+}
+
+/// Error for [`Uint::from_compact_bytes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecodeError {
+    /// The input ends before the encoded value.
+    Truncated {
+        /// The length of the encoding.
+        expected: usize,
+        /// The length of the input.
+        got:      usize,
+    },
+
+    /// The length prefix is larger than [`Uint::BYTES`].
+    TooManyBytes {
+        /// The number of bytes of the [`Uint`].
+        expected: usize,
+        /// The length prefix.
+        got:      usize,
+    },
+
+    /// The value has leading zero bytes.
+    NonCanonical,
+
+    /// The most significant byte has bits set beyond `BITS`.
+    HighBitsSet {
+        /// The bit size of the [`Uint`].
+        expected: usize,
+        /// The bit length of the value.
+        bit_len:  usize,
+    },
+}
+
+impl core::error::Error for DecodeError {}
+
+impl fmt::Display for DecodeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated { expected, got } => {
+                write!(f, "expected {expected} bytes of input, got {got}")
+            }
+            Self::TooManyBytes { expected, got } => {
+                write!(f, "expected at most {expected} bytes, got {got}")
+            }
+            Self::NonCanonical => f.write_str("non-canonical encoding with leading zero bytes"),
+            Self::HighBitsSet { expected, bit_len } => {
+                write!(f, "expected at most {expected} bits, got {bit_len}")
+            }
+        }
+    }
+}
+
+/// The order of the words in [`Uint::from_words`] and [`Uint::to_words`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// The most significant word comes first.
+    MostSignificantFirst,
+
+    /// The least significant word comes first.
+    LeastSignificantFirst,
+}
+
+/// The order of the bytes within each word in [`Uint::from_words`] and
+/// [`Uint::to_words`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// The most significant byte of a word comes first.
+    Big,
+
+    /// The least significant byte of a word comes first.
+    Little,
+}
+
+impl Order {
+    /// Returns the significance of the word at `index` out of `len`.
+    #[inline]
+    const fn significance(self, index: usize, len: usize) -> usize {
+        match self {
+            Self::MostSignificantFirst => len - 1 - index,
+            Self::LeastSignificantFirst => index,
+        }
+    }
+}
+
+impl Endian {
+    /// Returns the significance of the byte at `index` in a word of `size`
+    /// bytes.
+    #[inline]
+    const fn significance(self, index: usize, size: usize) -> usize {
+        match self {
+            Self::Big => size - 1 - index,
+            Self::Little => index,
+        }
+    }
+}
+
+/// Error for [`Uint::from_words`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImportError {
+    /// The word size is zero.
+    ZeroWordSize,
+
+    /// The length of the input is not a multiple of the word size.
+    PartialWord {
+        /// The word size in bytes.
+        word_size: usize,
+        /// The length of the input in bytes.
+        len:       usize,
+    },
+
+    /// The value is too large to fit the [`Uint`].
+    Overflow,
+}
+
+impl core::error::Error for ImportError {}
+
+impl fmt::Display for ImportError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroWordSize => f.write_str("word size is zero"),
+            Self::PartialWord { word_size, len } => {
+                write!(
+                    f,
+                    "{len} bytes is not a whole number of {word_size} byte words"
+                )
+            }
+            Self::Overflow => f.write_str("the value is too large to fit the target type"),
+        }
+    }
+}
+
 // OPT: *_to_smallvec to avoid allocation.
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// The size of this integer type in bytes. Note that some bits may be
@@ -209,6 +377,36 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         Self::from_be_slice(&bytes)
     }
 
+    /// Converts a reference to a big-endian byte array of size exactly
+    /// [`Self::BYTES`] to [`Uint`] at compile time.
+    ///
+    /// This is the companion of [`include_bytes!`] for embedded blobs like
+    /// public keys, which are only available by reference.
+    ///
+    /// ```
+    /// # use ruint::aliases::U64;
+    /// const KEY: U64 = U64::from_static_be_bytes(b"\x01\x23\x45\x67\x89\xab\xcd\xef");
+    /// assert_eq!(KEY, U64::from(0x0123_4567_89ab_cdef_u64));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `N` is not exactly [`Self::BYTES`].
+    ///
+    /// ```compile_fail
+    /// # use ruint::aliases::U64;
+    /// const KEY: U64 = U64::from_static_be_bytes(&[0; 7]);
+    /// ```
+    ///
+    /// Panics if the value is too large for the bit-size of the Uint.
+    #[must_use]
+    #[track_caller]
+    #[inline]
+    pub const fn from_static_be_bytes<const N: usize>(bytes: &[u8; N]) -> Self {
+        const { Self::assert_bytes(N) }
+        Self::from_be_slice(bytes)
+    }
+
     /// Creates a new integer from a big endian slice of bytes.
     ///
     /// The slice is interpreted as a big endian number, and must be at most
@@ -221,9 +419,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[track_caller]
     #[inline]
     pub const fn from_be_slice(bytes: &[u8]) -> Self {
-        match Self::try_from_be_slice(bytes) {
-            Some(value) => value,
-            None => panic!("Value too large for Uint"),
+        match Self::from_be_slice_fallible(bytes) {
+            Ok(value) => value,
+            Err(_) => panic!("Value too large for Uint"),
         }
     }
 
@@ -236,11 +434,96 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[must_use]
     #[inline]
     pub const fn try_from_be_slice(bytes: &[u8]) -> Option<Self> {
-        if bytes.len() > Self::BYTES {
+        match Self::from_be_slice_fallible(bytes) {
+            Ok(value) => Some(value),
+            Err(_) => None,
+        }
+    }
+
+    /// Creates a new integer from a big endian stream of bytes, without
+    /// first collecting them into a slice.
+    ///
+    /// The stream may have any length and leading zero bytes are ignored.
+    ///
+    /// Returns [`None`] if the value is larger than fits the [`Uint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U64;
+    /// let bytes = [0x00, 0x00, 0x01, 0x02, 0x03];
+    /// assert_eq!(U64::from_chunks_be(bytes), Some(U64::from(0x01_02_03)));
+    /// assert_eq!(U64::from_chunks_be([0xff; 9]), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_chunks_be<I: IntoIterator<Item = u8>>(bytes: I) -> Option<Self> {
+        // Shift whole limbs in at the least significant end, so each byte is
+        // only touched once.
+        let mut limbs = [0_u64; LIMBS];
+        let mut shift_in = |word: u64, bits: usize| {
+            if bits == 0 {
+                return true;
+            }
+            let Some(&top) = limbs.last() else {
+                return word == 0;
+            };
+            if bits == 64 {
+                if top != 0 {
+                    return false;
+                }
+                limbs.copy_within(..LIMBS - 1, 1);
+                limbs[0] = word;
+            } else {
+                if top >> (64 - bits) != 0 {
+                    return false;
+                }
+                let mut carry = word;
+                for limb in &mut limbs {
+                    (*limb, carry) = ((*limb << bits) | carry, *limb >> (64 - bits));
+                }
+            }
+            true
+        };
+        let mut word = 0;
+        let mut len = 0;
+        for byte in bytes {
+            word = (word << 8) | u64::from(byte);
+            len += 1;
+            if len == 8 {
+                if !shift_in(word, 64) {
+                    return None;
+                }
+                (word, len) = (0, 0);
+            }
+        }
+        if !shift_in(word, 8 * len) {
             return None;
         }
+        Self::checked_from_limbs_slice(&limbs)
+    }
 
-        if Self::BYTES % 8 == 0 && bytes.len() == Self::BYTES {
+    /// Creates a new integer from a big endian slice of bytes.
+    ///
+    /// The slice is interpreted as a big endian number, and must be at most
+    /// [`Self::BYTES`] long.
+    ///
+    /// # Errors
+    ///
+    /// * [`FromBytesError::TooManyBytes`] if the slice is longer than
+    ///   [`Self::BYTES`].
+    /// * [`FromBytesError::HighBitsSet`] if the value does not fit in `BITS`
+    ///   bits.
+    #[inline]
+    pub const fn from_be_slice_fallible(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        if bytes.len() > Self::BYTES {
+            return Err(FromBytesError::TooManyBytes {
+                expected: Self::BYTES,
+                got:      bytes.len(),
+            });
+        }
+
+        if BITS % 64 == 0 && bytes.len() == Self::BYTES {
             // Optimized implementation for full-limb types.
             let mut limbs = [0; LIMBS];
             let end = bytes.as_ptr_range().end;
@@ -249,7 +532,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 limbs[i] = u64::from_be_bytes(unsafe { *end.sub((i + 1) * 8).cast() });
                 i += 1;
             }
-            return Some(Self::from_limbs(limbs));
+            return Ok(Self::from_limbs(limbs));
         }
 
         let mut limbs = [0; LIMBS];
@@ -262,9 +545,12 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             i += 1;
         }
         if LIMBS > 0 && limbs[LIMBS - 1] > Self::MASK {
-            return None;
+            return Err(FromBytesError::HighBitsSet {
+                expected: BITS,
+                bit_len:  64 * LIMBS - limbs[LIMBS - 1].leading_zeros() as usize,
+            });
         }
-        Some(Self::from_limbs(limbs))
+        Ok(Self::from_limbs(limbs))
     }
 
     /// Converts a little-endian byte array of size exactly
@@ -287,6 +573,35 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         Self::from_le_slice(&bytes)
     }
 
+    /// Converts a reference to a little-endian byte array of size exactly
+    /// [`Self::BYTES`] to [`Uint`] at compile time.
+    ///
+    /// See [`Self::from_static_be_bytes`].
+    ///
+    /// ```
+    /// # use ruint::aliases::U64;
+    /// const KEY: U64 = U64::from_static_le_bytes(b"\xef\xcd\xab\x89\x67\x45\x23\x01");
+    /// assert_eq!(KEY, U64::from(0x0123_4567_89ab_cdef_u64));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `N` is not exactly [`Self::BYTES`].
+    ///
+    /// ```compile_fail
+    /// # use ruint::aliases::U64;
+    /// const KEY: U64 = U64::from_static_le_bytes(&[0; 9]);
+    /// ```
+    ///
+    /// Panics if the value is too large for the bit-size of the Uint.
+    #[must_use]
+    #[track_caller]
+    #[inline]
+    pub const fn from_static_le_bytes<const N: usize>(bytes: &[u8; N]) -> Self {
+        const { Self::assert_bytes(N) }
+        Self::from_le_slice(bytes)
+    }
+
     /// Creates a new integer from a little endian slice of bytes.
     ///
     /// The slice is interpreted as a little endian number, and must be at most
@@ -299,9 +614,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[track_caller]
     #[inline]
     pub const fn from_le_slice(bytes: &[u8]) -> Self {
-        match Self::try_from_le_slice(bytes) {
-            Some(value) => value,
-            None => panic!("Value too large for Uint"),
+        match Self::from_le_slice_fallible(bytes) {
+            Ok(value) => value,
+            Err(_) => panic!("Value too large for Uint"),
         }
     }
 
@@ -314,11 +629,33 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[must_use]
     #[inline]
     pub const fn try_from_le_slice(bytes: &[u8]) -> Option<Self> {
+        match Self::from_le_slice_fallible(bytes) {
+            Ok(value) => Some(value),
+            Err(_) => None,
+        }
+    }
+
+    /// Creates a new integer from a little endian slice of bytes.
+    ///
+    /// The slice is interpreted as a little endian number, and must be at most
+    /// [`Self::BYTES`] long.
+    ///
+    /// # Errors
+    ///
+    /// * [`FromBytesError::TooManyBytes`] if the slice is longer than
+    ///   [`Self::BYTES`].
+    /// * [`FromBytesError::HighBitsSet`] if the value does not fit in `BITS`
+    ///   bits.
+    #[inline]
+    pub const fn from_le_slice_fallible(bytes: &[u8]) -> Result<Self, FromBytesError> {
         if bytes.len() > Self::BYTES {
-            return None;
+            return Err(FromBytesError::TooManyBytes {
+                expected: Self::BYTES,
+                got:      bytes.len(),
+            });
         }
 
-        if Self::BYTES % 8 == 0 && bytes.len() == Self::BYTES {
+        if BITS % 64 == 0 && bytes.len() == Self::BYTES {
             // Optimized implementation for full-limb types.
             let mut limbs = [0; LIMBS];
             let mut i = 0;
@@ -326,7 +663,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 limbs[i] = u64::from_le_bytes(unsafe { *bytes.as_ptr().add(i * 8).cast() });
                 i += 1;
             }
-            return Some(Self::from_limbs(limbs));
+            return Ok(Self::from_limbs(limbs));
         }
 
         let mut limbs = [0; LIMBS];
@@ -337,9 +674,12 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             i += 1;
         }
         if LIMBS > 0 && limbs[LIMBS - 1] > Self::MASK {
-            return None;
+            return Err(FromBytesError::HighBitsSet {
+                expected: BITS,
+                bit_len:  64 * LIMBS - limbs[LIMBS - 1].leading_zeros() as usize,
+            });
         }
-        Some(Self::from_limbs(limbs))
+        Ok(Self::from_limbs(limbs))
     }
 
     /// Writes the little-endian representation of the [`Uint`] to the given
@@ -449,16 +789,344 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     }
 
     #[track_caller]
-    const fn assert_bytes(bytes: usize) {
+    pub(crate) const fn assert_bytes(bytes: usize) {
         assert!(bytes == Self::BYTES, "BYTES must be equal to Self::BYTES");
     }
+
+    #[track_caller]
+    const fn assert_compact_bytes(n: usize) {
+        assert!(n == Self::BYTES + 1, "N must be equal to BYTES + 1");
+        assert!(Self::BYTES <= 255, "BYTES must fit the length prefix");
+    }
+
+    /// Imports the value from an array of `word_size` byte words, like GMP's
+    /// `mpz_import`.
+    ///
+    /// The input may have more words than needed as long as the extra
+    /// significant bytes are zero.
+    ///
+    /// # Errors
+    ///
+    /// * [`ImportError::ZeroWordSize`] if `word_size` is zero.
+    /// * [`ImportError::PartialWord`] if the length of `words` is not a
+    ///   multiple of `word_size`.
+    /// * [`ImportError::Overflow`] if the value does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Endian, Order, aliases::U64};
+    /// // Two little-endian 32-bit words, most significant first.
+    /// let words = [0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+    /// let value = U64::from_words(&words, 4, Order::MostSignificantFirst, Endian::Little);
+    /// assert_eq!(value, Ok(U64::from(0x1_0000_0002_u64)));
+    /// ```
+    #[inline]
+    pub fn from_words(
+        words: &[u8],
+        word_size: usize,
+        order: Order,
+        endian: Endian,
+    ) -> Result<Self, ImportError> {
+        if word_size == 0 {
+            return Err(ImportError::ZeroWordSize);
+        }
+        if words.len() % word_size != 0 {
+            return Err(ImportError::PartialWord {
+                word_size,
+                len: words.len(),
+            });
+        }
+        let count = words.len() / word_size;
+        let mut limbs = [0; LIMBS];
+        for (i, &byte) in words.iter().enumerate() {
+            let position = order.significance(i / word_size, count) * word_size
+                + endian.significance(i % word_size, word_size);
+            match limbs.get_mut(position / 8) {
+                Some(limb) => *limb |= u64::from(byte) << (8 * (position % 8)),
+                None if byte != 0 => return Err(ImportError::Overflow),
+                None => {}
+            }
+        }
+        Self::checked_from_limbs_slice(&limbs).ok_or(ImportError::Overflow)
+    }
+
+    /// Exports the value as an array of `word_size` byte words, like GMP's
+    /// `mpz_export`.
+    ///
+    /// Unlike `mpz_export`, the output always has the full width of
+    /// [`Self::BYTES`] rounded up to a whole number of words, so it can be
+    /// read back by [`Self::from_words`] with the same parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Endian, Order, aliases::U64};
+    /// let value = U64::from(0x1_0000_0002_u64);
+    /// assert_eq!(
+    ///     value.to_words(4, Order::LeastSignificantFirst, Endian::Big),
+    ///     [0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    #[allow(clippy::cast_possible_truncation)] // Intentional.
+    pub fn to_words(&self, word_size: usize, order: Order, endian: Endian) -> Vec<u8> {
+        assert!(word_size != 0, "word size is zero");
+        let count = Self::BYTES.div_ceil(word_size);
+        (0..count * word_size)
+            .map(|i| {
+                let position = order.significance(i / word_size, count) * word_size
+                    + endian.significance(i % word_size, word_size);
+                match self.limbs.get(position / 8) {
+                    Some(limb) => (limb >> (8 * (position % 8))) as u8,
+                    None => 0,
+                }
+            })
+            .collect()
+    }
+
+    /// The width of the length prefix of [`Self::to_sortable_bytes_compact`].
+    const SORTABLE_PREFIX: usize = nbytes((usize::BITS - Self::BYTES.leading_zeros()) as usize);
+
+    /// Encodes the value such that the byte strings sort lexicographically
+    /// in the same order as the values sort numerically.
+    ///
+    /// This is the fixed-width big-endian encoding, the same as
+    /// [`Self::to_be_bytes`]. Use it for keys in ordered key-value stores
+    /// like RocksDB or LMDB. Note that the little-endian encoding does *not*
+    /// preserve the order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generic parameter `BYTES` is not exactly [`Self::BYTES`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U64;
+    /// let (a, b) = (U64::from(0x01ff), U64::from(0x0200));
+    /// assert!(a.to_sortable_bytes::<8>() < b.to_sortable_bytes::<8>());
+    /// assert!(a.to_le_bytes::<8>() > b.to_le_bytes::<8>());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_sortable_bytes<const BYTES: usize>(&self) -> [u8; BYTES] {
+        self.to_be_bytes()
+    }
+
+    /// Decodes the output of [`Self::to_sortable_bytes`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generic parameter `BYTES` is not exactly [`Self::BYTES`].
+    ///
+    /// Panics if the value is too large for the bit-size of the Uint.
+    #[must_use]
+    #[track_caller]
+    #[inline]
+    pub const fn from_sortable_bytes<const BYTES: usize>(bytes: [u8; BYTES]) -> Self {
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Encodes the value in a variable-length encoding such that the byte
+    /// strings sort lexicographically in the same order as the values sort
+    /// numerically.
+    ///
+    /// The encoding is the number of significant bytes followed by those bytes
+    /// in big-endian order. The length prefix is big-endian with a fixed width
+    /// per type, one byte for `Uint`s of up to 255 bytes, so smaller values
+    /// always sort first. Zero is encoded as just a zero length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// assert_eq!(U256::ZERO.to_sortable_bytes_compact(), [0]);
+    /// assert_eq!(U256::from(0x1234).to_sortable_bytes_compact(), [
+    ///     2, 0x12, 0x34
+    /// ]);
+    /// assert!(
+    ///     U256::from(0xff).to_sortable_bytes_compact()
+    ///         < U256::from(0x100).to_sortable_bytes_compact()
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn to_sortable_bytes_compact(&self) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; Self::SORTABLE_PREFIX + Self::BYTES];
+        let len = self.write_length_prefixed(Self::SORTABLE_PREFIX, &mut bytes);
+        bytes.truncate(len);
+        bytes
+    }
+
+    /// Decodes the output of [`Self::to_sortable_bytes_compact`].
+    ///
+    /// Returns [`None`] if the bytes are not a valid encoding for this type,
+    /// including non-canonical encodings with leading zero bytes. This makes
+    /// the encoding one-to-one, so equal keys mean equal values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// assert_eq!(
+    ///     U256::from_sortable_bytes_compact(&[2, 0x12, 0x34]),
+    ///     Some(U256::from(0x1234))
+    /// );
+    /// assert_eq!(U256::from_sortable_bytes_compact(&[2, 0x00, 0x34]), None);
+    /// assert_eq!(U256::from_sortable_bytes_compact(&[2, 0x12]), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_sortable_bytes_compact(bytes: &[u8]) -> Option<Self> {
+        match Self::read_length_prefixed(Self::SORTABLE_PREFIX, bytes) {
+            Ok((value, len)) if len == bytes.len() => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Encodes the value as a one byte length prefix followed by the minimal
+    /// big-endian bytes, returning a buffer and the number of bytes used.
+    ///
+    /// For `0 < BITS <= 2040` this is the same encoding as
+    /// [`Self::to_sortable_bytes_compact`], without allocation. Unlike RLP
+    /// there is no nesting and small values are not special cased.
+    /// Zero is encoded as just a zero length.
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `N` is not exactly `Self::BYTES + 1`, or if
+    /// [`Self::BYTES`] does not fit the one byte length prefix.
+    ///
+    /// ```compile_fail
+    /// # use ruint::aliases::U256;
+    /// let _ = U256::ZERO.to_compact_bytes::<32>();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// let (buffer, len) = U256::from(0x1234).to_compact_bytes::<33>();
+    /// assert_eq!(buffer[..len], [2, 0x12, 0x34]);
+    /// let (buffer, len) = U256::ZERO.to_compact_bytes::<33>();
+    /// assert_eq!(buffer[..len], [0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_compact_bytes<const N: usize>(&self) -> ([u8; N], usize) {
+        const { Self::assert_compact_bytes(N) }
+        let mut bytes = [0; N];
+        let len = self.write_length_prefixed(1, &mut bytes);
+        (bytes, len)
+    }
+
+    /// Decodes a value encoded by [`Self::to_compact_bytes`] from the start of
+    /// `bytes`, returning the value and the number of bytes consumed.
+    ///
+    /// Trailing bytes are ignored, so values can be decoded one after another
+    /// from a stream.
+    ///
+    /// # Errors
+    ///
+    /// * [`DecodeError::Truncated`] if `bytes` ends before the value.
+    /// * [`DecodeError::TooManyBytes`] if the length is larger than
+    ///   [`Self::BYTES`].
+    /// * [`DecodeError::NonCanonical`] if the value has leading zero bytes, so
+    ///   every value has exactly one encoding.
+    /// * [`DecodeError::HighBitsSet`] if the value does not fit `BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{DecodeError, aliases::U256};
+    /// let stream = [2, 0x12, 0x34, 0, 1, 0xff];
+    /// let (a, used) = U256::from_compact_bytes(&stream).unwrap();
+    /// let (b, _) = U256::from_compact_bytes(&stream[used..]).unwrap();
+    /// assert_eq!((a, b), (U256::from(0x1234), U256::ZERO));
+    /// let error = U256::from_compact_bytes(&[2, 0x00, 0x34]);
+    /// assert_eq!(error, Err(DecodeError::NonCanonical));
+    /// ```
+    #[inline]
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        Self::read_length_prefixed(1, bytes)
+    }
+
+    /// Writes the number of significant bytes as a `prefix` byte big-endian
+    /// length, followed by those bytes in big-endian order, to the start of
+    /// `bytes`. Returns the number of bytes written.
+    ///
+    /// This is the encoding of both [`Self::to_sortable_bytes_compact`] and
+    /// [`Self::to_compact_bytes`].
+    #[allow(clippy::cast_possible_truncation)] // Each byte of the length.
+    const fn write_length_prefixed(&self, prefix: usize, bytes: &mut [u8]) -> usize {
+        let len = self.byte_len();
+        let mut i = 0;
+        while i < prefix {
+            bytes[i] = (len >> (8 * (prefix - 1 - i))) as u8;
+            i += 1;
+        }
+        let mut i = 0;
+        while i < len {
+            bytes[prefix + i] = self.byte(len - 1 - i);
+            i += 1;
+        }
+        prefix + len
+    }
+
+    /// Decodes a value written by [`Self::write_length_prefixed`] from the
+    /// start of `bytes`, returning the value and the number of bytes consumed.
+    fn read_length_prefixed(prefix: usize, bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let Some((prefix_bytes, rest)) = bytes.split_at_checked(prefix) else {
+            return Err(DecodeError::Truncated {
+                expected: prefix,
+                got:      bytes.len(),
+            });
+        };
+        let len = prefix_bytes
+            .iter()
+            .fold(0_usize, |len, &byte| (len << 8) | usize::from(byte));
+        if len > Self::BYTES {
+            return Err(DecodeError::TooManyBytes {
+                expected: Self::BYTES,
+                got:      len,
+            });
+        }
+        let Some(value) = rest.get(..len) else {
+            return Err(DecodeError::Truncated {
+                expected: prefix + len,
+                got:      bytes.len(),
+            });
+        };
+        if value.first() == Some(&0) {
+            return Err(DecodeError::NonCanonical);
+        }
+        match Self::from_be_slice_fallible(value) {
+            Ok(value) => Ok((value, prefix + len)),
+            Err(FromBytesError::HighBitsSet { expected, bit_len }) => {
+                Err(DecodeError::HighBitsSet { expected, bit_len })
+            }
+            Err(FromBytesError::TooManyBytes { expected, got }) => {
+                Err(DecodeError::TooManyBytes { expected, got })
+            }
+        }
+    }
 }
 
 /// Number of bytes required to represent the given number of bits.
 ///
 /// This needs to be public because it is used in the `Uint` type,
 /// specifically in the [`to_be_bytes()`][Uint::to_be_bytes] and related
-/// functions.
+/// functions. For a given type this is [`Uint::BYTES`], which can be used to
+/// size arrays, e.g. `[u8; U256::BYTES]`.
 #[inline]
 #[must_use]
 pub const fn nbytes(bits: usize) -> usize {
@@ -590,6 +1258,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_slice_fallible() {
+        // Boundary lengths.
+        assert_eq!(Uint::<72, 2>::from_be_slice_fallible(&KBE), Ok(K));
+        assert_eq!(Uint::<72, 2>::from_le_slice_fallible(&KLE), Ok(K));
+        assert_eq!(Uint::<72, 2>::from_be_slice_fallible(&[]), Ok(Uint::ZERO));
+        assert_eq!(
+            Uint::<72, 2>::from_be_slice_fallible(&[0; 10]),
+            Err(FromBytesError::TooManyBytes {
+                expected: 9,
+                got:      10,
+            })
+        );
+        assert_eq!(
+            Uint::<72, 2>::from_le_slice_fallible(&[0; 10]),
+            Err(FromBytesError::TooManyBytes {
+                expected: 9,
+                got:      10,
+            })
+        );
+        assert_eq!(
+            Uint::<0, 0>::from_le_slice_fallible(&[0]),
+            Err(FromBytesError::TooManyBytes {
+                expected: 0,
+                got:      1,
+            })
+        );
+
+        // High bits in a partial byte.
+        assert_eq!(
+            Uint::<12, 1>::from_be_slice_fallible(&[0x0f, 0xff]),
+            Ok(Uint::from(0x0fff))
+        );
+        assert_eq!(
+            Uint::<12, 1>::from_be_slice_fallible(&[0x10, 0x00]),
+            Err(FromBytesError::HighBitsSet {
+                expected: 12,
+                bit_len:  13,
+            })
+        );
+        assert_eq!(
+            Uint::<12, 1>::from_le_slice_fallible(&[0x00, 0x80]),
+            Err(FromBytesError::HighBitsSet {
+                expected: 12,
+                bit_len:  16,
+            })
+        );
+        // Full width slice of a type with a partial top limb.
+        assert_eq!(
+            Uint::<63, 1>::from_be_slice_fallible(&[0xff; 8]),
+            Err(FromBytesError::HighBitsSet {
+                expected: 63,
+                bit_len:  64,
+            })
+        );
+        assert_eq!(Uint::<63, 1>::try_from_le_slice(&[0xff; 8]), None);
+
+        assert_eq!(
+            FromBytesError::TooManyBytes {
+                expected: 9,
+                got:      10,
+            }
+            .to_string(),
+            "expected at most 9 bytes, got 10"
+        );
+        assert_eq!(
+            FromBytesError::HighBitsSet {
+                expected: 12,
+                bit_len:  13,
+            }
+            .to_string(),
+            "expected at most 12 bits, got 13"
+        );
+    }
+
     #[test]
     fn test_to_bytes() {
         assert_eq!(Uint::<0, 0>::ZERO.to_le_bytes(), [0_u8; 0]);
@@ -614,8 +1357,257 @@ mod tests {
                 assert_eq!(value, Uint::try_from_le_slice(&value.to_le_bytes_trimmed_vec()).unwrap());
                 assert_eq!(value, Uint::from_be_bytes(value.to_be_bytes::<BYTES>()));
                 assert_eq!(value, Uint::from_le_bytes(value.to_le_bytes::<BYTES>()));
+                assert_eq!(value, Uint::from_static_be_bytes(&value.to_be_bytes::<BYTES>()));
+                assert_eq!(value, Uint::from_static_le_bytes(&value.to_le_bytes::<BYTES>()));
+            });
+        });
+    }
+
+    #[test]
+    fn test_from_static_bytes() {
+        const BE_CONST: Uint<128, 2> = Uint::from_static_be_bytes(&BE);
+        const LE_CONST: Uint<128, 2> = Uint::from_static_le_bytes(&LE);
+        const KBE_CONST: Uint<72, 2> = Uint::from_static_be_bytes(&KBE);
+        const KLE_CONST: Uint<72, 2> = Uint::from_static_le_bytes(&KLE);
+        const ZERO: Uint<0, 0> = Uint::from_static_be_bytes(&[]);
+        assert_eq!(BE_CONST, Uint::from_be_bytes(BE));
+        assert_eq!(LE_CONST, Uint::from_le_bytes(LE));
+        assert_eq!(KBE_CONST, Uint::from_be_bytes(KBE));
+        assert_eq!(KLE_CONST, Uint::from_le_bytes(KLE));
+        assert_eq!(ZERO, Uint::ZERO);
+    }
+
+    #[test]
+    fn test_words() {
+        use Endian::{Big, Little};
+        use Order::{LeastSignificantFirst as Lsf, MostSignificantFirst as Msf};
+
+        #[rustfmt::skip]
+        let dumps: [(Order, Endian, [u8; 16]); 4] = [
+            (Msf, Big, BE),
+            (Lsf, Little, LE),
+            (Msf, Little, [
+                0x78, 0x56, 0x34, 0x12, 0x56, 0x34, 0x12, 0x90,
+                0x34, 0x12, 0x90, 0x78, 0x12, 0x90, 0x78, 0x56,
+            ]),
+            (Lsf, Big, [
+                0x56, 0x78, 0x90, 0x12, 0x78, 0x90, 0x12, 0x34,
+                0x90, 0x12, 0x34, 0x56, 0x12, 0x34, 0x56, 0x78,
+            ]),
+        ];
+        for (order, endian, dump) in dumps {
+            assert_eq!(N.to_words(4, order, endian), dump);
+            assert_eq!(Uint::<128, 2>::from_words(&dump, 4, order, endian), Ok(N));
+        }
+
+        // Padded to whole words.
+        assert_eq!(K.to_words(4, Msf, Big), [
+            0, 0, 0, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, 0x78
+        ]);
+        assert_eq!(K.to_words(8, Lsf, Little), [&KLE[..], &[0; 7]].concat());
+        assert_eq!(
+            Uint::<72, 2>::from_words(&K.to_words(4, Msf, Big), 4, Msf, Big),
+            Ok(K)
+        );
+
+        // Errors.
+        assert_eq!(
+            Uint::<72, 2>::from_words(&KBE, 0, Msf, Big),
+            Err(ImportError::ZeroWordSize)
+        );
+        assert_eq!(
+            Uint::<72, 2>::from_words(&KBE, 2, Msf, Big),
+            Err(ImportError::PartialWord {
+                word_size: 2,
+                len:       9,
+            })
+        );
+        assert_eq!(
+            Uint::<64, 1>::from_words(&KBE, 1, Msf, Big),
+            Err(ImportError::Overflow)
+        );
+        assert_eq!(
+            Uint::<71, 2>::from_words(&[0xff; 9], 1, Lsf, Big),
+            Err(ImportError::Overflow)
+        );
+        assert_eq!(
+            Uint::<0, 0>::from_words(&[0; 8], 4, Lsf, Big),
+            Ok(Uint::ZERO)
+        );
+        assert!(Uint::<0, 0>::ZERO.to_words(4, Lsf, Big).is_empty());
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, word_size in 1_usize..=17)| {
+                for order in [Msf, Lsf] {
+                    for endian in [Big, Little] {
+                        let words = value.to_words(word_size, order, endian);
+                        assert_eq!(words.len() % word_size, 0);
+                        assert_eq!(U::from_words(&words, word_size, order, endian), Ok(value));
+                    }
+                }
+                let be = value.to_be_bytes_vec();
+                assert_eq!(U::from_words(&be, 1, Msf, Little), Ok(value));
+                assert_eq!(U::from_words(&be, be.len().max(1), Lsf, Big), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_from_chunks_be() {
+        type U = Uint<72, 2>;
+        assert_eq!(U::from_chunks_be([]), Some(U::ZERO));
+        assert_eq!(U::from_chunks_be([1, 2, 3]), Some(U::from(0x01_02_03)));
+        assert_eq!(U::from_chunks_be([0; 20]), Some(U::ZERO));
+        let nine = [0xff, 1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!(
+            U::from_chunks_be(nine),
+            Some(U::from_limbs([0x0102_0304_0506_0708, 0xff]))
+        );
+        assert_eq!(
+            U::from_chunks_be([0, 0, 0].into_iter().chain(nine)),
+            U::from_chunks_be(nine)
+        );
+        assert_eq!(U::from_chunks_be([1].into_iter().chain(nine)), None);
+        assert_eq!(Uint::<7, 1>::from_chunks_be([0, 0x7f]), Some(Uint::MAX));
+        assert_eq!(Uint::<7, 1>::from_chunks_be([0x80]), None);
+        assert_eq!(Uint::<0, 0>::from_chunks_be([0; 9]), Some(Uint::ZERO));
+        assert_eq!(Uint::<0, 0>::from_chunks_be([1]), None);
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, zeros in 0_usize..20)| {
+                let bytes = value.to_be_bytes_trimmed_vec();
+                let stream = core::iter::repeat_n(0, zeros).chain(bytes.iter().copied());
+                assert_eq!(U::from_chunks_be(stream), Some(value));
+            });
+            proptest!(|(bytes: Vec<u8>)| {
+                let expected = U::try_from_be_slice(&bytes).or_else(|| {
+                    let start = bytes.iter().position(|&byte| byte != 0).unwrap_or(bytes.len());
+                    U::try_from_be_slice(&bytes[start..])
+                });
+                assert_eq!(U::from_chunks_be(bytes), expected);
+            });
+        });
+    }
+
+    #[test]
+    fn test_sortable_bytes() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            const BYTES: usize = nbytes(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                let (fa, fb) = (a.to_sortable_bytes::<BYTES>(), b.to_sortable_bytes::<BYTES>());
+                assert_eq!(a.cmp(&b), fa.cmp(&fb));
+                assert_eq!(U::from_sortable_bytes(fa), a);
+
+                let (ca, cb) = (a.to_sortable_bytes_compact(), b.to_sortable_bytes_compact());
+                assert_eq!(a.cmp(&b), ca.cmp(&cb));
+                assert_eq!(U::from_sortable_bytes_compact(&ca), Some(a));
+            });
+            // Values of different lengths, as random pairs rarely have them.
+            proptest!(|(a: U, shift in 0..=BITS)| {
+                let b = a >> shift;
+                let (ca, cb) = (a.to_sortable_bytes_compact(), b.to_sortable_bytes_compact());
+                assert_eq!(a.cmp(&b), ca.cmp(&cb));
+            });
+        });
+
+        type U = Uint<72, 2>;
+        assert_eq!(K.to_sortable_bytes_compact(), [&[9][..], &KBE].concat());
+        assert_eq!(U::from_sortable_bytes_compact(&[]), None);
+        assert_eq!(U::from_sortable_bytes_compact(&[0]), Some(U::ZERO));
+        assert_eq!(U::from_sortable_bytes_compact(&[0, 0]), None);
+        assert_eq!(U::from_sortable_bytes_compact(&[1, 0]), None);
+        assert_eq!(U::from_sortable_bytes_compact(&[10; 11]), None);
+        assert_eq!(
+            Uint::<71, 2>::from_sortable_bytes_compact(&[&[9][..], &[0xff; 9]].concat()),
+            None
+        );
+
+        // Types of more than 255 bytes use a two byte length prefix.
+        type W = Uint<2048, 32>;
+        assert_eq!(W::ZERO.to_sortable_bytes_compact(), [0, 0]);
+        assert_eq!(W::MAX.to_sortable_bytes_compact()[..3], [1, 0, 0xff]);
+        assert_eq!(W::from_sortable_bytes_compact(&[0, 1, 7]), Some(W::from(7)));
+        assert_eq!(Uint::<0, 0>::ZERO.to_sortable_bytes_compact(), [0_u8; 0]);
+        assert_eq!(
+            Uint::<0, 0>::from_sortable_bytes_compact(&[]),
+            Some(Uint::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_compact_bytes() {
+        // The one byte length prefix limits the width to 255 bytes.
+        const_for!(BITS in [0, 1, 2, 63, 64, 65, 127, 128, 129, 256, 384, 512, 2040] {
+            const LIMBS: usize = nlimbs(BITS);
+            const N: usize = nbytes(BITS) + 1;
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, shift in 0..=BITS, tail: Vec<u8>)| {
+                let a = a >> shift;
+                let (bytes, len) = a.to_compact_bytes::<N>();
+                assert_eq!(len, 1 + a.byte_len());
+                assert!(bytes[len..].iter().all(|&b| b == 0));
+                if BITS != 0 {
+                    assert_eq!(bytes[..len], a.to_sortable_bytes_compact());
+                }
+                let stream = [&bytes[..len], &tail].concat();
+                assert_eq!(U::from_compact_bytes(&stream), Ok((a, len)));
+                for short in 0..len {
+                    let expected = if short == 0 { 1 } else { len };
+                    assert_eq!(
+                        U::from_compact_bytes(&bytes[..short]),
+                        Err(DecodeError::Truncated { expected, got: short })
+                    );
+                }
             });
         });
+
+        type U = Uint<72, 2>;
+        let (bytes, len) = K.to_compact_bytes::<10>();
+        assert_eq!((bytes[0], &bytes[1..], len), (9, &KBE[..], 10));
+        assert_eq!(U::from_compact_bytes(&[0]), Ok((U::ZERO, 1)));
+        assert_eq!(
+            U::from_compact_bytes(&[1, 0]),
+            Err(DecodeError::NonCanonical)
+        );
+        assert_eq!(
+            U::from_compact_bytes(&[2, 0, 1, 5]),
+            Err(DecodeError::NonCanonical)
+        );
+        assert_eq!(
+            U::from_compact_bytes(&[3, 1, 2]),
+            Err(DecodeError::Truncated {
+                expected: 4,
+                got:      3,
+            })
+        );
+        assert_eq!(
+            U::from_compact_bytes(&[10; 11]),
+            Err(DecodeError::TooManyBytes {
+                expected: 9,
+                got:      10,
+            })
+        );
+        assert_eq!(
+            Uint::<71, 2>::from_compact_bytes(&[&[9][..], &[0xff; 9]].concat()),
+            Err(DecodeError::HighBitsSet {
+                expected: 71,
+                bit_len:  72,
+            })
+        );
+        assert_eq!(Uint::<0, 0>::ZERO.to_compact_bytes::<1>(), ([0], 1));
+        assert_eq!(
+            Uint::<0, 0>::from_compact_bytes(&[1, 1]),
+            Err(DecodeError::TooManyBytes {
+                expected: 0,
+                got:      1,
+            })
+        );
     }
 
     #[test]
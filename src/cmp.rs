@@ -1,4 +1,4 @@
-use crate::Uint;
+use crate::{Uint, algorithms::borrowing_sub};
 use core::cmp::Ordering;
 
 impl<const BITS: usize, const LIMBS: usize> PartialOrd for Uint<BITS, LIMBS> {
@@ -11,7 +11,21 @@ impl<const BITS: usize, const LIMBS: usize> PartialOrd for Uint<BITS, LIMBS> {
 impl<const BITS: usize, const LIMBS: usize> Ord for Uint<BITS, LIMBS> {
     #[inline]
     fn cmp(&self, rhs: &Self) -> Ordering {
-        crate::algorithms::cmp(self.as_limbs(), rhs.as_limbs())
+        // Indexing the arrays directly, rather than going through a slice, lets
+        // LLVM fully unroll the loop for small `LIMBS`.
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            let (a, b) = (self.limbs[i], rhs.limbs[i]);
+            if a != b {
+                return if a < b {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+            }
+        }
+        Ordering::Equal
     }
 }
 
@@ -61,6 +75,11 @@ macro_rules! impl_for_primitives {
     };
 }
 
+// FEATURE: (BLOCKED) `PartialEq` and `PartialOrd` across `Uint` widths. A
+// blanket impl overlaps with the same-width ones, and concrete impls between
+// the alias widths break inference of e.g. `a == Uint::from(1_u64)`. Use
+// `Uint::cmp_uint` and `Uint::eq_uint` instead.
+
 #[rustfmt::skip]
 impl_for_primitives!(
     u8, u16, u32, u64, u128, usize,
@@ -71,18 +90,60 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Returns `true` if the value is zero.
     #[inline]
     #[must_use]
-    pub fn is_zero(&self) -> bool {
-        *self == Self::ZERO
+    pub const fn is_zero(&self) -> bool {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            bits |= self.limbs[i];
+            i += 1;
+        }
+        bits == 0
     }
 
     /// Returns `true` if the value is zero.
     ///
-    /// Note that this currently might perform worse than
-    /// [`is_zero`](Self::is_zero).
+    /// This is the same as [`is_zero`](Self::is_zero), which is now also a
+    /// `const fn`.
     #[inline]
     #[must_use]
     pub const fn const_is_zero(&self) -> bool {
-        self.const_eq(&Self::ZERO)
+        self.is_zero()
+    }
+
+    /// Compares `self` and `other` without branching on their values.
+    ///
+    /// This always reads every limb, unlike the early-exit [`Ord::cmp`]. It is
+    /// meant for code that wants to avoid data-dependent branches, e.g. in a
+    /// sorting network, but makes no constant-time guarantees; the compiler is
+    /// free to introduce branches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// # use core::cmp::Ordering;
+    /// assert_eq!(U256::ZERO.cmp_branchless(&U256::MAX), Ordering::Less);
+    /// assert_eq!(U256::MAX.cmp_branchless(&U256::MAX), Ordering::Equal);
+    /// assert_eq!(U256::MAX.cmp_branchless(&U256::ZERO), Ordering::Greater);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn cmp_branchless(&self, other: &Self) -> Ordering {
+        // `self < other` is the borrow out of `self - other`.
+        let mut lt = false;
+        let mut diff = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            (_, lt) = borrowing_sub(self.limbs[i], other.limbs[i], lt);
+            diff |= self.limbs[i] ^ other.limbs[i];
+            i += 1;
+        }
+        let gt = !lt & (diff != 0);
+        match gt as i8 - lt as i8 {
+            -1 => Ordering::Less,
+            0 => Ordering::Equal,
+            _ => Ordering::Greater,
+        }
     }
 
     /// Returns `true` if `self` equals `other`.
@@ -93,6 +154,19 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[must_use]
     pub const fn const_eq(&self, other: &Self) -> bool {
         // TODO: Replace with `self == other` and deprecate once `PartialEq` is const.
+        #[cfg(feature = "portable-simd")]
+        if LIMBS >= crate::simd::LANES {
+            return core::intrinsics::const_eval_select(
+                (self, other),
+                Self::const_eq_scalar,
+                Self::eq_simd,
+            );
+        }
+        self.const_eq_scalar(other)
+    }
+
+    #[inline]
+    const fn const_eq_scalar(&self, other: &Self) -> bool {
         let a = self.as_limbs();
         let b = other.as_limbs();
         let mut i = 0;
@@ -103,11 +177,143 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
         r
     }
+
+    /// Compares `self` with a floating point number exactly.
+    ///
+    /// Unlike comparing with [`Self::to_f64`], this does not round `self`, so
+    /// e.g. $2^{64} + 1$ compares greater than `2.0_f64.powi(64)`. Negative
+    /// numbers, including negative infinity, compare less than any [`Uint`],
+    /// and positive infinity compares greater. Returns [`None`] for NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// # use core::cmp::Ordering;
+    /// let x = U256::from(u64::MAX);
+    /// assert_eq!(x.cmp_f64(1.8e19), Some(Ordering::Greater));
+    /// assert_eq!(x.cmp_f64(u64::MAX as f64), Some(Ordering::Less));
+    /// assert_eq!(U256::from(3).cmp_f64(3.5), Some(Ordering::Less));
+    /// assert_eq!(U256::ZERO.cmp_f64(-0.0), Some(Ordering::Equal));
+    /// assert_eq!(U256::ZERO.cmp_f64(f64::NAN), None);
+    /// ```
+    #[must_use]
+    pub fn cmp_f64(&self, other: f64) -> Option<Ordering> {
+        if other.is_nan() {
+            return None;
+        }
+        if other <= 0.0 {
+            return Some(if other == 0.0 && self.is_zero() {
+                Ordering::Equal
+            } else {
+                Ordering::Greater
+            });
+        }
+        if other.is_infinite() {
+            return Some(Ordering::Less);
+        }
+
+        // `other = significand ⋅ 2^exponent` exactly.
+        let bits = other.to_bits();
+        let biased = (bits >> 52) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        let (significand, exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased - 1075)
+        };
+
+        if let Ok(shift) = usize::try_from(exponent) {
+            // `other` is an integer.
+            let bit_len = 64 - significand.leading_zeros() as usize + shift;
+            if bit_len > BITS {
+                return Some(Ordering::Less);
+            }
+            return Some(self.cmp(&(Self::from(significand) << shift)));
+        }
+
+        // Compare with the integer part, the fraction breaks ties.
+        let shift = exponent.unsigned_abs();
+        let (integer, fract) = if shift >= 64 {
+            (0, significand)
+        } else {
+            (significand >> shift, significand & ((1 << shift) - 1))
+        };
+        let Ok(small) = u64::try_from(self) else {
+            return Some(Ordering::Greater);
+        };
+        Some(small.cmp(&integer).then(if fract == 0 {
+            Ordering::Equal
+        } else {
+            Ordering::Less
+        }))
+    }
+
+    /// Compares `self` with a [`Uint`] of any width by value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::{U64, U256};
+    /// # use core::cmp::Ordering;
+    /// let limit = U256::from(u64::MAX);
+    /// assert_eq!(U64::MAX.cmp_uint(&limit), Ordering::Equal);
+    /// assert_eq!(U64::MAX.cmp_uint(&(limit + U256::ONE)), Ordering::Less);
+    /// assert_eq!(U256::MAX.cmp_uint(&U64::MAX), Ordering::Greater);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cmp_uint<const BITS_RHS: usize, const LIMBS_RHS: usize>(
+        &self,
+        other: &Uint<BITS_RHS, LIMBS_RHS>,
+    ) -> Ordering {
+        let (a, b) = (self.as_limbs(), other.as_limbs());
+        let common = LIMBS.min(LIMBS_RHS);
+        // Any non-zero limb beyond the narrower one decides.
+        if a[common..].iter().any(|&limb| limb != 0) {
+            return Ordering::Greater;
+        }
+        if b[common..].iter().any(|&limb| limb != 0) {
+            return Ordering::Less;
+        }
+        a[..common].iter().rev().cmp(b[..common].iter().rev())
+    }
+
+    /// Returns `true` if `self` equals a [`Uint`] of any width by value.
+    ///
+    /// Values that are equal across widths do not hash the same, as the
+    /// [`Hash`](core::hash::Hash) impl includes every limb. Convert to a
+    /// common width before using them as keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::{U64, U256};
+    /// let gas = U64::from(21_000);
+    /// assert!(gas.eq_uint(&U256::from(21_000)));
+    /// assert!(!U256::MAX.eq_uint(&U64::MAX));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn eq_uint<const BITS_RHS: usize, const LIMBS_RHS: usize>(
+        &self,
+        other: &Uint<BITS_RHS, LIMBS_RHS>,
+    ) -> bool {
+        self.cmp_uint(other).is_eq()
+    }
+
+    #[cfg(feature = "portable-simd")]
+    #[inline]
+    fn eq_simd(&self, other: &Self) -> bool {
+        crate::simd::eq(&self.limbs, &other.limbs)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Uint;
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
 
     #[test]
     fn test_is_zero() {
@@ -120,4 +326,135 @@ mod tests {
         assert!(!Uint::<7, 1>::from_limbs([1]).is_zero());
         assert!(!Uint::<64, 1>::from_limbs([1]).is_zero());
     }
+
+    #[test]
+    fn test_const_is_zero() {
+        const { assert!(Uint::<65, 2>::ZERO.is_zero()) };
+        const { assert!(!Uint::<65, 2>::ONE.is_zero()) };
+    }
+
+    #[test]
+    fn test_cmp() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                let expected = a.as_limbs().iter().rev().cmp(b.as_limbs().iter().rev());
+                assert_eq!(a.cmp(&b), expected);
+                assert_eq!(a.partial_cmp(&b), Some(expected));
+                assert_eq!(a.cmp_branchless(&b), expected);
+                assert_eq!(a.cmp(&a), Ordering::Equal);
+                assert_eq!(a.cmp_branchless(&a), Ordering::Equal);
+                assert_eq!(a.is_zero(), a == U::ZERO);
+            });
+        });
+    }
+
+    #[test]
+    fn test_cmp_odd_widths() {
+        // Values reached through wrapping arithmetic must compare equal to
+        // those built directly, i.e. the unused high bits are always cleared.
+        const_for!(BITS in [1, 7, 63, 65, 127, 129, 255, 257] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U)| {
+                let b = a.wrapping_add(U::MAX).wrapping_add(U::ONE);
+                let c = !!a;
+                for other in [b, c] {
+                    assert_eq!(a.partial_cmp(&other), Some(Ordering::Equal));
+                    assert_eq!(a.cmp_branchless(&other), Ordering::Equal);
+                    assert_eq!(a, other);
+                    assert_eq!(a.max(other), a.min(other));
+                }
+                assert!(U::MAX.wrapping_add(U::ONE).is_zero());
+                let expected = if a == U::MAX { Ordering::Equal } else { Ordering::Greater };
+                assert_eq!(U::MAX.cmp(&a), expected);
+            });
+        });
+    }
+
+    #[test]
+    fn test_cmp_uint() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            type W = Uint<{ BITS + 64 }, { nlimbs(BITS + 64) }>;
+            proptest!(|(a: U, b: U, c: W)| {
+                let expected = a.cmp(&b);
+                assert_eq!(a.cmp_uint(&b), expected);
+                assert_eq!(a.cmp_uint(&b.to::<W>()), expected);
+                assert_eq!(a.to::<W>().cmp_uint(&b), expected);
+                let expected = a.to::<W>().cmp(&c);
+                assert_eq!(a.cmp_uint(&c), expected);
+                assert_eq!(c.cmp_uint(&a), expected.reverse());
+                assert_eq!(a.eq_uint(&c), expected.is_eq());
+                assert_eq!(c.eq_uint(&a), expected.is_eq());
+            });
+            // Around the narrower `MAX`, in both directions.
+            let max = U::MAX.to::<W>();
+            let below = (BITS != 0).then(|| (max - W::ONE, Ordering::Greater));
+            let cases = [(max, Ordering::Equal), (max + W::ONE, Ordering::Less)];
+            for (wide, expected) in below.into_iter().chain(cases) {
+                assert_eq!(U::MAX.cmp_uint(&wide), expected);
+                assert_eq!(wide.cmp_uint(&U::MAX), expected.reverse());
+                assert_eq!(U::MAX.eq_uint(&wide), expected.is_eq());
+                assert_eq!(wide.eq_uint(&U::MAX), expected.is_eq());
+            }
+        });
+    }
+
+    #[test]
+    fn test_cmp_f64() {
+        type U = Uint<128, 2>;
+        let two64 = 2.0_f64.powi(64);
+        let below = f64::from_bits(two64.to_bits() - 1);
+        let above = f64::from_bits(two64.to_bits() + 1);
+        let x = U::from(1_u128 << 64);
+        assert_eq!(x.cmp_f64(two64), Some(Ordering::Equal));
+        assert_eq!((x + U::ONE).cmp_f64(two64), Some(Ordering::Greater));
+        assert_eq!((x - U::ONE).cmp_f64(two64), Some(Ordering::Less));
+        assert_eq!((x - U::ONE).cmp_f64(below), Some(Ordering::Greater));
+        assert_eq!(x.cmp_f64(above), Some(Ordering::Less));
+        assert_eq!((x + U::from(4096)).cmp_f64(above), Some(Ordering::Equal));
+        // 2^53 + 1 lies between two consecutive floats.
+        let y = U::from((1_u64 << 53) + 1);
+        assert_eq!(y.cmp_f64(2.0_f64.powi(53)), Some(Ordering::Greater));
+        assert_eq!(y.cmp_f64(2.0_f64.powi(53) + 2.0), Some(Ordering::Less));
+        assert_eq!(U::ONE.cmp_f64(f64::MIN_POSITIVE), Some(Ordering::Greater));
+        assert_eq!(U::ZERO.cmp_f64(f64::from_bits(1)), Some(Ordering::Less));
+        assert_eq!(U::ZERO.cmp_f64(0.0), Some(Ordering::Equal));
+        assert_eq!(U::ONE.cmp_f64(-0.0), Some(Ordering::Greater));
+        assert_eq!(U::ZERO.cmp_f64(f64::NEG_INFINITY), Some(Ordering::Greater));
+        assert_eq!(U::MAX.cmp_f64(f64::INFINITY), Some(Ordering::Less));
+        assert_eq!(U::MAX.cmp_f64(f64::MAX), Some(Ordering::Less));
+        assert_eq!(U::MAX.cmp_f64(2.0_f64.powi(128)), Some(Ordering::Less));
+        assert_eq!(Uint::<0, 0>::ZERO.cmp_f64(0.5), Some(Ordering::Less));
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            // Reference through the exact conversion of the integer part.
+            let reference = |a: U, f: f64| -> Option<Ordering> {
+                if f.is_nan() {
+                    return None;
+                }
+                if f < 0.0 {
+                    return Some(Ordering::Greater);
+                }
+                if f.is_infinite() {
+                    return Some(Ordering::Less);
+                }
+                let Some(integer) = U::from_f64_exact(f.trunc()) else {
+                    return Some(Ordering::Less);
+                };
+                let tie = if f.fract() == 0.0 { Ordering::Equal } else { Ordering::Less };
+                Some(a.cmp(&integer).then(tie))
+            };
+            proptest!(|(a: U, f: f64, ulps in -2_i64..=2)| {
+                assert_eq!(a.cmp_f64(f), reference(a, f));
+                let near = f64::from_bits(a.to_f64().to_bits().wrapping_add_signed(ulps));
+                assert_eq!(a.cmp_f64(near), reference(a, near));
+            });
+        });
+    }
 }
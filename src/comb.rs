@@ -0,0 +1,217 @@
+use crate::Uint;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Computes $n!$, or [`None`] if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint! {
+    /// assert_eq!(U64::checked_factorial(20), Some(2432902008176640000_U64));
+    /// assert_eq!(U64::checked_factorial(21), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_factorial(n: u32) -> Option<Self> {
+        let mut result = Self::checked_from(1_u64)?;
+        for i in 2..=u64::from(n) {
+            result = result.checked_mul(Self::checked_from(i)?)?;
+        }
+        Some(result)
+    }
+
+    /// Computes the binomial coefficient $\binom{n}{k}$, or [`None`] if the
+    /// result does not fit.
+    ///
+    /// Returns zero if $k > n$. The result is computed with the multiplicative
+    /// formula, dividing as it goes, so this only fails if the result itself
+    /// does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint! {
+    /// assert_eq!(U64::checked_binomial(52_U64, 5_U64), Some(2598960_U64));
+    /// assert_eq!(U64::checked_binomial(5_U64, 52_U64), Some(0_U64));
+    /// assert_eq!(U64::checked_binomial(100_U64, 50_U64), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_binomial(n: Self, k: Self) -> Option<Self> {
+        if k > n {
+            return Some(Self::ZERO);
+        }
+        let k = k.min(n - k);
+        let base = n - k;
+
+        // After step `i` the result is C(base + i, i), which is increasing in
+        // `i` and bounded by C(n, k). This takes at most about `BITS` steps
+        // before the result overflows.
+        let mut result = Self::checked_from(1_u64)?;
+        let mut i = Self::ZERO;
+        while i < k {
+            i += Self::ONE;
+            // `result * (base + i)` is divisible by `i`, so after removing
+            // the common factor of `result` and `i` the division is exact.
+            let g = result.gcd(i);
+            result /= g;
+            let factor = (base + i) / (i / g);
+            result = result.checked_mul(factor)?;
+        }
+        Some(result)
+    }
+
+    /// Computes the multinomial coefficient
+    /// $\binom{k_1 + k_2 + \cdots}{k_1, k_2, \ldots}$, or [`None`] if the
+    /// result does not fit.
+    ///
+    /// The multinomial of an empty slice is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint! {
+    /// assert_eq!(
+    ///     U64::checked_multinomial(&[2_U64, 3_U64, 4_U64]),
+    ///     Some(1260_U64)
+    /// );
+    /// assert_eq!(U64::checked_multinomial(&[]), Some(1_U64));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_multinomial(ks: &[Self]) -> Option<Self> {
+        let mut result = Self::checked_from(1_u64)?;
+        let mut total = Self::ZERO;
+        for &k in ks {
+            // If the total overflows, so does the result.
+            total = total.checked_add(k)?;
+            result = result.checked_mul(Self::checked_binomial(total, k)?)?;
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::proptest;
+
+    fn factorial_u128(n: u32) -> Option<u128> {
+        (2..=u128::from(n)).try_fold(1_u128, u128::checked_mul)
+    }
+
+    fn binomial_u128(n: u128, k: u128) -> Option<u128> {
+        if k > n {
+            return Some(0);
+        }
+        let k = k.min(n - k);
+        let mut result = 1_u128;
+        for i in 1..=k {
+            // C(n - k + i, i) = C(n - k + i - 1, i - 1) * (n - k + i) / i
+            let g = gcd_u128(result, i);
+            result = (result / g).checked_mul((n - k + i) / (i / g))?;
+        }
+        Some(result)
+    }
+
+    fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(Uint::<0, 0>::checked_factorial(0), None);
+        assert_eq!(Uint::<1, 1>::checked_factorial(1), Some(Uint::from(1)));
+        assert_eq!(Uint::<1, 1>::checked_factorial(2), None);
+        for n in 0..40 {
+            assert_eq!(
+                Uint::<128, 2>::checked_factorial(n),
+                factorial_u128(n).map(Uint::from)
+            );
+        }
+        assert!(U256::checked_factorial(57).is_some());
+        assert_eq!(U256::checked_factorial(58), None);
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(
+            U256::checked_binomial(U256::from(256), U256::from(128)),
+            Some(uint!(
+                5768658823449206338089748357862286887740211701975162032608436567264518750790_U256
+            ))
+        );
+        assert_eq!(
+            U256::checked_binomial(U256::from(1000), U256::from(500)),
+            None
+        );
+        assert_eq!(
+            U256::checked_binomial(U256::MAX, U256::ONE),
+            Some(U256::MAX)
+        );
+        assert_eq!(
+            U256::checked_binomial(U256::MAX, U256::MAX),
+            Some(U256::ONE)
+        );
+        assert_eq!(U256::checked_binomial(U256::MAX, U256::from(2)), None);
+        assert_eq!(Uint::<0, 0>::checked_binomial(Uint::ZERO, Uint::ZERO), None);
+
+        // Compare against Pascal's triangle.
+        let mut row = vec![1_u128];
+        for n in 1..=130_u64 {
+            let mut next = vec![1_u128; row.len() + 1];
+            for k in 1..row.len() {
+                next[k] = row[k - 1].saturating_add(row[k]);
+            }
+            row = next;
+            for (k, &expected) in row.iter().enumerate() {
+                let actual = Uint::<128, 2>::checked_binomial(Uint::from(n), Uint::from(k));
+                if expected != u128::MAX {
+                    assert_eq!(actual, Some(Uint::from(expected)));
+                }
+            }
+        }
+
+        const_for!(BITS in [8, 16, 64, 128] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n in 0_u128..300, k in 0_u128..300)| {
+                let expected = binomial_u128(n, k).and_then(U::checked_from);
+                if U::checked_from(n).is_some() && U::checked_from(k).is_some() {
+                    assert_eq!(U::checked_binomial(U::from(n), U::from(k)), expected);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_multinomial() {
+        assert_eq!(
+            U256::checked_multinomial(&[U256::from(10); 3]),
+            Some(U256::from(5_550_996_791_340_u64))
+        );
+        assert_eq!(
+            U256::checked_multinomial(&[U256::ZERO, U256::from(7), U256::ZERO]),
+            Some(U256::ONE)
+        );
+        assert_eq!(U256::checked_multinomial(&[U256::MAX, U256::ONE]), None);
+        proptest!(|(a in 0_u32..12, b in 0_u32..12, c in 0_u32..12)| {
+            let expected = factorial_u128(a + b + c).unwrap()
+                / factorial_u128(a).unwrap()
+                / factorial_u128(b).unwrap()
+                / factorial_u128(c).unwrap();
+            let parts = [a, b, c].map(U256::from);
+            assert_eq!(U256::checked_multinomial(&parts), Some(U256::from(expected)));
+        });
+    }
+}
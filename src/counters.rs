@@ -0,0 +1,117 @@
+//! Per thread counters of limb operations, for tuning algorithms.
+//!
+//! With the `counters` feature, the limb level primitives in
+//! [`algorithms`](crate::algorithms) count the operations they perform on the
+//! current thread. Without it, the counting compiles to nothing.
+//!
+//! Only the non-`const` primitives can count, because `const fn` can not
+//! access thread locals. In particular additions through the `const` methods
+//! like [`Uint::overflowing_add`](crate::Uint::overflowing_add) are not
+//! counted, while those inside multiplication and division are. With
+//! `--cfg ruint_limb32`, the small multiplications on `u32` words are not
+//! counted either.
+//!
+//! # Examples
+//!
+//! ```
+//! # use ruint::{aliases::{U256, U512}, counters};
+//! let a = U256::MAX;
+//! counters::reset();
+//! let _: U512 = a.widening_mul(a);
+//! assert_eq!(counters::snapshot().mul64, 16);
+//! ```
+
+use std::cell::Cell;
+
+/// Numbers of limb operations, see [`snapshot`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OpCounts {
+    /// 64×64→128 bit multiplications, including their fused additions.
+    pub mul64: u64,
+    /// 64 bit additions and subtractions outside of multiplications.
+    pub add64: u64,
+    /// 128 by 64 bit, or 192 by 128 bit, division steps.
+    pub div64: u64,
+}
+
+thread_local! {
+    static COUNTS: Cell<OpCounts> = const {
+        Cell::new(OpCounts {
+            mul64: 0,
+            add64: 0,
+            div64: 0,
+        })
+    };
+}
+
+/// Returns the operations counted on this thread since the last [`reset`].
+#[must_use]
+pub fn snapshot() -> OpCounts {
+    COUNTS.get()
+}
+
+/// Resets the counters of this thread to zero.
+pub fn reset() {
+    COUNTS.set(OpCounts::default());
+}
+
+#[inline(always)]
+pub(crate) fn increment(f: impl FnOnce(&mut OpCounts)) {
+    let mut counts = COUNTS.get();
+    f(&mut counts);
+    COUNTS.set(counts);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Uint,
+        aliases::{U256, U512},
+    };
+
+    #[test]
+    fn test_schoolbook_mul() {
+        let a = U256::from_limbs([1, 2, 3, 4]);
+        let b = U256::from_limbs([5, 6, 7, 8]);
+        reset();
+        let _: U512 = a.widening_mul(b);
+        assert_eq!(snapshot().mul64, 16);
+        assert_eq!(snapshot().div64, 0);
+
+        // Only the low half of the products is needed.
+        #[cfg(not(ruint_limb32))]
+        {
+            reset();
+            let _ = a.wrapping_mul(b);
+            assert_eq!(snapshot().mul64, 4 + 3 + 2 + 1);
+        }
+
+        // Zero limbs are skipped.
+        reset();
+        let _: U512 = U256::from(3).widening_mul(b);
+        assert_eq!(snapshot().mul64, 4);
+    }
+
+    #[test]
+    fn test_div() {
+        let a = U256::from_limbs([1, 2, 3, 4]);
+        let b = Uint::<128, 2>::from_limbs([5, 6]);
+        reset();
+        let _ = a / U256::from(b);
+        // A two limb divisor takes a step per numerator limb.
+        assert_eq!(snapshot().div64, 4);
+        reset();
+        assert_eq!(snapshot(), OpCounts::default());
+    }
+
+    #[test]
+    fn test_threads() {
+        reset();
+        let _: U512 = U256::MAX.widening_mul(U256::MAX);
+        std::thread::spawn(|| assert_eq!(snapshot(), OpCounts::default()))
+            .join()
+            .unwrap();
+        assert_ne!(snapshot(), OpCounts::default());
+    }
+}
@@ -1,6 +1,33 @@
 use crate::{Uint, algorithms};
 use core::ops::{Div, DivAssign, Rem, RemAssign};
 
+/// How [`Uint::div_nearest`] and [`Uint::mul_div_nearest`] round a quotient
+/// that is exactly halfway between two integers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Ties {
+    /// Round to the even neighbour, also known as bankers' rounding.
+    Even,
+
+    /// Round up, also known as round half up.
+    AwayFromZero,
+
+    /// Round down, also known as round half down.
+    TowardZero,
+}
+
+/// How [`Uint::rescale`] rounds when it drops decimals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round toward zero, i.e. truncate.
+    Down,
+
+    /// Round away from zero.
+    Up,
+
+    /// Round to the nearest integer, with the [`Ties`] rule for exact halves.
+    Nearest(Ties),
+}
+
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Computes `self / rhs`, returning [`None`] if `rhs == 0`.
     #[inline]
@@ -14,6 +41,8 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     }
 
     /// Computes `self % rhs`, returning [`None`] if `rhs == 0`.
+    ///
+    /// See [`Self::reduce_mod`] for a version that returns zero instead.
     #[inline]
     #[must_use]
     #[allow(clippy::missing_const_for_fn)] // False positive
@@ -24,6 +53,73 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         Some(self.rem(rhs))
     }
 
+    /// Computes `self / rhs` and `self % rhs`, returning [`None`] if
+    /// `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// assert_eq!(7_U256.checked_div_rem(2_U256), Some((3_U256, 1_U256)));
+    /// assert_eq!(7_U256.checked_div_rem(0_U256), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Some(self.div_rem(rhs))
+    }
+
+    /// Computes `self / rhs`, returning the quotient and `false`.
+    ///
+    /// Unsigned division never overflows. This exists for parity with
+    /// [`u64::overflowing_div`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_div(rhs), false)
+    }
+
+    /// Computes `self % rhs`, returning the remainder and `false`.
+    ///
+    /// Unsigned division never overflows. This exists for parity with
+    /// [`u64::overflowing_rem`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_rem(rhs), false)
+    }
+
+    /// Computes `self / rhs`.
+    ///
+    /// Unsigned division never overflows, so this is the same as
+    /// [`Self::wrapping_div`]. This exists for parity with
+    /// [`u64::saturating_div`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        self.wrapping_div(rhs)
+    }
+
     /// Computes `self / rhs` rounding up.
     ///
     /// # Panics
@@ -37,15 +133,358 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         if r.is_zero() { q } else { q + Self::ONE }
     }
 
-    /// Computes `self / rhs` and `self % rhs`.
+    /// Returns `true` if `self` is an exact multiple of `divisor`, i.e. if
+    /// `self % divisor == 0`.
+    ///
+    /// Returns `false` if `divisor == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// let supply = 1_000_000_U256;
+    /// assert!(supply.divides(&400_U256));
+    /// assert!(!supply.divides(&3_U256));
+    /// assert!(!supply.divides(&0_U256));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn divides(&self, divisor: &Self) -> bool {
+        if divisor.is_zero() {
+            return false;
+        }
+        if divisor.is_power_of_two() {
+            return self.trailing_zeros() >= divisor.trailing_zeros();
+        }
+        self.rem(*divisor).is_zero()
+    }
+
+    /// Computes `self / divisor`, returning [`None`] if `divisor == 0` or the
+    /// division is inexact.
+    ///
+    /// Unlike [`Self::checked_div`], this never truncates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// assert_eq!(1_000_000_U256.checked_exact_div(400_U256), Some(2500_U256));
+    /// assert_eq!(1_000_001_U256.checked_exact_div(400_U256), None);
+    /// assert_eq!(1_000_000_U256.checked_exact_div(0_U256), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_exact_div(self, divisor: Self) -> Option<Self> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if divisor.is_power_of_two() {
+            let shift = divisor.trailing_zeros();
+            return (self.trailing_zeros() >= shift).then(|| self.wrapping_shr(shift));
+        }
+        let (quotient, remainder) = self.div_rem(divisor);
+        remainder.is_zero().then_some(quotient)
+    }
+
+    /// Computes `self / rhs` rounded to the nearest integer, with `ties`
+    /// deciding exact halves.
     ///
     /// # Panics
     ///
     /// Panics if `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Ties, aliases::U64};
+    /// # uint! {
+    /// assert_eq!(7_U64.div_nearest(2_U64, Ties::Even), 4_U64);
+    /// assert_eq!(5_U64.div_nearest(2_U64, Ties::Even), 2_U64);
+    /// assert_eq!(5_U64.div_nearest(2_U64, Ties::AwayFromZero), 3_U64);
+    /// assert_eq!(5_U64.div_nearest(2_U64, Ties::TowardZero), 2_U64);
+    /// assert_eq!(8_U64.div_nearest(3_U64, Ties::TowardZero), 3_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn div_nearest(self, rhs: Self, ties: Ties) -> Self {
+        let (quotient, remainder) = self.div_rem(rhs);
+        // Rounding up implies `rhs >= 2`, so the quotient is below `MAX`.
+        if Self::rounds_up(quotient, remainder, rhs, ties) {
+            quotient + Self::ONE
+        } else {
+            quotient
+        }
+    }
+
+    /// Computes `self * mul / div` rounding down, without overflow in the
+    /// intermediate product.
+    ///
+    /// Returns [`None`] if `div == 0` or the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// assert_eq!(
+    ///     U256::MAX.mul_div(3_U256, 4_U256),
+    ///     Some(U256::MAX / 4_U256 * 3_U256 + 2_U256)
+    /// );
+    /// assert_eq!(U256::MAX.mul_div(3_U256, 2_U256), None);
+    /// assert_eq!(1_U256.mul_div(1_U256, 0_U256), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_div(self, mul: Self, div: Self) -> Option<Self> {
+        self.mul_div_rem(mul, div).map(|(quotient, _)| quotient)
+    }
+
+    /// Computes `self * mul / div` rounded to the nearest integer, with
+    /// `ties` deciding exact halves, without overflow in the intermediate
+    /// product.
+    ///
+    /// Returns [`None`] if `div == 0` or the result does not fit.
+    #[inline]
+    #[must_use]
+    pub fn mul_div_nearest(self, mul: Self, div: Self, ties: Ties) -> Option<Self> {
+        let (quotient, remainder) = self.mul_div_rem(mul, div)?;
+        if Self::rounds_up(quotient, remainder, div, ties) {
+            quotient.checked_add(Self::ONE)
+        } else {
+            Some(quotient)
+        }
+    }
+
+    /// Converts an amount with `from` decimals to one with `to` decimals,
+    /// e.g. between token units.
+    ///
+    /// Scaling up multiplies by $10^{\mathsf{to} - \mathsf{from}}$ and returns
+    /// [`None`] on overflow. Scaling down divides by
+    /// $10^{\mathsf{from} - \mathsf{to}}$ and rounds as requested, so it always
+    /// succeeds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Rounding, Ties, aliases::U256};
+    /// # uint! {
+    /// // 1.5 USDC (6 decimals) as an 18 decimals amount.
+    /// let wei = 1_500_000_U256.rescale(6, 18, Rounding::Down);
+    /// assert_eq!(wei, Some(1_500_000_000_000_000_000_U256));
+    ///
+    /// // And back, with 1.234567890123456789 dropping its last 12 digits.
+    /// let wei = 1_234_567_890_123_456_789_U256;
+    /// assert_eq!(wei.rescale(18, 6, Rounding::Down), Some(1_234_567_U256));
+    /// assert_eq!(wei.rescale(18, 6, Rounding::Up), Some(1_234_568_U256));
+    /// let nearest = Rounding::Nearest(Ties::Even);
+    /// assert_eq!(wei.rescale(18, 6, nearest), Some(1_234_568_U256));
+    ///
+    /// assert_eq!(U256::MAX.rescale(0, 1, Rounding::Down), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rescale(&self, from: u8, to: u8, rounding: Rounding) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::ZERO);
+        }
+        if to >= from {
+            return self.checked_mul(Self::pow10(usize::from(to - from))?);
+        }
+        let decimals = usize::from(from - to);
+        let Some(scale) = Self::pow10(decimals) else {
+            // The value is below `scale`, so only the rounding is left.
+            let round_up = match rounding {
+                Rounding::Down => false,
+                Rounding::Up => true,
+                Rounding::Nearest(ties) => {
+                    let half = Self::pow10(decimals - 1)
+                        .and_then(|p| p.checked_mul(Self::try_from(5_u64).ok()?));
+                    match half.map(|half| self.cmp(&half)) {
+                        Some(core::cmp::Ordering::Greater) => true,
+                        Some(core::cmp::Ordering::Equal) => ties == Ties::AwayFromZero,
+                        Some(core::cmp::Ordering::Less) | None => false,
+                    }
+                }
+            };
+            return Some(if round_up { Self::ONE } else { Self::ZERO });
+        };
+        Some(match rounding {
+            Rounding::Down => *self / scale,
+            Rounding::Up => self.div_ceil(scale),
+            Rounding::Nearest(ties) => self.div_nearest(scale, ties),
+        })
+    }
+
+    /// Computes the weighted mean `Σ value·weight / Σ weight` of
+    /// `(value, weight)` pairs, rounding down.
+    ///
+    /// The products and sums are accumulated exactly in a double width buffer
+    /// and divided once, so there is no intermediate overflow or rounding.
+    ///
+    /// Returns [`None`] if the input is empty or the total weight is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// let price = U256::MAX - U256::from(2);
+    /// let observations = [(price, U256::MAX), (U256::MAX, U256::MAX >> 1)];
+    /// assert_eq!(
+    ///     U256::weighted_mean(&observations),
+    ///     Some(U256::MAX - U256::from(2))
+    /// );
+    /// assert_eq!(U256::weighted_mean(&[(price, U256::ZERO)]), None);
+    /// ```
+    #[must_use]
+    pub fn weighted_mean(values_weights: &[(Self, Self)]) -> Option<Self> {
+        // A slice holds fewer than `2^64` pairs, so the sums exceed the
+        // products and weights by less than one limb.
+        let mut numerator = [[0; 3]; LIMBS];
+        let numerator = numerator.as_flattened_mut();
+        let mut total = [[0; 2]; LIMBS];
+        let total = total.as_flattened_mut();
+        for (value, weight) in values_weights {
+            let overflow = algorithms::addmul(numerator, value.as_limbs(), weight.as_limbs());
+            debug_assert!(!overflow);
+            let overflow = algorithms::addmul(total, weight.as_limbs(), &[1]);
+            debug_assert!(!overflow);
+        }
+        if total.iter().all(|&limb| limb == 0) {
+            return None;
+        }
+        algorithms::div::div_inlined(numerator, total);
+        // The mean is at most the largest value.
+        Self::checked_from_limbs_slice(numerator)
+    }
+
+    /// Computes the arithmetic mean of `values`, rounding down.
+    ///
+    /// The sum is accumulated with an extra limb, so it does not overflow.
+    ///
+    /// Returns [`None`] if `values` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// let values = [U256::MAX, U256::MAX - U256::from(3)];
+    /// assert_eq!(U256::mean(&values), Some(U256::MAX - U256::from(2)));
+    /// assert_eq!(U256::mean(&[]), None);
+    /// ```
+    #[must_use]
+    pub fn mean(values: &[Self]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut sum = [[0; 2]; LIMBS];
+        let sum = sum.as_flattened_mut();
+        for value in values {
+            let overflow = algorithms::addmul(sum, value.as_limbs(), &[1]);
+            debug_assert!(!overflow);
+        }
+        algorithms::div::div_inlined(sum, &mut [values.len() as u64]);
+        Self::checked_from_limbs_slice(sum)
+    }
+
+    /// Computes the exact dot product `Σ a[i]·b[i]` in a wider
+    /// [`Uint`].
+    ///
+    /// A result of `2 * BITS` bits holds any single product. Each doubling of
+    /// the number of terms needs one more bit to rule out overflow.
+    ///
+    /// Returns [`None`] if the slices have different lengths or the result
+    /// does not fit. The dot product of empty slices is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, aliases::U256};
+    /// let a = [U256::MAX; 2];
+    /// let max = Uint::<576, 9>::from(U256::MAX);
+    /// assert_eq!(
+    ///     U256::checked_dot::<576, 9>(&a, &a),
+    ///     Some(max * max * Uint::from(2))
+    /// );
+    /// assert_eq!(U256::checked_dot::<512, 8>(&a, &a), None);
+    /// assert_eq!(U256::checked_dot::<512, 8>(&a, &a[..1]), None);
+    /// ```
+    #[must_use]
+    pub fn checked_dot<const BITS_RES: usize, const LIMBS_RES: usize>(
+        a: &[Self],
+        b: &[Self],
+    ) -> Option<Uint<BITS_RES, LIMBS_RES>> {
+        if a.len() != b.len() {
+            return None;
+        }
+        let mut result = [0; LIMBS_RES];
+        for (a, b) in a.iter().zip(b) {
+            // Limbs only grow, so the high bits of a partial limb can be
+            // checked once at the end.
+            if algorithms::addmul(&mut result, a.as_limbs(), b.as_limbs()) {
+                return None;
+            }
+        }
+        Uint::checked_from_limbs_slice(&result)
+    }
+
+    /// Returns the quotient and remainder of `self * mul / div`.
+    fn mul_div_rem(self, mul: Self, mut div: Self) -> Option<(Self, Self)> {
+        if div.is_zero() {
+            return None;
+        }
+        let_double_bits!(product);
+        let overflow = algorithms::addmul(product, self.as_limbs(), mul.as_limbs());
+        debug_assert!(!overflow);
+        algorithms::div::div_inlined(product, &mut div.limbs);
+        match Self::overflowing_from_limbs_slice(product) {
+            (quotient, false) => Some((quotient, div)),
+            (_, true) => None,
+        }
+    }
+
+    /// Whether `quotient + remainder / divisor` rounds up to the nearest
+    /// integer, where `remainder < divisor`.
+    fn rounds_up(quotient: Self, remainder: Self, divisor: Self, ties: Ties) -> bool {
+        // Compares `2 * remainder` with `divisor` without overflow.
+        match remainder.cmp(&(divisor - remainder)) {
+            core::cmp::Ordering::Less => false,
+            core::cmp::Ordering::Greater => true,
+            core::cmp::Ordering::Equal => match ties {
+                Ties::Even => quotient.bit(0),
+                Ties::AwayFromZero => true,
+                Ties::TowardZero => false,
+            },
+        }
+    }
+
+    /// Computes `self / rhs` and `self % rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a "division by zero" message naming the bit width if
+    /// `rhs == 0`. All dividing methods and the `/` and `%` operators report
+    /// the caller's location. Use [`Self::checked_div_rem`] to handle a zero
+    /// `rhs`.
+    ///
+    /// ```should_panic
+    /// # use ruint::aliases::U256;
+    /// let _ = U256::from(7).div_rem(U256::ZERO);
+    /// ```
     #[inline(always)]
     #[must_use]
     #[track_caller]
     pub fn div_rem(mut self, mut rhs: Self) -> (Self, Self) {
+        if rhs.is_zero() {
+            panic_div_by_zero(BITS);
+        }
         if LIMBS == 1 {
             let q = &mut self.limbs[0];
             let r = &mut rhs.limbs[0];
@@ -63,9 +502,16 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 
     /// Computes `self / rhs` rounding down.
     ///
+    /// This is the implementation of the `/` operator.
+    ///
     /// # Panics
     ///
-    /// Panics if `rhs == 0`.
+    /// Panics if `rhs == 0`. Use [`Self::checked_div`] to handle a zero `rhs`.
+    ///
+    /// ```should_panic
+    /// # use ruint::aliases::U256;
+    /// let _ = U256::from(7) / U256::ZERO;
+    /// ```
     #[inline]
     #[must_use]
     #[track_caller]
@@ -75,17 +521,37 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 
     /// Computes `self % rhs`.
     ///
+    /// This is the implementation of the `%` operator. A power of two `rhs`
+    /// only takes a mask, and an `rhs` that fits in a single limb takes a short
+    /// division.
+    ///
     /// # Panics
     ///
-    /// Panics if `rhs == 0`.
+    /// Panics if `rhs == 0`. Use [`Self::checked_rem`] or [`Self::reduce_mod`]
+    /// to handle a zero `rhs`.
+    ///
+    /// ```should_panic
+    /// # use ruint::aliases::U256;
+    /// let _ = U256::from(7) % U256::ZERO;
+    /// ```
     #[inline]
     #[must_use]
     #[track_caller]
     pub fn wrapping_rem(self, rhs: Self) -> Self {
+        if rhs.is_power_of_two() {
+            return self & (rhs - Self::ONE);
+        }
         self.div_rem(rhs).1
     }
 }
 
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn panic_div_by_zero(bits: usize) -> ! {
+    panic!("attempt to divide Uint<{bits}> by zero: division by zero")
+}
+
 impl_bin_op!(Div, div, DivAssign, div_assign, wrapping_div);
 impl_bin_op!(Rem, rem, RemAssign, rem_assign, wrapping_rem);
 
@@ -93,7 +559,7 @@ impl_bin_op!(Rem, rem, RemAssign, rem_assign, wrapping_rem);
 mod tests {
     use super::*;
     use crate::{const_for, nlimbs};
-    use proptest::{prop_assume, proptest};
+    use proptest::{prelude::any, prop_assume, proptest};
 
     #[test]
     fn test_div_ceil() {
@@ -146,4 +612,328 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_rem_special() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, k in 0..BITS)| {
+                let m = U::ONE << k;
+                assert_eq!(n % m, n.div_rem(m).1);
+                assert_eq!(n.reduce_mod(m), n.div_rem(m).1);
+                assert_eq!(n % U::ONE, U::ZERO);
+                assert_eq!(n % U::MAX, if n == U::MAX { U::ZERO } else { n });
+                assert_eq!(n.reduce_mod(U::ZERO), U::ZERO);
+                assert_eq!(n.checked_rem(U::ZERO), None);
+            });
+        });
+    }
+
+    #[test]
+    fn test_overflowing_saturating() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert_eq!(U::ZERO.overflowing_div(U::MAX), (U::ZERO, false));
+            assert_eq!(U::MAX.overflowing_div(U::MAX), (U::ONE, false));
+            assert_eq!(U::MAX.overflowing_div(U::ONE), (U::MAX, false));
+            assert_eq!(U::MAX.overflowing_rem(U::MAX), (U::ZERO, false));
+            assert_eq!(U::ZERO.overflowing_rem(U::ONE), (U::ZERO, false));
+            assert_eq!(U::MAX.saturating_div(U::ONE), U::MAX);
+            assert_eq!(U::ZERO.saturating_div(U::MAX), U::ZERO);
+            proptest!(|(n: U, d: U)| {
+                prop_assume!(d != U::ZERO);
+                let (q, r) = n.div_rem(d);
+                assert_eq!(n.overflowing_div(d), (q, false));
+                assert_eq!(n.overflowing_rem(d), (r, false));
+                assert_eq!(n.saturating_div(d), q);
+            });
+        });
+    }
+
+    #[test]
+    fn test_exact_div() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert!(U::ZERO.divides(&U::ONE));
+            assert!(U::MAX.divides(&U::ONE));
+            assert!(U::MAX.divides(&U::MAX));
+            assert!(!U::ONE.divides(&U::ZERO));
+            assert!(!U::ZERO.divides(&U::ZERO));
+            assert_eq!(U::MAX.checked_exact_div(U::ONE), Some(U::MAX));
+            assert_eq!(U::ZERO.checked_exact_div(U::MAX), Some(U::ZERO));
+            assert_eq!(U::ZERO.checked_exact_div(U::ZERO), None);
+            proptest!(|(n: U, d: U, shift in 0..BITS)| {
+                for d in [d, d >> (BITS / 2), U::ONE << shift] {
+                    let (q, r) = n.checked_div_rem(d).unwrap_or((U::ZERO, U::ONE));
+                    assert_eq!(n.divides(&d), r.is_zero());
+                    assert_eq!(n.checked_exact_div(d), r.is_zero().then_some(q));
+                }
+            });
+        });
+        // One bit leaves no divisor above one to miss by one with.
+        const_for!(BITS in NON_ZERO if BITS >= 2 {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: U)| {
+                // A product is divisible by its factors, but a remainder of one
+                // is a near miss. The factors split the bits, so the product
+                // fits.
+                let d = (d >> (BITS / 2)) | U::from(2);
+                let quotient = n >> (BITS - BITS / 2);
+                let product = quotient.checked_mul(d).unwrap();
+                assert_eq!(product.checked_exact_div(d), Some(quotient));
+                if let Some(miss) = product.checked_add(U::ONE) {
+                    assert!(!miss.divides(&d));
+                    assert_eq!(miss.checked_exact_div(d), None);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_div_nearest() {
+        type U = Uint<256, 4>;
+        let ties = [Ties::Even, Ties::AwayFromZero, Ties::TowardZero];
+
+        // Exact halves at large magnitudes.
+        let half = U::MAX >> 1_usize; // (MAX - 1) / 2, odd
+        assert_eq!(U::MAX.div_nearest(U::from(2), Ties::Even), half + U::ONE);
+        assert_eq!(
+            U::MAX.div_nearest(U::from(2), Ties::AwayFromZero),
+            half + U::ONE
+        );
+        assert_eq!(U::MAX.div_nearest(U::from(2), Ties::TowardZero), half);
+        let even = (U::MAX - U::from(2)).div_nearest(U::from(2), Ties::Even);
+        assert_eq!(even, half - U::ONE);
+        for ties in ties {
+            assert_eq!(U::MAX.div_nearest(U::ONE, ties), U::MAX);
+            assert_eq!(U::MAX.div_nearest(U::MAX, ties), U::ONE);
+            assert_eq!(U::ZERO.div_nearest(U::MAX, ties), U::ZERO);
+            assert_eq!(half.div_nearest(U::MAX, ties), U::ZERO);
+            assert_eq!((half + U::ONE).div_nearest(U::MAX, ties), U::ONE);
+
+            assert_eq!(U::MAX.mul_div_nearest(U::MAX, U::MAX, ties), Some(U::MAX));
+            assert_eq!(U::MAX.mul_div_nearest(U::ONE, U::ONE, ties), Some(U::MAX));
+            assert_eq!(U::MAX.mul_div_nearest(U::from(2), U::from(3), ties), {
+                // 2 * MAX / 3 = (2^257 - 2) / 3 with remainder 2 * 2 mod 3 = 1.
+                Some(U::MAX / U::from(3) * U::from(2))
+            });
+            assert_eq!(U::MAX.mul_div_nearest(U::ONE, U::ZERO, ties), None);
+            assert_eq!(U::MAX.mul_div_nearest(U::from(2), U::ONE, ties), None);
+        }
+        // The quotient `MAX + 1 + 1 / (MAX - 1)` does not fit.
+        assert_eq!(
+            U::MAX.mul_div_nearest(U::MAX, U::MAX - U::ONE, Ties::AwayFromZero),
+            None
+        );
+
+        // Exact reference in double width, where `2 * remainder` fits.
+        proptest!(|(a: U, b: U, c: U)| {
+            type W = Uint<512, 8>;
+            prop_assume!(c != U::ZERO);
+            let (a_w, b_w, c_w) = (W::from(a), W::from(b), W::from(c));
+            for (n, expect_mul) in [(a_w, false), (a_w * b_w, true)] {
+                let (q, r) = n.div_rem(c_w);
+                for ties in ties {
+                    let up = match (r + r).cmp(&c_w) {
+                        core::cmp::Ordering::Less => false,
+                        core::cmp::Ordering::Greater => true,
+                        core::cmp::Ordering::Equal => match ties {
+                            Ties::Even => q.bit(0),
+                            Ties::AwayFromZero => true,
+                            Ties::TowardZero => false,
+                        },
+                    };
+                    let expected = U::checked_from(q + W::from(up));
+                    if expect_mul {
+                        assert_eq!(a.mul_div_nearest(b, c, ties), expected);
+                        assert_eq!(a.mul_div(b, c), U::checked_from(q));
+                    } else {
+                        assert_eq!(Some(a.div_nearest(c, ties)), expected);
+                    }
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_rescale() {
+        type U = Uint<64, 1>;
+        let roundings = [
+            Rounding::Down,
+            Rounding::Up,
+            Rounding::Nearest(Ties::Even),
+            Rounding::Nearest(Ties::AwayFromZero),
+            Rounding::Nearest(Ties::TowardZero),
+        ];
+
+        // Overflow boundary when scaling up.
+        let max = U::MAX / U::from(1000);
+        assert_eq!(max.rescale(6, 9, Rounding::Down), Some(max * U::from(1000)));
+        assert_eq!((max + U::ONE).rescale(6, 9, Rounding::Down), None);
+        assert_eq!(U::ONE.rescale(0, 19, Rounding::Down), U::pow10(19));
+        assert_eq!(U::ONE.rescale(0, 20, Rounding::Down), None);
+        assert_eq!(U::ZERO.rescale(0, 255, Rounding::Down), Some(U::ZERO));
+
+        // Scaling down to zero.
+        let to_zero = |value: u64, rounding| U::from(value).rescale(2, 0, rounding);
+        for (value, expected) in [
+            (49, [0, 1, 0, 0, 0]),
+            (50, [0, 1, 0, 1, 0]),
+            (51, [0, 1, 1, 1, 1]),
+        ] {
+            for (rounding, expected) in roundings.into_iter().zip(expected) {
+                assert_eq!(to_zero(value, rounding), Some(U::from(expected)));
+            }
+        }
+        // Where the scale itself does not fit.
+        type S = Uint<16, 1>;
+        for (value, expected) in [
+            (49_999, [0, 1, 0, 0, 0]),
+            (50_000, [0, 1, 0, 1, 0]),
+            (50_001, [0, 1, 1, 1, 1]),
+        ] {
+            for (rounding, expected) in roundings.into_iter().zip(expected) {
+                assert_eq!(
+                    S::from(value).rescale(5, 0, rounding),
+                    Some(S::from(expected))
+                );
+            }
+        }
+        for rounding in roundings {
+            assert_eq!(
+                U::MAX.rescale(255, 0, rounding),
+                Some(U::from(rounding == Rounding::Up))
+            );
+        }
+
+        // Agrees with a width where all scales up to 10^40 fit.
+        proptest!(|(value: U, from in 0_u8..40, to in 0_u8..40)| {
+            type W = Uint<256, 4>;
+            for rounding in roundings {
+                let expected = W::from(value)
+                    .rescale(from, to, rounding)
+                    .and_then(U::checked_from);
+                assert_eq!(value.rescale(from, to, rounding), expected);
+            }
+        });
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        type U = Uint<256, 4>;
+        // Exact reference with room for the sums of up to 8 products.
+        type W = Uint<576, 9>;
+        proptest!(|(pairs in proptest::collection::vec(any::<(U, U)>(), 0..8))| {
+            let numerator = pairs.iter().map(|&(v, w)| W::from(v) * W::from(w)).sum::<W>();
+            let total = pairs.iter().map(|&(_, w)| W::from(w)).sum::<W>();
+            let expected = numerator.checked_div(total).map(U::from);
+            assert_eq!(U::weighted_mean(&pairs), expected);
+
+            let values = pairs.iter().map(|&(v, _)| v).collect::<Vec<_>>();
+            let sum = values.iter().map(|&v| W::from(v)).sum::<W>();
+            let expected = sum.checked_div(W::from(values.len())).map(U::from);
+            assert_eq!(U::mean(&values), expected);
+
+            let weights = pairs.iter().map(|&(_, w)| w).collect::<Vec<_>>();
+            assert_eq!(U::checked_dot::<576, 9>(&values, &weights), Some(numerator));
+            let fits = W::checked_from(numerator).filter(|n| n.bit_len() <= 512);
+            assert_eq!(
+                U::checked_dot::<512, 8>(&values, &weights),
+                fits.map(Uint::<512, 8>::from)
+            );
+        });
+
+        // Both the naive numerator and the total weight overflow.
+        let pairs = [
+            (U::MAX, U::MAX),
+            (U::MAX - U::ONE, U::MAX),
+            (U::ONE, U::ONE),
+        ];
+        let expected = (W::from(U::MAX) * W::from(U::MAX) * W::from(2) - W::from(U::MAX) + W::ONE)
+            / (W::from(U::MAX) * W::from(2) + W::ONE);
+        assert_eq!(U::weighted_mean(&pairs), Some(U::from(expected)));
+        assert_eq!(U::weighted_mean(&[(U::MAX, U::MAX); 16]), Some(U::MAX));
+        assert_eq!(U::mean(&[U::MAX; 16]), Some(U::MAX));
+        assert_eq!(U::weighted_mean(&[(U::MAX, U::ZERO); 2]), None);
+        assert_eq!(U::weighted_mean(&[]), None);
+        assert_eq!(U::checked_dot::<512, 8>(&[], &[]), Some(Uint::ZERO));
+
+        type V = Uint<65, 2>;
+        // `MAX² / (MAX + 1)` rounds down to `MAX - 1`.
+        let pairs = [(V::MAX, V::MAX), (V::ZERO, V::ONE)];
+        assert_eq!(V::weighted_mean(&pairs), Some(V::MAX - V::ONE));
+        let square = Uint::<130, 3>::from(V::MAX).pow(Uint::from(2));
+        assert_eq!(V::checked_dot::<130, 3>(&[V::MAX], &[V::MAX]), Some(square));
+        assert_eq!(V::checked_dot::<130, 3>(&[V::MAX; 2], &[V::MAX; 2]), None);
+        assert_eq!(
+            Uint::<0, 0>::weighted_mean(&[(Uint::ZERO, Uint::ZERO)]),
+            None
+        );
+        assert_eq!(Uint::<0, 0>::mean(&[Uint::ZERO]), Some(Uint::ZERO));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide Uint<256> by zero: division by zero")]
+    fn test_rem_zero() {
+        let _ = Uint::<256, 4>::ONE % Uint::ZERO;
+    }
+
+    #[test]
+    fn test_div_zero() {
+        extern crate std;
+        use std::{panic::catch_unwind, string::String};
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U)| {
+                assert_eq!(n.checked_div(U::ZERO), None);
+                assert_eq!(n.checked_rem(U::ZERO), None);
+                assert_eq!(n.checked_div_rem(U::ZERO), None);
+                assert_eq!(n.mul_div(n, U::ZERO), None);
+            });
+            let expected = format!("attempt to divide Uint<{BITS}> by zero: division by zero");
+            let message = |f: fn(U) -> U| {
+                let payload = catch_unwind(|| f(U::MAX)).unwrap_err();
+                payload.downcast::<String>().map(|s| *s).unwrap_or_default()
+            };
+            assert_eq!(message(|n| n / U::ZERO), expected);
+            assert_eq!(message(|n| n % U::ZERO), expected);
+            assert_eq!(message(|mut n| { n /= U::ZERO; n }), expected);
+            assert_eq!(message(|mut n| { n %= U::ZERO; n }), expected);
+            assert_eq!(message(|n| n.div_ceil(U::ZERO)), expected);
+            assert_eq!(message(|n| n.div_nearest(U::ZERO, Ties::Even)), expected);
+            assert_eq!(message(|n| n.div_rem(U::ZERO).1), expected);
+        });
+    }
+
+    /// Any non-zero divisor must not panic.
+    #[test]
+    fn test_div_no_panic() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: U, shift in 0..=BITS)| {
+                // Vary the divisor's size, since the algorithm depends on it.
+                let d = d >> shift;
+                let Some((q, r)) = n.checked_div_rem(d) else {
+                    assert!(d.is_zero());
+                    return Ok(());
+                };
+                assert_eq!(n.checked_div(d), Some(q));
+                assert_eq!(n.checked_rem(d), Some(r));
+                assert_eq!((n / d, n % d), (q, r));
+                assert_eq!(n.wrapping_rem(d), r);
+                assert_eq!(n.overflowing_div(d), (q, false));
+                assert_eq!(n.saturating_div(d), q);
+                let _ = (n.div_ceil(d), n.div_nearest(d, Ties::Even), n.mul_div(n, d));
+                assert!(r < d);
+                assert_eq!(q.checked_mul(d).and_then(|qd| qd.checked_add(r)), Some(n));
+            });
+        });
+    }
 }
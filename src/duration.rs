@@ -0,0 +1,163 @@
+use crate::{Uint, from::ToUintError};
+use core::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Construct a new [`Uint`] from the total number of nanoseconds in a
+    /// [`Duration`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToUintError::ValueTooLarge`] if the number of nanoseconds does
+    /// not fit in the [`Uint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # use core::time::Duration;
+    /// # type U96 = Uint<96, 2>;
+    /// # uint! {
+    /// assert_eq!(
+    ///     U96::from_duration_nanos(Duration::new(3, 5)),
+    ///     Ok(3_000_000_005_U96)
+    /// );
+    /// assert!(U64::from_duration_nanos(Duration::MAX).is_err());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_duration_nanos(duration: Duration) -> Result<Self, ToUintError<Self>> {
+        Self::try_from(duration.as_nanos())
+    }
+
+    /// Construct a new [`Uint`] from the total number of whole microseconds in
+    /// a [`Duration`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToUintError::ValueTooLarge`] if the number of microseconds
+    /// does not fit in the [`Uint`].
+    #[inline]
+    pub fn from_duration_micros(duration: Duration) -> Result<Self, ToUintError<Self>> {
+        Self::try_from(duration.as_micros())
+    }
+
+    /// Construct a new [`Uint`] from the total number of whole milliseconds in
+    /// a [`Duration`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToUintError::ValueTooLarge`] if the number of milliseconds
+    /// does not fit in the [`Uint`].
+    #[inline]
+    pub fn from_duration_millis(duration: Duration) -> Result<Self, ToUintError<Self>> {
+        Self::try_from(duration.as_millis())
+    }
+
+    /// Construct a new [`Uint`] from the number of whole seconds in a
+    /// [`Duration`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToUintError::ValueTooLarge`] if the number of seconds does not
+    /// fit in the [`Uint`].
+    #[inline]
+    pub fn from_duration_secs(duration: Duration) -> Result<Self, ToUintError<Self>> {
+        Self::try_from(duration.as_secs())
+    }
+
+    /// Interpret the value as a number of nanoseconds and convert it to a
+    /// [`Duration`], or [`None`] if it exceeds [`Duration::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # use core::time::Duration;
+    /// # type U96 = Uint<96, 2>;
+    /// # uint! {
+    /// assert_eq!(
+    ///     3_000_000_005_U96.to_duration_nanos(),
+    ///     Some(Duration::new(3, 5))
+    /// );
+    /// assert_eq!(U128::MAX.to_duration_nanos(), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // Checked or less than a second.
+    pub fn to_duration_nanos(&self) -> Option<Duration> {
+        let nanos = u128::try_from(self).ok()?;
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+        Some(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{U64, U128},
+        const_for, nlimbs,
+    };
+    use proptest::proptest;
+
+    type U96 = Uint<96, 2>;
+
+    #[test]
+    fn test_duration_boundaries() {
+        assert_eq!(U96::from_duration_nanos(Duration::ZERO), Ok(U96::ZERO));
+        assert_eq!(U96::ZERO.to_duration_nanos(), Some(Duration::ZERO));
+        assert_eq!(
+            Uint::<0, 0>::from_duration_secs(Duration::ZERO),
+            Ok(Uint::ZERO)
+        );
+        assert_eq!(Uint::<0, 0>::ZERO.to_duration_nanos(), Some(Duration::ZERO));
+
+        // `Duration::MAX` is just below 2^94 nanoseconds.
+        let max = U96::from_duration_nanos(Duration::MAX).unwrap();
+        assert_eq!(
+            max,
+            U96::from(u64::MAX) * U96::from(NANOS_PER_SEC) + U96::from(999_999_999)
+        );
+        assert_eq!(max.to_duration_nanos(), Some(Duration::MAX));
+        assert_eq!((max + U96::ONE).to_duration_nanos(), None);
+        assert_eq!(U96::MAX.to_duration_nanos(), None);
+        assert!(Uint::<93, 2>::from_duration_nanos(Duration::MAX).is_err());
+        assert!(Uint::<94, 2>::from_duration_nanos(Duration::MAX).is_ok());
+
+        assert_eq!(
+            U64::from_duration_secs(Duration::MAX),
+            Ok(U64::from(u64::MAX))
+        );
+        assert_eq!(
+            U64::from_duration_millis(Duration::new(1, 999_999_999)),
+            Ok(U64::from(1_999))
+        );
+        assert_eq!(
+            U64::from_duration_micros(Duration::new(1, 999_999_999)),
+            Ok(U64::from(1_999_999))
+        );
+        assert!(U64::from_duration_millis(Duration::MAX).is_err());
+        assert!(U128::from_duration_millis(Duration::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_duration_roundtrip() {
+        proptest!(|(secs: u64, nanos in 0_u32..1_000_000_000)| {
+            let duration = Duration::new(secs, nanos);
+            let value = U96::from_duration_nanos(duration).unwrap();
+            assert_eq!(value.to_duration_nanos(), Some(duration));
+        });
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                if let Some(duration) = value.to_duration_nanos() {
+                    assert_eq!(U::from_duration_nanos(duration), Ok(value));
+                }
+            });
+        });
+    }
+}
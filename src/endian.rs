@@ -0,0 +1,321 @@
+//! Integers stored as bytes in a fixed byte order, for binary layouts.
+
+use crate::{Uint, from::ToUintError};
+use core::{cmp::Ordering, fmt, marker::PhantomData};
+
+macro_rules! impl_endian {
+    (
+        $name:ident,
+        $order:literal,
+        $to_bytes:ident,
+        $from_bytes:ident,
+        $from_slice:ident,
+        $top:ident
+    ) => {
+        #[doc = concat!("A [`Uint`] stored as ", $order, " bytes.")]
+        /// This makes the byte order of binary layouts, like packed network
+        /// structs, explicit in the type. It has the size of the serialized
+        /// value and an alignment of one. With the `zerocopy` feature it
+        /// implements the `zerocopy` traits, so it can be a field of such a
+        /// struct directly.
+        ///
+        /// `BYTES` must be equal to [`Uint::BYTES`], which is checked at compile
+        /// time. It is a separate parameter because stable Rust can not yet
+        /// compute it from `BITS`.
+        ///
+        /// Comparisons are numeric, like those of the [`Uint`].
+        #[repr(transparent)]
+        #[cfg_attr(
+            feature = "zerocopy",
+            derive(
+                zerocopy::FromBytes,
+                zerocopy::IntoBytes,
+                zerocopy::KnownLayout,
+                zerocopy::Immutable,
+                zerocopy::Unaligned,
+            )
+        )]
+        pub struct $name<T, const BYTES: usize> {
+            bytes:   [u8; BYTES],
+            _marker: PhantomData<T>,
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize>
+            $name<Uint<BITS, LIMBS>, BYTES>
+        {
+            /// Stores `value` in this byte order.
+            #[inline]
+            #[must_use]
+            pub const fn new(value: Uint<BITS, LIMBS>) -> Self {
+                Self::from_bytes(value.$to_bytes())
+            }
+
+            /// Wraps bytes that are already in this byte order.
+            #[inline]
+            #[must_use]
+            pub const fn from_bytes(bytes: [u8; BYTES]) -> Self {
+                const { Uint::<BITS, LIMBS>::assert_bytes(BYTES) }
+                Self {
+                    bytes,
+                    _marker: PhantomData,
+                }
+            }
+
+            /// Returns the stored bytes.
+            #[inline]
+            #[must_use]
+            pub const fn into_bytes(self) -> [u8; BYTES] {
+                self.bytes
+            }
+
+            /// Returns a reference to the stored bytes.
+            #[inline]
+            #[must_use]
+            pub const fn as_bytes(&self) -> &[u8; BYTES] {
+                &self.bytes
+            }
+
+            /// Decodes the stored value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the bytes encode a value that does not fit in `BITS`
+            /// bits, which can only happen if they were not created from a
+            /// [`Uint`], for example when they were read from a packet. Use
+            /// [`Self::try_get`] or [`TryFrom`] for untrusted bytes.
+            #[inline]
+            #[must_use]
+            #[track_caller]
+            pub const fn get(self) -> Uint<BITS, LIMBS> {
+                Uint::$from_bytes(self.bytes)
+            }
+
+            /// Decodes the stored value, or returns [`None`] if it does not fit
+            /// in `BITS` bits.
+            #[inline]
+            #[must_use]
+            pub const fn try_get(self) -> Option<Uint<BITS, LIMBS>> {
+                Uint::$from_slice(&self.bytes)
+            }
+
+            /// Sets the stored value.
+            #[inline]
+            pub const fn set(&mut self, value: Uint<BITS, LIMBS>) {
+                self.bytes = value.$to_bytes();
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> Clone
+            for $name<Uint<BITS, LIMBS>, BYTES>
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> Copy
+            for $name<Uint<BITS, LIMBS>, BYTES>
+        {
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> Default
+            for $name<Uint<BITS, LIMBS>, BYTES>
+        {
+            #[inline]
+            fn default() -> Self {
+                Self::from_bytes([0; BYTES])
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> fmt::Debug
+            for $name<Uint<BITS, LIMBS>, BYTES>
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut tuple = f.debug_tuple(stringify!($name));
+                match self.try_get() {
+                    Some(value) => tuple.field(&value),
+                    None => tuple.field(&self.bytes),
+                };
+                tuple.finish()
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> PartialEq
+            for $name<Uint<BITS, LIMBS>, BYTES>
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.bytes == other.bytes
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> Eq
+            for $name<Uint<BITS, LIMBS>, BYTES>
+        {
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> core::hash::Hash
+            for $name<Uint<BITS, LIMBS>, BYTES>
+        {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.bytes.hash(state);
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> PartialOrd
+            for $name<Uint<BITS, LIMBS>, BYTES>
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> From<Uint<BITS, LIMBS>>
+            for $name<Uint<BITS, LIMBS>, BYTES>
+        {
+            #[inline]
+            fn from(value: Uint<BITS, LIMBS>) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize, const BYTES: usize>
+            TryFrom<$name<Uint<BITS, LIMBS>, BYTES>> for Uint<BITS, LIMBS>
+        {
+            type Error = ToUintError<Self>;
+
+            #[inline]
+            fn try_from(value: $name<Self, BYTES>) -> Result<Self, Self::Error> {
+                value.try_get().ok_or_else(|| {
+                    // Only the most significant byte can hold bits beyond `BITS`.
+                    let mut bytes = value.bytes;
+                    if let Some(top) = bytes.$top() {
+                        *top &= u8::MAX >> (BYTES * 8 - BITS);
+                    }
+                    ToUintError::ValueTooLarge(BITS, Uint::$from_bytes(bytes))
+                })
+            }
+        }
+    };
+}
+
+impl_endian!(
+    Be,
+    "big-endian",
+    to_be_bytes,
+    from_be_bytes,
+    try_from_be_slice,
+    first_mut
+);
+impl_endian!(
+    Le,
+    "little-endian",
+    to_le_bytes,
+    from_le_bytes,
+    try_from_le_slice,
+    last_mut
+);
+
+// Big-endian bytes compare like the numbers they encode.
+impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> Ord
+    for Be<Uint<BITS, LIMBS>, BYTES>
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes.cmp(&other.bytes)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const BYTES: usize> Ord
+    for Le<Uint<BITS, LIMBS>, BYTES>
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes.iter().rev().cmp(other.bytes.iter().rev())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U16, const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            const BYTES: usize = Uint::<BITS, LIMBS>::BYTES;
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                let (be_a, be_b) = (Be::<U, BYTES>::from(a), Be::<U, BYTES>::from(b));
+                let (le_a, le_b) = (Le::<U, BYTES>::from(a), Le::<U, BYTES>::from(b));
+                assert_eq!(be_a.into_bytes(), a.to_be_bytes::<BYTES>());
+                assert_eq!(le_a.into_bytes(), a.to_le_bytes::<BYTES>());
+                assert_eq!(U::try_from(be_a), Ok(a));
+                assert_eq!(U::try_from(le_a), Ok(a));
+                assert_eq!(be_a.cmp(&be_b), a.cmp(&b));
+                assert_eq!(le_a.cmp(&le_b), a.cmp(&b));
+                assert_eq!(be_a == be_b, a == b);
+            });
+        });
+    }
+
+    #[test]
+    fn test_invalid_bytes() {
+        type U12 = Uint<12, 1>;
+        let be = Be::<U12, 2>::from_bytes([0xf0, 0x00]);
+        assert_eq!(be.try_get(), None);
+        assert_eq!(format!("{be:?}"), "Be([240, 0])");
+        assert_eq!(
+            U12::try_from(be),
+            Err(ToUintError::ValueTooLarge(12, U12::from(0x000)))
+        );
+        assert_eq!(
+            U12::try_from(Le::<U12, 2>::from_bytes([0x34, 0x12])),
+            Err(ToUintError::ValueTooLarge(12, U12::from(0x234)))
+        );
+        assert_eq!(
+            Le::<U12, 2>::from_bytes([0x34, 0x02]).try_get(),
+            Some(U12::from(0x234))
+        );
+        assert!(Be::<U16, 2>::default().get().is_zero());
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn test_packed_struct() {
+        use crate::aliases::{U64, U256};
+        use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+        #[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+        #[repr(C, packed)]
+        struct Header {
+            kind:   u8,
+            amount: Be<U256, 32>,
+            nonce:  Le<U64, 8>,
+        }
+
+        let header = Header {
+            kind:   7,
+            amount: U256::from(0x0102_0304_u64).into(),
+            nonce:  U64::from(0x0a0b_u64).into(),
+        };
+        let mut expected = [0_u8; 41];
+        expected[0] = 7;
+        expected[29..33].copy_from_slice(&[1, 2, 3, 4]);
+        expected[33..35].copy_from_slice(&[0x0b, 0x0a]);
+        assert_eq!(header.as_bytes(), &expected);
+
+        let decoded = Header::read_from_bytes(&expected).unwrap();
+        assert_eq!(decoded.kind, 7);
+        assert_eq!(decoded.amount.get(), U256::from(0x0102_0304_u64));
+        assert_eq!(decoded.nonce.get(), U64::from(0x0a0b_u64));
+
+        let view = Header::ref_from_bytes(&expected[..]).unwrap();
+        assert_eq!({ view.amount }.get(), U256::from(0x0102_0304_u64));
+    }
+}
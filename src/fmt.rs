@@ -76,18 +76,436 @@ macro_rules! impl_fmt {
     };
 }
 
+/// Error for [`Uint::to_dec_str`] and [`Uint::to_hex_str`] when the buffer
+/// can not hold all digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall;
+
+impl core::error::Error for BufferTooSmall {}
+
+impl fmt::Display for BufferTooSmall {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("buffer too small to hold all digits")
+    }
+}
+
+/// See [`Uint::max_dec_len`].
+const fn max_dec_len(bits: usize) -> usize {
+    // ⌊log₁₀(2) · 2¹²⁸⌋ in two halves.
+    const LOG10_2_HI: u128 = 0x4d10_4d42_7de7_fbcc;
+    const LOG10_2_LO: u128 = 0x47c4_acd6_05be_48bc;
+    let bits = bits as u128;
+    ((bits * LOG10_2_HI + ((bits * LOG10_2_LO) >> 64)) >> 64) as usize + 1
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// The maximum number of decimal digits of a value of this type, which is
+    /// the length of [`Self::MAX`] in decimal and the smallest precision `p` of
+    /// a `DECIMAL(p, 0)` column that holds every value.
+    ///
+    /// This is $\floor{\mathtt{BITS} ⋅ \log_{10} 2} + 1$, computed with a
+    /// 128-bit fixed point logarithm. That is exact for any `BITS` that fits
+    /// in memory, since $\mathtt{BITS} ⋅ \log_{10} 2$ is never within
+    /// $2^{-64}$ of an integer there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::*;
+    /// assert_eq!(U64::max_dec_len(), u64::MAX.to_string().len());
+    /// assert_eq!(U256::max_dec_len(), 78);
+    /// assert_eq!(U0::max_dec_len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max_dec_len() -> usize {
+        max_dec_len(BITS)
+    }
+
+    /// Returns `true` if the value has at most `digits` decimal digits, i.e.
+    /// fits a `DECIMAL(digits, 0)` column.
+    ///
+    /// Zero has one digit, like its [`Display`](fmt::Display) form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// assert!(U256::from(999).fits_decimal_digits(3));
+    /// assert!(!U256::from(1000).fits_decimal_digits(3));
+    /// assert!(U256::MAX.fits_decimal_digits(78));
+    /// assert!(!U256::ZERO.fits_decimal_digits(0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fits_decimal_digits(&self, digits: usize) -> bool {
+        if digits == 0 {
+            return false;
+        }
+        match Self::pow10(digits) {
+            Some(bound) => *self < bound,
+            None => true,
+        }
+    }
+
+    /// Returns the number of decimal digits of `self`, which is the length of
+    /// its [`Display`](fmt::Display) form, without formatting it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// assert_eq!(U256::ZERO.dec_str_len(), 1);
+    /// assert_eq!(U256::from(999).dec_str_len(), 3);
+    /// assert_eq!(U256::from(1000).dec_str_len(), 4);
+    /// assert_eq!(U256::MAX.dec_str_len(), U256::max_dec_len());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn dec_str_len(&self) -> usize {
+        // A value of `bits` bits has either the digits of `2^(bits - 1)` or
+        // one more.
+        let digits = max_dec_len(self.bit_len().saturating_sub(1));
+        if self.fits_decimal_digits(digits) {
+            digits
+        } else {
+            digits + 1
+        }
+    }
+
+    /// The maximum number of hexadecimal digits of a value of this type, which
+    /// is the length of [`Self::MAX`] in hexadecimal.
+    #[inline]
+    #[must_use]
+    pub const fn max_hex_len() -> usize {
+        if BITS == 0 { 1 } else { BITS.div_ceil(4) }
+    }
+
+    /// Writes the decimal digits of `self` right-aligned into `buf` and
+    /// returns the written part as a string.
+    ///
+    /// This does not allocate. A buffer of [`Self::max_dec_len`] bytes is
+    /// always large enough.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BufferTooSmall`] if `buf` can not hold all digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U128};
+    /// let mut buf = [0_u8; U128::max_dec_len()];
+    /// let value = uint!(340282366920938463463374607431768211455_U128);
+    /// assert_eq!(
+    ///     value.to_dec_str(&mut buf),
+    ///     Ok("340282366920938463463374607431768211455")
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_dec_str<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+        if LIMBS >= DEC_SPLIT_LIMBS {
+            return self.write_dec_digits_split(buf);
+        }
+        self.write_base_digits::<base::Decimal>(buf)
+    }
+
+    /// Writes the lowercase hexadecimal digits of `self` right-aligned into
+    /// `buf` and returns the written part as a string. No `0x` prefix is
+    /// written.
+    ///
+    /// This does not allocate. A buffer of [`Self::max_hex_len`] bytes is
+    /// always large enough.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BufferTooSmall`] if `buf` can not hold all digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U128};
+    /// let mut buf = [0_u8; U128::max_hex_len()];
+    /// assert_eq!(uint!(0xc0ffee_U128).to_hex_str(&mut buf), Ok("c0ffee"));
+    /// ```
+    #[inline]
+    pub fn to_hex_str<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+        self.write_base_digits::<base::Hexadecimal>(buf)
+    }
+
+    /// Displays `self` as a fixed-point number with `decimals` fractional
+    /// digits, e.g. an amount of wei with 18 decimals as ether.
+    ///
+    /// The formatter's precision sets the number of fractional digits shown
+    /// and defaults to `decimals`. A lower precision rounds half up, which can
+    /// carry into the integer part. A higher precision pads with zeros. Width,
+    /// fill and alignment are honored, with right alignment as the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// let wei = uint!(1_337_000_000_000_000_000_U256);
+    /// assert_eq!(format!("{}", wei.display_fixed(18)), "1.337000000000000000");
+    /// assert_eq!(format!("{:>12.4}", wei.display_fixed(18)), "      1.3370");
+    /// assert_eq!(format!("{:<8.1}|", wei.display_fixed(18)), "1.3     |");
+    /// assert_eq!(format!("{:.2}", uint!(9999_U256).display_fixed(4)), "1.00");
+    /// assert_eq!(format!("{:.0}", uint!(15_U256).display_fixed(1)), "2");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn display_fixed(&self, decimals: u8) -> impl fmt::Display + use<BITS, LIMBS> {
+        DisplayFixed {
+            value: *self,
+            decimals,
+        }
+    }
+
+    /// Writes the decimal digits by divide-and-conquer: `self` is split into
+    /// a quotient and remainder by $10^{19 ⋅ 2^k}$ and both halves are written
+    /// recursively, down to single `u64` chunks.
+    ///
+    /// Each division only involves the limbs in use, so this does far less
+    /// work than dividing the whole value by $10^{19}$ once per chunk. It is
+    /// still quadratic in the number of limbs, as division is schoolbook.
+    fn write_dec_digits_split<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+        // `powers[k] = 10^(19 ⋅ 2^k)` up to the first one whose square exceeds
+        // `self`. They live on the stack, so keep few: `10^(19 ⋅ 2^16)` has over
+        // four million bits, and the rare wider value is written by repeated
+        // division instead.
+        let mut powers = [Self::ZERO; 16];
+        powers[0] = Self::from(base::Decimal::MAX);
+        let mut len = 1;
+        while let Some(square) = powers[len - 1].checked_mul(powers[len - 1]) {
+            if square > *self {
+                break;
+            }
+            if len == powers.len() {
+                return self.write_base_digits::<base::Decimal>(buf);
+            }
+            powers[len] = square;
+            len += 1;
+        }
+
+        let mut pos = buf.len();
+        write_dec_split(*self, &powers[..len], false, buf, &mut pos)?;
+        // SAFETY: Only ASCII digits were written to `buf[pos..]`.
+        Ok(unsafe { core::str::from_utf8_unchecked(&buf[pos..]) })
+    }
+
+    fn write_base_digits<'a, B: Base>(&self, buf: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let mut pos = buf.len();
+        let mut push = |digit: u64| {
+            pos = pos.checked_sub(1).ok_or(BufferTooSmall)?;
+            buf[pos] = DIGITS[digit as usize];
+            Ok(())
+        };
+
+        let mut spigots = self.to_base_le(B::MAX).peekable();
+        if spigots.peek().is_none() {
+            push(0)?;
+        }
+        while let Some(mut spigot) = spigots.next() {
+            // All but the most significant spigot are zero padded.
+            let last = spigots.peek().is_none();
+            let mut written = 0;
+            while (last && spigot != 0) || (!last && written < B::WIDTH) {
+                push(spigot % B::BASE)?;
+                spigot /= B::BASE;
+                written += 1;
+            }
+        }
+
+        // SAFETY: Only ASCII digits were written to `buf[pos..]`.
+        Ok(unsafe { core::str::from_utf8_unchecked(&buf[pos..]) })
+    }
+}
+
+/// Values with at least this many limbs are written in decimal by
+/// [`Uint::write_dec_digits_split`]. Below about 1024 bits, repeated division
+/// by $10^{19}$ is faster.
+const DEC_SPLIT_LIMBS: usize = 16;
+
+/// Writes the decimal digits of `value < powers.last()²` right-aligned before
+/// `buf[*pos]`, zero padded to `19 ⋅ 2^powers.len()` digits if `pad` is set.
+/// `powers` is not empty.
+fn write_dec_split<const BITS: usize, const LIMBS: usize>(
+    value: Uint<BITS, LIMBS>,
+    powers: &[Uint<BITS, LIMBS>],
+    pad: bool,
+    buf: &mut [u8],
+    pos: &mut usize,
+) -> Result<(), BufferTooSmall> {
+    let (power, powers) = powers.split_last().expect("powers is not empty");
+    if powers.is_empty() {
+        let value = u128::try_from(value).expect("value < 10^38 fits u128");
+        let chunk = u128::from(base::Decimal::MAX);
+        #[allow(clippy::cast_possible_truncation)] // Both are less than `10^19`.
+        let (high, low) = ((value / chunk) as u64, (value % chunk) as u64);
+        write_dec_chunk(low, pad || high != 0, buf, pos)?;
+        if pad || high != 0 {
+            write_dec_chunk(high, pad, buf, pos)?;
+        }
+        return Ok(());
+    }
+    if !pad && value < *power {
+        return write_dec_split(value, powers, false, buf, pos);
+    }
+    let (high, low) = value.div_rem(*power);
+    write_dec_split(low, powers, true, buf, pos)?;
+    write_dec_split(high, powers, pad, buf, pos)
+}
+
+/// Writes the decimal digits of `chunk < 10^19` right-aligned before
+/// `buf[*pos]`, zero padded to 19 digits if `pad` is set.
+fn write_dec_chunk(
+    mut chunk: u64,
+    pad: bool,
+    buf: &mut [u8],
+    pos: &mut usize,
+) -> Result<(), BufferTooSmall> {
+    let mut written = 0;
+    while chunk != 0 || written == 0 || (pad && written < base::Decimal::WIDTH) {
+        *pos = pos.checked_sub(1).ok_or(BufferTooSmall)?;
+        #[allow(clippy::cast_possible_truncation)] // A single digit.
+        let digit = (chunk % 10) as u8;
+        buf[*pos] = b'0' + digit;
+        chunk /= 10;
+        written += 1;
+    }
+    Ok(())
+}
+
+/// Formats the value as a [`uint!`](crate::uint) literal, e.g. `0x4d2_U256`,
+/// so it can be pasted back into code.
+///
+/// The alternate form `{:#?}` appends the decimal value and the bit length in
+/// a comment, e.g. `0x4d2_U256 /* 1234 (11 bits) */`. Width, fill and other
+/// flags are ignored.
 impl<const BITS: usize, const LIMBS: usize> fmt::Debug for Uint<BITS, LIMBS> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(self, f)
+        write!(f, "{self:#x}_U{BITS}")?;
+        if f.alternate() {
+            write!(f, " /* {self} ({} bits) */", self.bit_len())?;
+        }
+        Ok(())
     }
 }
 
-impl_fmt!(fmt::Display; base::Decimal, "");
+impl<const BITS: usize, const LIMBS: usize> fmt::Display for Uint<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Ok(small) = u64::try_from(self) {
+            return fmt::Display::fmt(&small, f);
+        }
+        if let Ok(small) = u128::try_from(self) {
+            return fmt::Display::fmt(&small, f);
+        }
+
+        // Use `BITS` since `generic_const_exprs` is not yet stable. It exceeds
+        // `max_dec_len` for all values that reach here.
+        let mut buf = [0_u8; BITS];
+        let s = self.to_dec_str(&mut buf).map_err(|_| fmt::Error)?;
+        f.pad_integral(true, "", s)
+    }
+}
 impl_fmt!(fmt::Binary; base::Binary, "b");
 impl_fmt!(fmt::Octal; base::Octal, "o");
 impl_fmt!(fmt::LowerHex; base::Hexadecimal, "x");
 impl_fmt!(fmt::UpperHex; base::Hexadecimal, "X");
 
+/// See [`Uint::display_fixed`].
+struct DisplayFixed<const BITS: usize, const LIMBS: usize> {
+    value:    Uint<BITS, LIMBS>,
+    decimals: u8,
+}
+
+impl<const BITS: usize, const LIMBS: usize> DisplayFixed<BITS, LIMBS> {
+    /// Returns the integer part and the first `digits` fractional digits,
+    /// rounded half up, where `digits <= decimals`.
+    fn split(&self, digits: usize) -> (Uint<BITS, LIMBS>, Uint<BITS, LIMBS>) {
+        let value = self.value;
+        let dropped = usize::from(self.decimals) - digits;
+        let scaled = if dropped == 0 {
+            value
+        } else if let Some(scale) = Uint::pow10(dropped) {
+            let (quotient, remainder) = value.div_rem(scale);
+            // `quotient < MAX` because `scale >= 10`.
+            if remainder >= scale - remainder {
+                quotient + Uint::ONE
+            } else {
+                quotient
+            }
+        } else {
+            // The value is below `scale`, so only the rounding is left.
+            let half =
+                Uint::pow10(dropped - 1).and_then(|p| p.checked_mul(Uint::try_from(5_u64).ok()?));
+            if half.is_some_and(|half| value >= half) {
+                Uint::ONE
+            } else {
+                Uint::ZERO
+            }
+        };
+        match Uint::pow10(digits) {
+            Some(scale) if digits > 0 => scaled.div_rem(scale),
+            Some(_) => (scaled, Uint::ZERO),
+            None => (Uint::ZERO, scaled),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> fmt::Display for DisplayFixed<BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(self.decimals.into());
+        let digits = precision.min(self.decimals.into());
+        let (integer, fraction) = self.split(digits);
+
+        let mut len = Counter(if precision > 0 { precision + 1 } else { 0 });
+        write!(len, "{integer}")?;
+        let padding = f.width().unwrap_or(0).saturating_sub(len.0);
+        let (fill, (pre, post)) = if f.sign_aware_zero_pad() {
+            ('0', (padding, 0))
+        } else {
+            let pre = match f.align() {
+                Some(fmt::Alignment::Left) => 0,
+                Some(fmt::Alignment::Center) => padding / 2,
+                Some(fmt::Alignment::Right) | None => padding,
+            };
+            (f.fill(), (pre, padding - pre))
+        };
+
+        for _ in 0..pre {
+            f.write_char(fill)?;
+        }
+        write!(f, "{integer}")?;
+        if precision > 0 {
+            f.write_char('.')?;
+            if digits > 0 {
+                write!(f, "{fraction:0digits$}")?;
+            }
+            for _ in digits..precision {
+                f.write_char('0')?;
+            }
+        }
+        for _ in 0..post {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`fmt::Write`] that only counts the bytes written.
+struct Counter(usize);
+
+impl fmt::Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
 /// A stack-allocated buffer that implements [`fmt::Write`].
 pub(crate) struct StackString<const SIZE: usize> {
     len: usize,
@@ -146,6 +564,10 @@ impl<const SIZE: usize> fmt::Write for StackString<SIZE> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        aliases::{U64, U256},
+        const_for, nlimbs,
+    };
     use proptest::{prop_assert_eq, proptest};
 
     #[allow(unused_imports)]
@@ -179,6 +601,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_str() {
+        let mut buf = [0_u8; 80];
+        assert_eq!(
+            N.to_dec_str(&mut buf),
+            Ok("90630363884335538722706632492458228784305343302099024356772372330524102404852")
+        );
+        assert_eq!(
+            N.to_hex_str(&mut buf),
+            Ok("c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4")
+        );
+        assert_eq!(Uint::<0, 0>::ZERO.to_dec_str(&mut buf), Ok("0"));
+        assert_eq!(Uint::<0, 0>::ZERO.to_hex_str(&mut buf), Ok("0"));
+        assert_eq!(Uint::<0, 0>::max_dec_len(), 1);
+        assert_eq!(Uint::<0, 0>::max_hex_len(), 1);
+        assert_eq!(Uint::<64, 1>::ZERO.to_dec_str(&mut []), Err(BufferTooSmall));
+        assert_eq!(
+            Uint::<64, 1>::from(1234).to_dec_str(&mut buf[..3]),
+            Err(BufferTooSmall)
+        );
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+
+            // The bounds are exact and reached at `MAX`.
+            let mut buf = [0_u8; 2000];
+            let dec = U::MAX.to_dec_str(&mut buf).unwrap().len();
+            assert_eq!(dec, U::max_dec_len());
+            let hex = U::MAX.to_hex_str(&mut buf).unwrap().len();
+            assert_eq!(hex, U::max_hex_len());
+            assert_eq!(U::MAX.to_dec_str(&mut buf[..dec - 1]), Err(BufferTooSmall));
+            assert_eq!(U::MAX.to_hex_str(&mut buf[..hex - 1]), Err(BufferTooSmall));
+
+            proptest!(|(value: U)| {
+                let mut buf = [0_u8; 2000];
+                prop_assert_eq!(value.to_dec_str(&mut buf).unwrap(), format!("{value}"));
+                prop_assert_eq!(value.to_hex_str(&mut buf).unwrap(), format!("{value:x}"));
+            });
+        });
+    }
+
+    #[test]
+    fn test_display_fixed() {
+        type U = Uint<64, 1>;
+        let fixed = |value: u64, decimals: u8| U::from(value).display_fixed(decimals);
+
+        // Precision equal to, above and below `decimals`.
+        assert_eq!(format!("{}", fixed(12345, 4)), "1.2345");
+        assert_eq!(format!("{:.4}", fixed(12345, 4)), "1.2345");
+        assert_eq!(format!("{:.6}", fixed(12345, 4)), "1.234500");
+        assert_eq!(format!("{:.3}", fixed(12345, 4)), "1.235");
+        assert_eq!(format!("{:.2}", fixed(12345, 4)), "1.23");
+        assert_eq!(format!("{:.0}", fixed(12345, 4)), "1");
+        assert_eq!(format!("{:.0}", fixed(15000, 4)), "2");
+
+        // Rounding carries into the integer part.
+        assert_eq!(format!("{:.2}", fixed(9999, 4)), "1.00");
+        assert_eq!(format!("{:.3}", fixed(99_9999, 4)), "100.000");
+        assert_eq!(format!("{:.0}", fixed(9999, 4)), "1");
+        assert_eq!(format!("{:.2}", fixed(9949, 4)), "0.99");
+
+        // No fractional digits, and values below one.
+        assert_eq!(format!("{}", fixed(42, 0)), "42");
+        assert_eq!(format!("{:.2}", fixed(42, 0)), "42.00");
+        assert_eq!(format!("{}", fixed(7, 3)), "0.007");
+        assert_eq!(format!("{}", fixed(0, 3)), "0.000");
+
+        // Width, fill and alignment.
+        assert_eq!(format!("{:8.2}", fixed(12345, 4)), "    1.23");
+        assert_eq!(format!("{:<8.2}|", fixed(12345, 4)), "1.23    |");
+        assert_eq!(format!("{:^8.2}", fixed(12345, 4)), "  1.23  ");
+        assert_eq!(format!("{:*>8.2}", fixed(12345, 4)), "****1.23");
+        assert_eq!(format!("{:08.2}", fixed(12345, 4)), "00001.23");
+        assert_eq!(format!("{:2.2}", fixed(12345, 4)), "1.23");
+
+        // `decimals` beyond the range of the type.
+        assert_eq!(
+            format!("{}", fixed(u64::MAX, 25)),
+            "0.0000018446744073709551615"
+        );
+        assert_eq!(format!("{:.5}", fixed(u64::MAX, 25)), "0.00000");
+        assert_eq!(format!("{:.6}", fixed(u64::MAX, 25)), "0.000002");
+        assert_eq!(format!("{:.2}", fixed(u64::MAX, 255)), "0.00");
+        assert_eq!(format!("{:.1}", Uint::<0, 0>::ZERO.display_fixed(2)), "0.0");
+        assert_eq!(format!("{:.0}", Uint::<1, 1>::ONE.display_fixed(1)), "0");
+
+        proptest!(|(value: u64, decimals in 0_u32..20, precision in 0_u32..25)| {
+            // Reference in `u128`, where all the scales fit.
+            let value = u128::from(value);
+            let digits = precision.min(decimals);
+            let scale = 10_u128.pow(decimals - digits);
+            let rounded = (value + scale / 2) / scale;
+            let (integer, fraction) = (
+                rounded / 10_u128.pow(digits),
+                rounded % 10_u128.pow(digits),
+            );
+            let mut expected = integer.to_string();
+            if precision > 0 {
+                expected += ".";
+                if digits > 0 {
+                    expected += &format!("{fraction:0width$}", width = digits as usize);
+                }
+                expected += &"0".repeat((precision - digits) as usize);
+            }
+            let (value, decimals) = (value as u64, decimals as u8);
+            let precision = precision as usize;
+            prop_assert_eq!(format!("{:.precision$}", fixed(value, decimals)), expected);
+        });
+    }
+
     #[test]
     fn test_fmt() {
         proptest!(|(value: u128)| {
@@ -195,9 +728,7 @@ mod tests {
             prop_assert_eq!(format!("{n:}"), format!("{value:}"));
             prop_assert_eq!(format!("{n:064}"), format!("{value:064}"));
             prop_assert_eq!(format!("{n:#}"), format!("{value:#}"));
-            prop_assert_eq!(format!("{n:?}"), format!("{value:?}"));
-            prop_assert_eq!(format!("{n:064}"), format!("{value:064?}"));
-            prop_assert_eq!(format!("{n:#?}"), format!("{value:#?}"));
+            prop_assert_eq!(format!("{n:?}"), format!("{value:#x}_U128"));
 
             prop_assert_eq!(format!("{n:x}"), format!("{value:x}"));
             prop_assert_eq!(format!("{n:064x}"), format!("{value:064x}"));
@@ -208,4 +739,129 @@ mod tests {
             prop_assert_eq!(format!("{n:#X}"), format!("{value:#X}"));
         });
     }
+    #[test]
+    fn test_max_dec_len() {
+        macro_rules! check {
+            ($($alias:ident),*) => {$(
+                let max = crate::aliases::$alias::MAX.to_string();
+                assert_eq!(crate::aliases::$alias::max_dec_len(), max.len());
+                assert!(crate::aliases::$alias::MAX.fits_decimal_digits(max.len()));
+                assert!(!crate::aliases::$alias::MAX.fits_decimal_digits(max.len() - 1));
+            )*};
+        }
+        check!(
+            U0, U1, U8, U16, U32, U64, U128, U160, U192, U256, U320, U384, U448, U512, U768, U1024,
+            U2048, U4096
+        );
+
+        // Count the digits of `2^bits` in base 10^9 for every width, which
+        // equal those of `2^bits - 1` for `bits > 0`.
+        let mut chunks = vec![1_u64];
+        for bits in 1..=8192 {
+            let mut carry = 0;
+            for chunk in &mut chunks {
+                let doubled = *chunk * 2 + carry;
+                (*chunk, carry) = (doubled % 1_000_000_000, doubled / 1_000_000_000);
+            }
+            if carry > 0 {
+                chunks.push(carry);
+            }
+            let digits = 9 * (chunks.len() - 1) + chunks.last().unwrap().to_string().len();
+            assert_eq!(max_dec_len(bits), digits, "bits = {bits}");
+        }
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, digits in 0_usize..=U::max_dec_len() + 1)| {
+                let len = value.to_string().len();
+                prop_assert_eq!(value.fits_decimal_digits(digits), len <= digits);
+                prop_assert_eq!(value.dec_str_len(), len);
+            });
+            assert_eq!(U::ZERO.dec_str_len(), 1);
+            assert_eq!(U::MAX.dec_str_len(), U::max_dec_len());
+            for power in core::iter::successors(Some(U::ONE), |p| p.checked_shl(1)).take(BITS) {
+                assert_eq!(power.dec_str_len(), power.to_string().len());
+                let below = power - U::ONE;
+                assert_eq!(below.dec_str_len(), below.to_string().len());
+            }
+        });
+        use crate::aliases::U4096;
+        let mut power = U4096::ONE;
+        for digits in 1..=U4096::max_dec_len() {
+            assert_eq!(power.dec_str_len(), digits);
+            assert_eq!((power - U4096::ONE).dec_str_len(), (digits - 1).max(1));
+            power = power.saturating_mul(U4096::from(10));
+        }
+    }
+
+    #[test]
+    fn test_dec_split() {
+        fn check<const BITS: usize, const LIMBS: usize>(value: Uint<BITS, LIMBS>) {
+            let mut expected = [0_u8; 5000];
+            let expected = value
+                .write_base_digits::<base::Decimal>(&mut expected)
+                .unwrap();
+            let mut buf = [0_u8; 5000];
+            assert_eq!(value.write_dec_digits_split(&mut buf), Ok(expected));
+            assert_eq!(value.to_string(), expected);
+            assert_eq!(expected.parse(), Ok(value));
+            let len = expected.len();
+            assert_eq!(value.write_dec_digits_split(&mut buf[..len]), Ok(expected));
+            assert_eq!(
+                value.write_dec_digits_split(&mut buf[..len - 1]),
+                Err(BufferTooSmall)
+            );
+        }
+        const_for!(BITS in [64, 128, 192, 256, 512, 1024, 1088, 2048, 4096, 16384] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            check(U::ZERO);
+            check(U::ONE);
+            check(U::MAX);
+            // The split points and their neighbours.
+            for digits in [18, 19, 20, 37, 38, 39, 75, 76, 77, 151, 152, 153, 304, 608, 1216] {
+                if let Some(power) = U::pow10(digits) {
+                    check(power - U::ONE);
+                    check(power);
+                    check(power + U::ONE);
+                }
+            }
+            proptest!(|(value: U)| {
+                check(value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(format!("{:?}", Uint::<0, 0>::ZERO), "0x0_U0");
+        assert_eq!(
+            format!("{:#?}", Uint::<0, 0>::ZERO),
+            "0x0_U0 /* 0 (0 bits) */"
+        );
+        assert_eq!(format!("{:?}", Uint::<7, 1>::MAX), "0x7f_U7");
+        assert_eq!(format!("{:?}", U64::from(1234)), "0x4d2_U64");
+        assert_eq!(
+            format!("{:#?}", U256::from(1234)),
+            "0x4d2_U256 /* 1234 (11 bits) */"
+        );
+        assert_eq!(
+            format!("{:?}", U256::MAX),
+            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff_U256"
+        );
+        assert_eq!(
+            format!("{:#?}", Uint::<65, 2>::MAX),
+            "0x1ffffffffffffffff_U65 /* 36893488147419103231 (65 bits) */"
+        );
+        assert_eq!(
+            format!("{:#?}", Some(U64::ZERO)),
+            "Some(\n    0x0_U64 /* 0 (0 bits) */,\n)"
+        );
+
+        // The output is a valid `uint!` literal.
+        assert_eq!(format!("{:?}", uint!(0x4d2_U256)), "0x4d2_U256");
+        assert_eq!(format!("{:?}", uint!(0x7f_U7)), "0x7f_U7");
+        assert_eq!(format!("{:?}", uint!(0x0_U0)), "0x0_U0");
+    }
 }
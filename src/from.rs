@@ -16,7 +16,7 @@
 // See <https://github.com/rust-lang/rust/issues/50133>
 
 // FEATURE: (BLOCKED) It would be nice if we could make TryFrom assignment work
-// for all Uints.
+// for all Uints. The alias widths have concrete `From` impls in `aliases.rs`.
 // impl<
 //         const BITS_SRC: usize,
 //         const LIMBS_SRC: usize,
@@ -31,8 +31,12 @@
 //     }
 // }
 
-use crate::Uint;
-use core::{fmt, fmt::Debug};
+use crate::{Uint, nlimbs};
+use core::{
+    fmt,
+    fmt::Debug,
+    num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize},
+};
 
 /// Error for [`TryFrom<T>`][TryFrom] for [`Uint`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -51,8 +55,7 @@ pub enum ToUintError<T> {
     NotANumber(usize),
 }
 
-#[cfg(feature = "std")]
-impl<T: fmt::Debug> std::error::Error for ToUintError<T> {}
+impl<T: fmt::Debug> core::error::Error for ToUintError<T> {}
 
 impl<T> fmt::Display for ToUintError<T> {
     #[inline]
@@ -72,6 +75,127 @@ impl<T> fmt::Display for ToUintError<T> {
     }
 }
 
+impl<T> ToUintError<T> {
+    /// Returns an adapter that displays the error together with the wrapped
+    /// value.
+    ///
+    /// The input that failed to convert is not kept, use
+    /// [`Uint::try_from_preserving`] to report it instead.
+    ///
+    /// ```
+    /// # use ruint::{Uint, aliases::U64};
+    /// let error = U64::try_from(u128::from(u64::MAX) + 1).unwrap_err();
+    /// assert_eq!(error.to_string(), "Value is too large for Uint<64>");
+    /// assert_eq!(
+    ///     error.display_with_value().to_string(),
+    ///     "Value is too large for Uint<64> (wrapped to 0)"
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn display_with_value(&self) -> DisplayWithValue<'_, Self> {
+        DisplayWithValue(self)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DisplayWithValue<'_, ToUintError<T>> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)?;
+        match self.0 {
+            ToUintError::ValueTooLarge(_, wrapped) | ToUintError::ValueNegative(_, wrapped) => {
+                write!(f, " (wrapped to {wrapped})")
+            }
+            ToUintError::NotANumber(_) => Ok(()),
+        }
+    }
+}
+
+/// Adapter that displays an error together with the values it carries.
+///
+/// Returned by [`ToUintError::display_with_value`],
+/// [`ToUintErrorWithInput::display_with_value`] and
+/// [`FromUintError::display_with_value`].
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayWithValue<'a, E>(&'a E);
+
+/// Error for [`Uint::try_from_preserving`].
+///
+/// Like [`ToUintError`], but also keeps the original input so it can be
+/// reported instead of the wrapped value.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ToUintErrorWithInput<T, U> {
+    /// The input that failed to convert.
+    pub input: T,
+
+    /// The conversion error, including the wrapped value.
+    pub error: ToUintError<U>,
+}
+
+impl<T: fmt::Debug + fmt::Display, U: fmt::Debug> core::error::Error
+    for ToUintErrorWithInput<T, U>
+{
+}
+
+impl<T: fmt::Display, U> fmt::Display for ToUintErrorWithInput<T, U> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input = &self.input;
+        match self.error {
+            ToUintError::ValueTooLarge(bits, _) => {
+                write!(f, "Value {input} is too large for Uint<{bits}>")
+            }
+            ToUintError::ValueNegative(bits, _) => {
+                write!(
+                    f,
+                    "Negative value {input} cannot be represented as Uint<{bits}>"
+                )
+            }
+            ToUintError::NotANumber(bits) => {
+                write!(
+                    f,
+                    "'Not a number' (NaN) cannot be represented as Uint<{bits}>"
+                )
+            }
+        }
+    }
+}
+
+impl<T, U> ToUintErrorWithInput<T, U> {
+    /// Returns an adapter that displays the error together with the input and
+    /// the wrapped value.
+    ///
+    /// ```
+    /// # use ruint::{Uint, aliases::U8};
+    /// let error = U8::try_from_preserving(300_u16).unwrap_err();
+    /// assert_eq!(
+    ///     error.display_with_value().to_string(),
+    ///     "Value 300 is too large for Uint<8> (wrapped to 44)"
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn display_with_value(&self) -> DisplayWithValue<'_, Self> {
+        DisplayWithValue(self)
+    }
+}
+
+impl<T: fmt::Display, U: fmt::Display> fmt::Display
+    for DisplayWithValue<'_, ToUintErrorWithInput<T, U>>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)?;
+        match &self.0.error {
+            ToUintError::ValueTooLarge(_, wrapped) | ToUintError::ValueNegative(_, wrapped) => {
+                write!(f, " (wrapped to {wrapped})")
+            }
+            ToUintError::NotANumber(_) => Ok(()),
+        }
+    }
+}
+
 /// Error for [`TryFrom<Uint>`][TryFrom].
 #[allow(clippy::derive_partial_eq_without_eq)] // False positive
 #[allow(clippy::module_name_repetitions)]
@@ -84,8 +208,7 @@ pub enum FromUintError<T> {
     Overflow(usize, T, T),
 }
 
-#[cfg(feature = "std")]
-impl<T: fmt::Debug> std::error::Error for FromUintError<T> {}
+impl<T: fmt::Debug> core::error::Error for FromUintError<T> {}
 
 impl<T> fmt::Display for FromUintError<T> {
     #[inline]
@@ -100,6 +223,115 @@ impl<T> fmt::Display for FromUintError<T> {
     }
 }
 
+impl<T> FromUintError<T> {
+    /// Returns an adapter that displays the error together with the wrapped
+    /// and saturated values.
+    ///
+    /// ```
+    /// # use ruint::{Uint, aliases::U64};
+    /// let error = U64::from(300).try_to::<u8>().unwrap_err();
+    /// assert_eq!(
+    ///     error.display_with_value().to_string(),
+    ///     "Uint<64> value is too large for u8 (wrapped to 44, saturated to 255)"
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn display_with_value(&self) -> DisplayWithValue<'_, Self> {
+        DisplayWithValue(self)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DisplayWithValue<'_, FromUintError<T>> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)?;
+        match self.0 {
+            FromUintError::Overflow(_, wrapped, saturated) => {
+                write!(f, " (wrapped to {wrapped}, saturated to {saturated})")
+            }
+        }
+    }
+}
+
+/// Error for [`TryFrom<Uint>`][TryFrom] for the non-zero integer types like
+/// [`NonZeroU64`].
+///
+/// These types are only supported through [`TryFrom`] and not through
+/// [`Uint::to`] and friends. Those go through [`UintTryTo`], whose
+/// [`FromUintError`] carries the wrapped value that [`Uint::wrapping_to`]
+/// returns, and zero wraps to no non-zero value.
+///
+/// ```
+/// # use ruint::{FromUintNonZeroError, aliases::U256};
+/// # use core::num::NonZeroU64;
+/// assert_eq!(
+///     NonZeroU64::try_from(U256::from(7)),
+///     Ok(NonZeroU64::new(7).unwrap())
+/// );
+/// assert_eq!(
+///     NonZeroU64::try_from(U256::ZERO),
+///     Err(FromUintNonZeroError::Zero)
+/// );
+/// ```
+///
+/// ```compile_fail
+/// # use ruint::aliases::U256;
+/// # use core::num::NonZeroU64;
+/// let _ = U256::from(7).to::<NonZeroU64>();
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromUintNonZeroError {
+    /// The Uint value is zero.
+    Zero,
+
+    /// The Uint value is too large for the target type.
+    ///
+    /// `.0` is the number of `BITS` in the Uint.
+    Overflow(usize),
+}
+
+impl core::error::Error for FromUintNonZeroError {}
+
+impl fmt::Display for FromUintNonZeroError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Zero => f.write_str("Uint value is zero"),
+            Self::Overflow(bits) => {
+                write!(f, "Uint<{bits}> value is too large for the non-zero type")
+            }
+        }
+    }
+}
+
+/// Error for [`TryFrom<Uint>`][TryFrom] for [`char`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromUintCharError {
+    /// The Uint value is larger than [`char::MAX`].
+    ///
+    /// `.0` is the number of `BITS` in the Uint.
+    Overflow(usize),
+
+    /// The Uint value `.0` is a surrogate code point, which is not a valid
+    /// [`char`].
+    Surrogate(u32),
+}
+
+impl core::error::Error for FromUintCharError {}
+
+impl fmt::Display for FromUintCharError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow(bits) => write!(f, "Uint<{bits}> value is too large for char"),
+            Self::Surrogate(value) => write!(f, "{value:#x} is a surrogate code point"),
+        }
+    }
+}
+
 /// Error for [`TryFrom<Uint>`][TryFrom] for [`ark_ff`](https://docs.rs/ark-ff) and others.
 #[allow(dead_code)] // This is used by some support features.
 #[derive(Debug, Clone, Copy)]
@@ -108,8 +340,7 @@ pub enum ToFieldError {
     NotInField,
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ToFieldError {}
+impl core::error::Error for ToFieldError {}
 
 impl fmt::Display for ToFieldError {
     #[inline]
@@ -224,6 +455,61 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Construct a new [`Uint`] from the value, returning [`None`] if the
+    /// conversion fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(U8::checked_from(142_u16), Some(142_U8));
+    /// assert_eq!(U8::checked_from(300_u16), None);
+    /// assert_eq!(U8::checked_from(-1_i16), None);
+    /// assert_eq!(U32::checked_from(0x7014b4c2d1f2_U256), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_from<T>(value: T) -> Option<Self>
+    where
+        Self: UintTryFrom<T>,
+    {
+        Self::uint_try_from(value).ok()
+    }
+
+    /// Construct a new [`Uint`] from the value, keeping a copy of the input
+    /// in the error if the conversion fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ToUintError`] together with the original input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(U64::try_from_preserving(42.0_f64), Ok(42_U64));
+    /// let error = U64::try_from_preserving(1.8e25_f64).unwrap_err();
+    /// assert_eq!(error.input, 1.8e25);
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Value 18000000000000000000000000 is too large for Uint<64>"
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_from_preserving<T: Clone>(value: T) -> Result<Self, ToUintErrorWithInput<T, Self>>
+    where
+        Self: UintTryFrom<T>,
+    {
+        Self::uint_try_from(value.clone()).map_err(|error| ToUintErrorWithInput {
+            input: value,
+            error,
+        })
+    }
+
     /// # Panics
     ///
     /// Panics if the conversion fails, for example if the value is too large
@@ -249,6 +535,56 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         self.uint_try_to().expect("Uint conversion error")
     }
 
+    /// Converts to the target type, returning [`None`] if the value does not
+    /// fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(300_U12.checked_to::<i16>(), Some(300_i16));
+    /// assert_eq!(300_U12.checked_to::<u8>(), None);
+    /// assert_eq!(0x1337cafec0d3_U256.checked_to::<U32>(), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_to<T>(&self) -> Option<T>
+    where
+        Self: UintTryTo<T>,
+    {
+        self.uint_try_to().ok()
+    }
+
+    /// Converts to the target type, returning the error if the value does not
+    /// fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromUintError::Overflow`] if the value is too large for the
+    /// target type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*, FromUintError};
+    /// # uint!{
+    /// assert_eq!(300_U12.try_to::<u16>(), Ok(300_u16));
+    /// assert_eq!(
+    ///     300_U12.try_to::<u8>(),
+    ///     Err(FromUintError::Overflow(12, 44, 255))
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_to<T>(&self) -> Result<T, FromUintError<T>>
+    where
+        Self: UintTryTo<T>,
+    {
+        self.uint_try_to()
+    }
+
     /// # Examples
     ///
     /// ```
@@ -291,6 +627,123 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Returns the `N` least significant bits.
+    ///
+    /// Unlike [`Self::wrapping_to`], the direction of the truncation is
+    /// explicit in the name and a target wider than `Self` is a compile time
+    /// error. See [`Self::zero_extend`] to widen instead.
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `N > BITS` or if `L` is not the number of limbs
+    /// for `N` bits.
+    ///
+    /// # Examples
+    ///
+    /// An Ethereum address is the last 20 bytes of the big-endian Keccak-256
+    /// hash of the public key, `keccak(pubkey)[12..]`. Those are the low 160
+    /// bits of the hash:
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let hash = 0x37ee7f1bb2a9c1e2fa9f4e1eb5d6a3b84fcc00b53d5e4fa3c1c68fda0df0b78c_U256;
+    /// let address = hash.truncate_low::<160, 3>();
+    /// assert_eq!(address, 0xb5d6a3b84fcc00b53d5e4fa3c1c68fda0df0b78c_U160);
+    /// assert_eq!(address.to_be_bytes::<20>(), hash.to_be_bytes::<32>()[12..]);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn truncate_low<const N: usize, const L: usize>(&self) -> Uint<N, L> {
+        const { Self::assert_resize(N, L, N <= BITS) }
+        let mut limbs = [0; L];
+        let mut i = 0;
+        while i < L {
+            limbs[i] = self.limbs[i];
+            i += 1;
+        }
+        Uint::from_limbs_unmasked(limbs)
+    }
+
+    /// Returns the `N` most significant bits, i.e. `self >> (BITS - N)`.
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `N > BITS` or if `L` is not the number of limbs
+    /// for `N` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let hash = 0x37ee7f1bb2a9c1e2fa9f4e1eb5d6a3b84fcc00b53d5e4fa3c1c68fda0df0b78c_U256;
+    /// assert_eq!(
+    ///     hash.truncate_high::<160, 3>(),
+    ///     0x37ee7f1bb2a9c1e2fa9f4e1eb5d6a3b84fcc00b5_U160
+    /// );
+    /// assert_eq!(
+    ///     hash.truncate_high::<160, 3>(),
+    ///     (hash >> 96_usize).truncate_low::<160, 3>()
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn truncate_high<const N: usize, const L: usize>(&self) -> Uint<N, L> {
+        const { Self::assert_resize(N, L, N <= BITS) }
+        let shift = BITS - N;
+        let (offset, bits) = (shift / 64, shift % 64);
+        let mut limbs = [0; L];
+        let mut i = 0;
+        while i < L {
+            limbs[i] = self.limbs[offset + i] >> bits;
+            if bits != 0 && offset + i + 1 < LIMBS {
+                limbs[i] |= self.limbs[offset + i + 1] << (64 - bits);
+            }
+            i += 1;
+        }
+        Uint::from_limbs_unmasked(limbs)
+    }
+
+    /// Widens to `N` bits, filling the new most significant bits with zeros.
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `N < BITS` or if `L` is not the number of limbs
+    /// for `N` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(U160::MAX.zero_extend::<256, 4>(), U256::MAX >> 96);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn zero_extend<const N: usize, const L: usize>(&self) -> Uint<N, L> {
+        const { Self::assert_resize(N, L, N >= BITS) }
+        let mut limbs = [0; L];
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = self.limbs[i];
+            i += 1;
+        }
+        Uint::from_limbs(limbs)
+    }
+
+    #[track_caller]
+    const fn assert_resize(bits: usize, limbs: usize, direction: bool) {
+        assert!(
+            direction,
+            "N is on the wrong side of BITS for this conversion"
+        );
+        assert!(limbs == nlimbs(bits), "L must be equal to nlimbs(N)");
+    }
+
     /// Construct a new [`Uint`] from a potentially different sized [`Uint`].
     ///
     /// # Panics
@@ -349,6 +802,120 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     }
 }
 
+/// # Primitive shorthands
+///
+/// Non-generic spellings of the conversions to primitive types, so they show
+/// up in autocompletion and need no turbofish. They share the semantics of the
+/// generic API:
+///
+/// | Shorthand            | Generic equivalent              |
+/// | -------------------- | ------------------------------- |
+/// | `x.to_u64_checked()` | `x.checked_to::<u64>()`         |
+/// | `x.expect_u64(msg)`  | `x.try_to::<u64>().expect(msg)` |
+/// | `x.to_f64()`         | `f64::from(x)`                  |
+///
+/// and likewise for the other integer types.
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Converts to [`u64`], panicking with `msg` if the value does not fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the value is larger than [`u64::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// let gas = U256::from(21_000);
+    /// assert_eq!(gas.expect_u64("gas fits u64"), 21_000);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn expect_u64(&self, msg: &str) -> u64 {
+        self.try_to::<u64>().expect(msg)
+    }
+
+    /// Converts to [`usize`], saturating at [`usize::MAX`].
+    ///
+    /// Use this for lengths and capacities where anything too large for
+    /// the platform is equally out of range. Unlike `to::<usize>()`, it never
+    /// panics, so values that fit on 64-bit targets but not on 32-bit ones
+    /// don't become a portability hazard. See [`Self::to_usize_checked`] to
+    /// detect the overflow instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// assert_eq!(U256::from(1024).to_usize_capped(), 1024);
+    /// assert_eq!(U256::MAX.to_usize_capped(), usize::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_usize_capped(&self) -> usize {
+        self.saturating_to()
+    }
+
+    /// Returns the element of `slice` at index `self`, or [`None`] if the
+    /// index is out of bounds.
+    ///
+    /// This combines the conversion to [`usize`] with the bounds check, so
+    /// indices too large for the platform are out of bounds rather than
+    /// truncated or panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// let slice = [10, 20, 30];
+    /// assert_eq!(U256::from(1).index_into(&slice), Some(&20));
+    /// assert_eq!(U256::from(3).index_into(&slice), None);
+    /// assert_eq!(U256::MAX.index_into(&slice), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn index_into<'a, T>(&self, slice: &'a [T]) -> Option<&'a T> {
+        slice.get(self.to_usize_checked()?)
+    }
+
+    /// Approximates the value as the nearest [`f64`], rounding ties to even.
+    /// Returns [`f64::INFINITY`] if the value is too large to represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// assert_eq!(U256::from(3).to_f64(), 3.0);
+    /// assert_eq!(U256::MAX.to_f64(), 2.0_f64.powi(256));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_f64(&self) -> f64 {
+        f64::from(self)
+    }
+}
+
+macro_rules! to_checked {
+    ($($name:ident: $int:ty),*) => {
+        impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {$(
+            #[doc = concat!("Converts to [`", stringify!($int), "`], returning [`None`] if the")]
+            #[doc = concat!("value does not fit. See [`Self::checked_to`].")]
+            #[inline]
+            #[must_use]
+            pub fn $name(&self) -> Option<$int> {
+                self.checked_to()
+            }
+        )*}
+    };
+}
+
+to_checked!(
+    to_u8_checked: u8, to_u16_checked: u16, to_u32_checked: u32, to_u64_checked: u64,
+    to_u128_checked: u128, to_usize_checked: usize, to_i8_checked: i8, to_i16_checked: i16,
+    to_i32_checked: i32, to_i64_checked: i64, to_i128_checked: i128, to_isize_checked: isize
+);
+
 /// ⚠️ Workaround for [Rust issue #50133](https://github.com/rust-lang/rust/issues/50133).
 /// Use [`TryFrom`] instead.
 ///
@@ -378,6 +945,17 @@ impl<const BITS: usize, const LIMBS: usize, const BITS_SRC: usize, const LIMBS_S
 {
     #[inline]
     fn uint_try_from(value: Uint<BITS_SRC, LIMBS_SRC>) -> Result<Self, ToUintError<Self>> {
+        Self::uint_try_from(&value)
+    }
+}
+
+// The source is only read through its limbs, so conversions from references
+// avoid copying large values.
+impl<const BITS: usize, const LIMBS: usize, const BITS_SRC: usize, const LIMBS_SRC: usize>
+    UintTryFrom<&Uint<BITS_SRC, LIMBS_SRC>> for Uint<BITS, LIMBS>
+{
+    #[inline]
+    fn uint_try_from(value: &Uint<BITS_SRC, LIMBS_SRC>) -> Result<Self, ToUintError<Self>> {
         let (n, overflow) = Self::overflowing_from_limbs_slice(value.as_limbs());
         if overflow {
             Err(ToUintError::ValueTooLarge(BITS, n))
@@ -387,6 +965,15 @@ impl<const BITS: usize, const LIMBS: usize, const BITS_SRC: usize, const LIMBS_S
     }
 }
 
+impl<const BITS: usize, const LIMBS: usize, const BITS_SRC: usize, const LIMBS_SRC: usize>
+    UintTryFrom<&mut Uint<BITS_SRC, LIMBS_SRC>> for Uint<BITS, LIMBS>
+{
+    #[inline]
+    fn uint_try_from(value: &mut Uint<BITS_SRC, LIMBS_SRC>) -> Result<Self, ToUintError<Self>> {
+        Self::uint_try_from(&*value)
+    }
+}
+
 /// ⚠️ Workaround for [Rust issue #50133](https://github.com/rust-lang/rust/issues/50133).
 /// Use [`TryFrom`] instead.
 pub trait UintTryTo<T>: Sized {
@@ -413,7 +1000,7 @@ impl<const BITS: usize, const LIMBS: usize, const BITS_DST: usize, const LIMBS_D
     ) -> Result<Uint<BITS_DST, LIMBS_DST>, FromUintError<Uint<BITS_DST, LIMBS_DST>>> {
         let (n, overflow) = Uint::overflowing_from_limbs_slice(self.as_limbs());
         if overflow {
-            Err(FromUintError::Overflow(BITS_DST, n, Uint::MAX))
+            Err(FromUintError::Overflow(BITS, n, Uint::MAX))
         } else {
             Ok(n)
         }
@@ -490,7 +1077,7 @@ impl_from_unsigned_int!(u32);
 impl_from_unsigned_int!(usize);
 
 // Signed int version check for positive and delegate to the corresponding
-// `uint`.
+// `uint`. Negative values are wrapped as two's complement at the full width.
 macro_rules! impl_from_signed_int {
     ($int:ty, $uint:ty) => {
         impl<const BITS: usize, const LIMBS: usize> TryFrom<$int> for Uint<BITS, LIMBS> {
@@ -499,12 +1086,9 @@ macro_rules! impl_from_signed_int {
             #[inline]
             fn try_from(value: $int) -> Result<Self, Self::Error> {
                 if value.is_negative() {
-                    Err(match Self::try_from(value as $uint) {
-                        Ok(n) | Err(ToUintError::ValueTooLarge(_, n)) => {
-                            ToUintError::ValueNegative(BITS, n)
-                        }
-                        _ => unreachable!(),
-                    })
+                    // Sign-extend to the full width, i.e. wrap modulo 2^BITS.
+                    let n = Self::wrapping_from(value.unsigned_abs()).wrapping_neg();
+                    Err(ToUintError::ValueNegative(BITS, n))
                 } else {
                     Self::try_from(value as $uint)
                 }
@@ -520,11 +1104,80 @@ impl_from_signed_int!(i64, u64);
 impl_from_signed_int!(i128, u128);
 impl_from_signed_int!(isize, usize);
 
+// Non-zero integers delegate to the primitive, which can not fail on zero.
+macro_rules! impl_from_non_zero {
+    ($($non_zero:ty)*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> TryFrom<$non_zero> for Uint<BITS, LIMBS> {
+            type Error = ToUintError<Self>;
+
+            #[inline]
+            fn try_from(value: $non_zero) -> Result<Self, Self::Error> {
+                Self::try_from(value.get())
+            }
+        }
+    )*};
+}
+
+impl_from_non_zero!(NonZeroU8 NonZeroU16 NonZeroU32 NonZeroU64 NonZeroU128 NonZeroUsize);
+
 impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
     type Error = ToUintError<Self>;
 
     #[inline]
     fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_from_f64_inexact(value).map(|(n, _)| n)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Converts the [`f64`] rounded to the nearest integer and returns
+    /// whether rounding changed the value.
+    ///
+    /// Returns the saturated value, as in [`Self::saturating_from`], and
+    /// `true` if `value` is negative, not a number or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U64;
+    /// assert_eq!(U64::from_f64_lossy(123.0), (U64::from(123), false));
+    /// assert_eq!(U64::from_f64_lossy(123.456), (U64::from(123), true));
+    /// assert_eq!(U64::from_f64_lossy(-1.0), (U64::ZERO, true));
+    /// assert_eq!(U64::from_f64_lossy(f64::INFINITY), (U64::MAX, true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f64_lossy(value: f64) -> (Self, bool) {
+        match Self::try_from_f64_inexact(value) {
+            Ok(result) => result,
+            Err(ToUintError::ValueTooLarge(..)) => (Self::MAX, true),
+            Err(ToUintError::ValueNegative(..) | ToUintError::NotANumber(_)) => (Self::ZERO, true),
+        }
+    }
+
+    /// Converts the [`f64`] if it is an integer that fits, or returns
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U64;
+    /// assert_eq!(U64::from_f64_exact(123.0), Some(U64::from(123)));
+    /// assert_eq!(U64::from_f64_exact(123.456), None);
+    /// assert_eq!(U64::from_f64_exact(2.0_f64.powi(64)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f64_exact(value: f64) -> Option<Self> {
+        match Self::try_from_f64_inexact(value) {
+            Ok((n, false)) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Converts the [`f64`] rounded to the nearest integer, together with
+    /// whether rounding changed the value.
+    fn try_from_f64_inexact(value: f64) -> Result<(Self, bool), ToUintError<Self>> {
         // Mimics Rust's own float-to-int conversion
         // https://github.com/rust-lang/compiler-builtins/blob/f4c7940d3b13ec879c9fdc218812f71a65149123/src/float/conv.rs#L163
 
@@ -542,12 +1195,12 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
 
         let sign = if (a_rep & sign_bit) == 0 {
             if a_rep < HALF_BITS {
-                return Ok(Self::ZERO);
+                return Ok((Self::ZERO, a_rep != 0));
             }
             Sign::Positive
         } else {
             if a_abs == 0 {
-                return Ok(Self::ZERO);
+                return Ok((Self::ZERO, false));
             }
             Sign::Negative
         };
@@ -562,7 +1215,8 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
 
         // Helper: produce integer magnitude for |f| given an unbiased exponent `e`,
         // using round-to-nearest, ties-to-even, then interpreted modulo 2^BITS by Uint.
-        let compute_mag = |e: usize| -> Self {
+        // Also returns whether rounding discarded a non-zero remainder.
+        let compute_mag = |e: usize| -> (Self, bool) {
             if e < significand_bits {
                 // Right shift with round-to-nearest, ties-to-even
                 let shift = significand_bits - e; // shift >= 1 here
@@ -572,10 +1226,13 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
                 if remainder > halfway || (remainder == halfway && (r & 1) == 1) {
                     r = r.wrapping_add(1);
                 }
-                from_lossy(r)
+                (from_lossy(r), remainder != 0)
             } else {
                 // Left shift; Uint shifts are modulo 2^BITS already.
-                from_lossy(significand).wrapping_shl(e - significand_bits)
+                (
+                    from_lossy(significand).wrapping_shl(e - significand_bits),
+                    false,
+                )
             }
         };
 
@@ -595,7 +1252,7 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
             } else {
                 // |value| >= 1: compute magnitude normally with unbiased exponent.
                 let e = exponent - exponent_bias;
-                let mag = compute_mag(e);
+                let (mag, _) = compute_mag(e);
                 let wrapped = Self::ZERO.wrapping_sub(mag);
                 return Err(ToUintError::ValueNegative(BITS, wrapped));
             }
@@ -607,7 +1264,7 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
                 Err(ToUintError::ValueTooLarge(BITS, Self::ZERO))
             } else {
                 // We already handled value < 0.5 above; here 0.5 <= value < 1.0 → 1.
-                Ok(Self::ONE)
+                Ok((Self::ONE, true))
             };
         }
         exponent -= exponent_bias;
@@ -620,7 +1277,7 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
                 return Err(ToUintError::NotANumber(BITS));
             }
 
-            let mag = compute_mag(exponent);
+            let (mag, _) = compute_mag(exponent);
             let wrapped = match sign {
                 Sign::Positive => mag,
                 Sign::Negative => Self::ZERO.wrapping_sub(mag),
@@ -632,7 +1289,7 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
         }
 
         // In-range: produce the integer normally.
-        let r = compute_mag(exponent);
+        let (r, inexact) = compute_mag(exponent);
 
         // Match old impl: if rounding bumps us across 2^BITS (only possible when
         // exponent == BITS - 1), report ValueTooLarge with the wrapped payload.
@@ -644,7 +1301,7 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
             return Err(ToUintError::ValueTooLarge(BITS, r));
         }
 
-        Ok(r)
+        Ok((r, inexact))
     }
 }
 
@@ -766,6 +1423,61 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<&Uint<BITS, LIMBS>> for u128
     }
 }
 
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Uint<BITS, LIMBS>> for char {
+    type Error = FromUintCharError;
+
+    #[inline]
+    fn try_from(value: Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&Uint<BITS, LIMBS>> for char {
+    type Error = FromUintCharError;
+
+    #[inline]
+    fn try_from(value: &Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+        let value = u32::try_from(value)
+            .ok()
+            .filter(|&value| value <= Self::MAX as u32)
+            .ok_or(FromUintCharError::Overflow(BITS))?;
+        Self::from_u32(value).ok_or(FromUintCharError::Surrogate(value))
+    }
+}
+
+macro_rules! to_non_zero {
+    ($($non_zero:ident($int:ty))*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> TryFrom<Uint<BITS, LIMBS>> for $non_zero {
+            type Error = FromUintNonZeroError;
+
+            #[inline]
+            fn try_from(value: Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+                Self::try_from(&value)
+            }
+        }
+
+        impl<const BITS: usize, const LIMBS: usize> TryFrom<&Uint<BITS, LIMBS>> for $non_zero {
+            type Error = FromUintNonZeroError;
+
+            #[inline]
+            fn try_from(value: &Uint<BITS, LIMBS>) -> Result<Self, Self::Error> {
+                let value = <$int>::try_from(value)
+                    .map_err(|_| FromUintNonZeroError::Overflow(BITS))?;
+                Self::new(value).ok_or(FromUintNonZeroError::Zero)
+            }
+        }
+    )*};
+}
+
+to_non_zero! {
+    NonZeroU8(u8)
+    NonZeroU16(u16)
+    NonZeroU32(u32)
+    NonZeroU64(u64)
+    NonZeroU128(u128)
+    NonZeroUsize(usize)
+}
+
 // Convert Uint to floating point
 
 impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for f32 {
@@ -799,29 +1511,45 @@ impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for f64 {
     /// Returns `f64::INFINITY` if the value is too large to represent.
     #[inline]
     fn from(value: &Uint<BITS, LIMBS>) -> Self {
-        Self::from_bits(value.as_f64_bits())
+        Self::from_bits(value.to_f64_bits())
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
-    /// Convert to IEEE 754 double precision float bit representation.
+    /// Returns the bit representation of the [`f64`] nearest to the value,
+    /// rounding ties to even.
+    ///
+    /// Values too large to represent give the bits of [`f64::INFINITY`]. This
+    /// is the same as `f64::from(self).to_bits()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, aliases::*};
+    /// assert_eq!(U64::from(3).to_f64_bits(), 3.0_f64.to_bits());
+    /// assert_eq!(U256::MAX.to_f64_bits(), 2.0_f64.powi(256).to_bits());
+    /// assert_eq!(Uint::<1025, 17>::MAX.to_f64_bits(), f64::INFINITY.to_bits());
+    /// ```
     #[inline]
-    fn as_f64_bits(&self) -> u64 {
+    #[must_use]
+    pub fn to_f64_bits(&self) -> u64 {
         as_primitives!(self, {
             u64(x) => return f64::to_bits(x as f64),
         });
 
         const SIG: usize = f64::MANTISSA_DIGITS as usize; // includes the hidden bit
+        const INFINITY_BITS: u64 = 0x7ff0_0000_0000_0000;
 
         let sd = self.bit_len(); // 0 for zero
         if sd == 0 {
             return 0;
         }
 
-        // Early +∞ if exponent field is already saturated before rounding.
-        // e_pre = 1021 + sd; saturation when e_pre >= 0x7ff <=> sd >= 1026
-        if sd >= 1026 {
-            return 0x7ff0_0000_0000_0000;
+        // Early +∞ for values of at least 2^1024, which exceed `f64::MAX` before
+        // rounding. The hidden bit adds one to the exponent field, so these would
+        // otherwise produce an all-ones exponent with a nonzero mantissa (NaN).
+        if sd > f64::MAX_EXP as usize {
+            return INFINITY_BITS;
         }
 
         let e_pre = 1021u64 + sd as u64;
@@ -863,8 +1591,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         let round_up = guard && (sticky || ((a & 1) != 0));
         let m = a + (round_up as u64);
 
-        // Combine with '+' so a carry out of m bumps the exponent.
-        (e_pre << 52) + m
+        // Combine with '+' so a carry out of m bumps the exponent. A carry into
+        // the all-ones exponent leaves a zero mantissa, but saturate regardless.
+        ((e_pre << 52) + m).min(INFINITY_BITS)
     }
 }
 
@@ -927,6 +1656,343 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_try_from_preserving() {
+        use crate::aliases::{U8, U64};
+
+        assert_eq!(U8::try_from_preserving(200_u64), Ok(U8::from(200)));
+        assert_eq!(
+            U8::try_from_preserving(300_u64),
+            Err(ToUintErrorWithInput {
+                input: 300,
+                error: ToUintError::ValueTooLarge(8, U8::from(44)),
+            })
+        );
+        assert_eq!(
+            U8::try_from_preserving(-3_i32).unwrap_err().to_string(),
+            "Negative value -3 cannot be represented as Uint<8>"
+        );
+        assert_eq!(
+            U64::try_from_preserving(f64::NAN).unwrap_err().to_string(),
+            "'Not a number' (NaN) cannot be represented as Uint<64>"
+        );
+        assert_eq!(
+            U8::try_from_preserving(U64::MAX).unwrap_err().to_string(),
+            "Value 18446744073709551615 is too large for Uint<8>"
+        );
+    }
+
+    #[test]
+    fn test_display_with_value() {
+        use crate::aliases::{U8, U64, U256};
+
+        let error = U64::try_from(u128::from(u64::MAX) + 1).unwrap_err();
+        assert_eq!(
+            error.display_with_value().to_string(),
+            "Value is too large for Uint<64> (wrapped to 0)"
+        );
+        let error = U8::try_from(-3_i32).unwrap_err();
+        assert_eq!(
+            error.display_with_value().to_string(),
+            "Negative values cannot be represented as Uint<8> (wrapped to 253)"
+        );
+        let error = U64::try_from(f64::NAN).unwrap_err();
+        assert_eq!(
+            error.display_with_value().to_string(),
+            "'Not a number' (NaN) cannot be represented as Uint<64>"
+        );
+
+        let error = U256::MAX.try_to::<u16>().unwrap_err();
+        assert_eq!(
+            error.display_with_value().to_string(),
+            "Uint<256> value is too large for u16 (wrapped to 65535, saturated to 65535)"
+        );
+        let error = U256::from(0x1_0001).try_to::<U8>().unwrap_err();
+        assert_eq!(
+            error.display_with_value().to_string(),
+            "Uint<256> value is too large for ruint::Uint<8, 1> (wrapped to 1, saturated to 255)"
+        );
+
+        let error = U8::try_from_preserving(300_u16).unwrap_err();
+        assert_eq!(
+            error.display_with_value().to_string(),
+            "Value 300 is too large for Uint<8> (wrapped to 44)"
+        );
+        let error = U8::try_from_preserving(-3_i32).unwrap_err();
+        assert_eq!(
+            error.display_with_value().to_string(),
+            "Negative value -3 cannot be represented as Uint<8> (wrapped to 253)"
+        );
+        let error = U64::try_from_preserving(f64::NAN).unwrap_err();
+        assert_eq!(
+            error.display_with_value().to_string(),
+            "'Not a number' (NaN) cannot be represented as Uint<64>"
+        );
+    }
+
+    #[test]
+    fn test_wrapping_from_signed() {
+        use crate::aliases::{U8, U128, U256};
+
+        assert_eq!(U8::wrapping_from(-10_i16), U8::from(246));
+        assert_eq!(U256::wrapping_from(-1_i32), U256::MAX);
+        assert_eq!(U256::wrapping_from(-10_i8), U256::MAX - U256::from(9));
+        assert_eq!(
+            U256::try_from(-10_i8),
+            Err(ToUintError::ValueNegative(256, U256::MAX - U256::from(9)))
+        );
+        assert_eq!(
+            U128::wrapping_from(i128::MIN),
+            U128::from(i128::MIN as u128)
+        );
+        assert_eq!(U256::wrapping_from(i128::MIN), U256::MAX << 127_usize);
+        assert_eq!(U8::wrapping_from(i128::MIN), U8::ZERO);
+        assert_eq!(Uint::<0, 0>::wrapping_from(i64::MIN), Uint::ZERO);
+        assert_eq!(U8::saturating_from(i128::MIN), U8::ZERO);
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value in (i64::MIN + 1)..)| {
+                let expected = U::wrapping_from(value.unsigned_abs());
+                let expected = if value < 0 { expected.wrapping_neg() } else { expected };
+                assert_eq!(U::wrapping_from(value), expected);
+                let sum = U::wrapping_from(value).wrapping_add(U::wrapping_from(-value));
+                assert_eq!(sum, U::ZERO);
+            });
+        });
+    }
+
+    #[test]
+    fn test_non_zero() {
+        use crate::aliases::{U8, U256};
+
+        macro_rules! test_non_zero {
+            ($($non_zero:ident($int:ty))*) => {$(
+                let max = U256::from(<$int>::MAX);
+                assert_eq!($non_zero::try_from(U256::ZERO), Err(FromUintNonZeroError::Zero));
+                assert_eq!($non_zero::try_from(U256::ONE), Ok($non_zero::MIN));
+                assert_eq!($non_zero::try_from(&max), Ok($non_zero::MAX));
+                assert_eq!(
+                    $non_zero::try_from(max + U256::ONE),
+                    Err(FromUintNonZeroError::Overflow(256))
+                );
+                assert_eq!(U256::try_from($non_zero::MAX), Ok(max));
+                assert_eq!(U256::from($non_zero::MIN), U256::ONE);
+            )*};
+        }
+        test_non_zero! {
+            NonZeroU8(u8)
+            NonZeroU16(u16)
+            NonZeroU32(u32)
+            NonZeroU64(u64)
+            NonZeroU128(u128)
+            NonZeroUsize(usize)
+        }
+
+        assert_eq!(
+            NonZeroU8::try_from(Uint::<0, 0>::ZERO),
+            Err(FromUintNonZeroError::Zero)
+        );
+        assert_eq!(
+            U8::try_from(NonZeroU16::new(256).unwrap()),
+            Err(ToUintError::ValueTooLarge(8, U8::ZERO))
+        );
+        assert_eq!(U8::checked_from(NonZeroU64::MIN), Some(U8::ONE));
+        assert_eq!(
+            FromUintNonZeroError::Overflow(256).to_string(),
+            "Uint<256> value is too large for the non-zero type"
+        );
+    }
+
+    #[test]
+    fn test_shorthands() {
+        macro_rules! check {
+            ($value:expr, $($name:ident: $int:ty),*) => {$(
+                assert_eq!($value.$name(), $value.checked_to::<$int>());
+            )*};
+        }
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let mut boundaries = vec![U::ZERO, U::MAX];
+            for bits in [7, 8, 15, 16, 31, 32, 63, 64, 127, 128] {
+                let bit = U::ONE.checked_shl(bits);
+                boundaries.extend(bit.into_iter().flat_map(|bit| [bit - U::ONE, bit]));
+            }
+            for value in boundaries {
+                check!(value,
+                    to_u8_checked: u8, to_u16_checked: u16, to_u32_checked: u32,
+                    to_u64_checked: u64, to_u128_checked: u128, to_usize_checked: usize,
+                    to_i8_checked: i8, to_i16_checked: i16, to_i32_checked: i32,
+                    to_i64_checked: i64, to_i128_checked: i128, to_isize_checked: isize
+                );
+                assert_eq!(value.to_f64().to_bits(), f64::from(value).to_bits());
+                if let Some(expected) = value.checked_to::<u64>() {
+                    assert_eq!(value.expect_u64("fits"), expected);
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_usize_capped() {
+        use crate::aliases::{U256, U512};
+
+        // Simulates a 32-bit `usize` with the same saturating conversion.
+        for (value, capped) in [
+            (U256::ZERO, 0),
+            (U256::from(u32::MAX - 1), u32::MAX - 1),
+            (U256::from(u32::MAX), u32::MAX),
+            (U256::from(u64::from(u32::MAX) + 1), u32::MAX),
+            (U256::from(u64::MAX), u32::MAX),
+            (U256::MAX, u32::MAX),
+        ] {
+            assert_eq!(value.saturating_to::<u32>(), capped);
+        }
+        proptest!(|(value: U512)| {
+            let expected = usize::try_from(value).unwrap_or(usize::MAX);
+            assert_eq!(value.to_usize_capped(), expected);
+        });
+        assert_eq!(U256::from(usize::MAX).to_usize_capped(), usize::MAX);
+        assert_eq!(U256::from(u128::MAX).to_usize_capped(), usize::MAX);
+        assert_eq!(Uint::<0, 0>::ZERO.to_usize_capped(), 0);
+
+        let slice = [1, 2, 3];
+        assert_eq!(U256::ZERO.index_into(&slice), Some(&1));
+        assert_eq!(U256::from(2).index_into(&slice), Some(&3));
+        assert_eq!(U256::from(3).index_into(&slice), None);
+        assert_eq!(U256::from(u64::MAX).index_into(&slice), None);
+        assert_eq!(U256::from_limbs([0, 1, 0, 0]).index_into(&slice), None);
+        assert_eq!(U256::ZERO.index_into::<u8>(&[]), None);
+    }
+
+    #[test]
+    #[should_panic = "too big"]
+    fn test_expect_u64() {
+        let _ = crate::aliases::U128::MAX.expect_u64("too big");
+    }
+
+    #[test]
+    fn test_char() {
+        use crate::aliases::{U8, U256};
+
+        proptest!(|(c: char)| {
+            assert_eq!(char::try_from(U256::from(u32::from(c))), Ok(c));
+        });
+        assert_eq!(char::try_from(U8::MAX), Ok('\u{ff}'));
+        assert_eq!(char::try_from(Uint::<0, 0>::ZERO), Ok('\0'));
+        assert_eq!(
+            char::try_from(U256::from(0xd800)),
+            Err(FromUintCharError::Surrogate(0xd800))
+        );
+        assert_eq!(
+            char::try_from(U256::from(0xdfff)),
+            Err(FromUintCharError::Surrogate(0xdfff))
+        );
+        assert_eq!(
+            char::try_from(U256::from(char::MAX as u32 + 1)),
+            Err(FromUintCharError::Overflow(256))
+        );
+        assert_eq!(
+            char::try_from(U256::MAX),
+            Err(FromUintCharError::Overflow(256))
+        );
+        assert_eq!(
+            FromUintCharError::Surrogate(0xd800).to_string(),
+            "0xd800 is a surrogate code point"
+        );
+    }
+
+    #[test]
+    fn test_from_ref() {
+        use crate::aliases::{U64, U256, U512, U4096};
+
+        // Only a shared reference to the source is required, no `Copy`.
+        fn convert<'a, S: ?Sized, D: UintTryFrom<&'a S>>(source: &'a S) -> Option<D> {
+            D::uint_try_from(source).ok()
+        }
+
+        let small = U4096::from(0x1337_u64);
+        let large = U4096::MAX;
+        assert_eq!(U512::from(&small), U512::from(0x1337_u64));
+        assert_eq!(U64::saturating_from(&large), U64::MAX);
+        assert_eq!(U64::wrapping_from(&large), U64::MAX);
+        assert_eq!(U256::checked_from(&large), None);
+        assert_eq!(convert::<_, U512>(&small), Some(U512::from(0x1337_u64)));
+        assert_eq!(convert::<_, U512>(&large), None);
+
+        let mut value = large;
+        assert_eq!(U512::saturating_from(&mut value), U512::MAX);
+        assert_eq!(
+            U512::uint_try_from(&large),
+            Err(ToUintError::ValueTooLarge(512, U512::MAX))
+        );
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert_eq!(U512::uint_try_from(&value), U512::uint_try_from(value));
+                assert_eq!(U64::uint_try_from(&value), U64::uint_try_from(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_resize() {
+        type U256 = Uint<256, 4>;
+        type U160 = Uint<160, 3>;
+        // Distinct low and high halves, so a truncation in the wrong direction
+        // or by the wrong amount shows.
+        let hash = U256::from_limbs([
+            0x0011_2233_4455_6677,
+            0x8899_aabb_ccdd_eeff,
+            0x0123_4567_89ab_cdef,
+            0xfedc_ba98_7654_3210,
+        ]);
+        let low = U160::from_limbs([0x0011_2233_4455_6677, 0x8899_aabb_ccdd_eeff, 0x89ab_cdef]);
+        let high = U160::from_limbs([0x89ab_cdef_8899_aabb, 0x7654_3210_0123_4567, 0xfedc_ba98]);
+        assert_eq!(hash.truncate_low::<160, 3>(), low);
+        assert_eq!(hash.truncate_high::<160, 3>(), high);
+        assert_eq!(hash.truncate_low::<256, 4>(), hash);
+        assert_eq!(hash.truncate_high::<256, 4>(), hash);
+        assert_eq!(hash.truncate_low::<0, 0>(), Uint::ZERO);
+        assert_eq!(hash.truncate_high::<0, 0>(), Uint::ZERO);
+        assert_eq!(hash.truncate_low::<1, 1>(), Uint::ONE);
+        assert_eq!(hash.truncate_high::<1, 1>(), Uint::ONE);
+        assert_eq!(hash.truncate_high::<4, 1>(), Uint::from(0xf));
+        assert_eq!(hash.truncate_high::<8, 1>(), Uint::from(0xfe));
+        assert_eq!(low.zero_extend::<256, 4>(), hash & (U256::MAX >> 96_usize));
+        assert_eq!(high.zero_extend::<256, 4>(), hash >> 96_usize);
+        assert_eq!(Uint::<0, 0>::ZERO.zero_extend::<64, 1>(), Uint::ZERO);
+
+        const LOW: Uint<64, 1> = Uint::<65, 2>::MAX.truncate_low();
+        assert_eq!(LOW, Uint::MAX);
+
+        proptest!(|(value: U256)| {
+            assert_eq!(value.truncate_low::<160, 3>(), value.wrapping_to::<U160>());
+            assert_eq!(value.truncate_high::<160, 3>(), (value >> 96_usize).to::<U160>());
+            assert_eq!(value.truncate_high::<129, 3>(), (value >> 127_usize).to::<Uint<129, 3>>());
+            assert_eq!(value.truncate_high::<63, 1>(), (value >> 193_usize).to::<Uint<63, 1>>());
+            let low = value.truncate_low::<100, 2>();
+            assert_eq!(low.zero_extend::<256, 4>(), value & ((U256::ONE << 100) - U256::ONE));
+        });
+    }
+
+    #[test]
+    fn test_zero_extend_truncate_low() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let wide = value.zero_extend::<4096, 64>();
+                assert_eq!(wide, value.to::<Uint<4096, 64>>());
+                assert_eq!(wide.truncate_low::<BITS, LIMBS>(), value);
+            });
+        });
+    }
+
     #[test]
     fn test_f64() {
         assert_eq!(Uint::<0, 0>::try_from(0.0_f64), Ok(Uint::ZERO));
@@ -946,6 +2012,48 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_f64_lossy() {
+        type U = Uint<64, 1>;
+        // 2^53 + 1 is not a float, but the float it rounds to is an integer.
+        let big = ((1_u64 << 53) + 1) as f64;
+        assert_eq!(U::from_f64_lossy(big), (U::from(1_u64 << 53), false));
+        assert_eq!(U::from_f64_lossy(0.5), (U::ONE, true));
+        assert_eq!(U::from_f64_lossy(123.0), (U::from(123), false));
+        assert_eq!(U::from_f64_lossy(123.456), (U::from(123), true));
+        assert_eq!(U::from_f64_lossy(123.5), (U::from(124), true));
+        assert_eq!(U::from_f64_lossy(0.0), (U::ZERO, false));
+        assert_eq!(U::from_f64_lossy(-0.0), (U::ZERO, false));
+        assert_eq!(U::from_f64_lossy(f64::MIN_POSITIVE), (U::ZERO, true));
+        assert_eq!(U::from_f64_lossy(-1.0), (U::ZERO, true));
+        assert_eq!(U::from_f64_lossy(f64::NAN), (U::ZERO, true));
+        assert_eq!(U::from_f64_lossy(2.0_f64.powi(64)), (U::MAX, true));
+        assert_eq!(U::from_f64_exact(big), Some(U::from(1_u64 << 53)));
+        assert_eq!(U::from_f64_exact(0.5), None);
+        assert_eq!(U::from_f64_exact(-1.0), None);
+        assert_eq!(Uint::<0, 0>::from_f64_exact(0.0), Some(Uint::ZERO));
+        assert_eq!(Uint::<0, 0>::from_f64_lossy(1.0), (Uint::ZERO, true));
+
+        proptest!(|(value in 0.0..1e19_f64)| {
+            let (n, lossy) = U::from_f64_lossy(value);
+            assert_eq!(Ok(n), U::try_from(value));
+            assert_eq!(lossy, value.fract() != 0.0);
+            assert_eq!(U::from_f64_exact(value), (!lossy).then_some(n));
+        });
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let float = f64::from(value);
+                match U::from_f64_exact(float) {
+                    Some(n) => assert_eq!(U::from_f64_lossy(float), (n, false)),
+                    // Only when rounding up to the next power of two overflows.
+                    None => assert_eq!(U::from_f64_lossy(float), (U::MAX, true)),
+                }
+            });
+        });
+    }
+
     #[test]
     fn all_integers_are_representable() {
         const MAX_SAFE_INTEGER: u64 = (1 << f64::MANTISSA_DIGITS) - 1;
@@ -1040,6 +2148,66 @@ mod test {
         assert!(f32::from(Uint::<F32_BITS, F_32LIMBS>::MAX).is_infinite());
     }
 
+    #[test]
+    fn test_to_f64_bits_saturation() {
+        const_for!(BITS in [1021, 1022, 1023, 1024, 1025, 1026, 1027, 1100] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            // 2^BITS - 1 rounds up to 2^BITS, which overflows from 1024 bits on.
+            let expected = if BITS < 1024 {
+                2.0_f64.powi(BITS as i32)
+            } else {
+                f64::INFINITY
+            };
+            assert_eq!(U::MAX.to_f64_bits(), expected.to_bits(), "BITS = {BITS}");
+            if BITS > 1024 {
+                let mut value = U::ZERO;
+                value.set_bit(1024, true);
+                assert_eq!(value.to_f64_bits(), f64::INFINITY.to_bits());
+                value.set_bit(1000, true);
+                assert_eq!(value.to_f64_bits(), f64::INFINITY.to_bits());
+            }
+        });
+
+        // Exactly 53 leading ones is exactly representable, the largest being
+        // `f64::MAX`. Half an ulp more ties to even, which rounds up.
+        type U = Uint<1024, 16>;
+        let ones = U::from((1_u64 << 53) - 1);
+        let max = ones << 971_usize;
+        assert_eq!(max.to_f64_bits(), f64::MAX.to_bits());
+        assert_eq!(
+            (max + (U::ONE << 970_usize)).to_f64_bits(),
+            f64::INFINITY.to_bits()
+        );
+        assert_eq!(
+            (max + (U::ONE << 969_usize)).to_f64_bits(),
+            f64::MAX.to_bits()
+        );
+        let small = ones << 100_usize;
+        let expected = ((1_u64 << 53) - 1) as f64 * 2.0_f64.powi(100);
+        assert_eq!(small.to_f64_bits(), expected.to_bits());
+        assert_eq!(
+            (small + (U::ONE << 99_usize)).to_f64_bits(),
+            2.0_f64.powi(153).to_bits()
+        );
+    }
+
+    #[test]
+    fn test_to_f64_bits_reference() {
+        const_for!(BITS in [1, 2, 52, 53, 54, 63, 64, 65, 128, 129, 256, 1000, 1023, 1024] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, shift in 0..BITS)| {
+                // Random values rarely have long runs of ones or zeros below
+                // the top bits, so also test the truncated value.
+                for value in [value, value >> shift, U::MAX >> shift] {
+                    let expected = value.to_string().parse::<f64>().unwrap();
+                    assert_eq!(value.to_f64_bits(), expected.to_bits(), "value = {value}");
+                }
+            });
+        });
+    }
+
     #[cfg(feature = "std")]
     fn old_uint_to_f64<const BITS: usize, const LIMBS: usize>(value: &Uint<BITS, LIMBS>) -> f64 {
         let (bits, exponent) = value.most_significant_bits();
@@ -0,0 +1,262 @@
+use crate::Uint;
+use core::iter::FusedIterator;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Iterate over all values of this type, from [`Self::ZERO`] to
+    /// [`Self::MAX`] in increasing order.
+    ///
+    /// This is intended for exhaustive testing of algorithms at small bit
+    /// sizes. It is a compile time error to call this when `BITS` is larger
+    /// than 32 bits, or when the number of values does not fit a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// Exhaustively verify the ring inverse at 8 and 12 bits:
+    ///
+    /// ```
+    /// # use ruint::{Uint, aliases::U8};
+    /// # type U12 = Uint<12, 1>;
+    /// for a in U8::iter_all() {
+    ///     match a.inv_ring() {
+    ///         Some(inverse) => assert_eq!(a * inverse, U8::ONE),
+    ///         None => assert!(!a.bit(0)),
+    ///     }
+    /// }
+    /// assert_eq!(
+    ///     U12::iter_all().filter(|a| a.inv_ring().is_some()).count(),
+    ///     2048
+    /// );
+    /// assert_eq!(U12::iter_all().rev().next(), Some(U12::MAX));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn iter_all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator + FusedIterator {
+        const { Self::assert_iter_all(BITS) }
+        IterAll {
+            front: 0,
+            back:  1 << BITS,
+        }
+        .map(Self::const_from_u64)
+    }
+
+    #[inline]
+    #[track_caller]
+    const fn assert_iter_all(bits: usize) {
+        assert!(
+            bits <= 32 && bits < usize::BITS as usize,
+            "iter_all is only available for BITS <= 32"
+        );
+    }
+
+    /// Adds one to the low `bits` bits of `self` read in reverse, so that bit
+    /// `bits - 1` is the least significant. Bits at and above `bits` are left
+    /// unchanged, and the window wraps around to zero after all ones.
+    ///
+    /// Starting from zero, repeated calls visit `0..2^bits` in the bit
+    /// reversed order used by number-theoretic transforms. Each call costs
+    /// amortized constant time, unlike [`Self::reverse_bits`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits > BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U8;
+    /// let i = U8::from(0b010);
+    /// assert_eq!(i.bit_reversed_increment(3), U8::from(0b110));
+    /// assert_eq!(U8::from(0b111).bit_reversed_increment(3), U8::ZERO);
+    /// assert_eq!(
+    ///     U8::from(0b1000_0111).bit_reversed_increment(3),
+    ///     U8::from(0b1000_0000)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn bit_reversed_increment(&self, bits: usize) -> Self {
+        assert!(
+            bits <= BITS,
+            "bit_reversed_increment: window is wider than the type"
+        );
+        let mut limbs = self.limbs;
+        let mut i = bits;
+        while i > 0 {
+            i -= 1;
+            let bit = 1 << (i % 64);
+            limbs[i / 64] ^= bit;
+            if limbs[i / 64] & bit != 0 {
+                break;
+            }
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// Iterate over `0..2^bits` in bit reversed order, as produced by
+    /// [`Self::bit_reversed_increment`].
+    ///
+    /// The permutation is generated one step at a time and never stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits > BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U8;
+    /// let order: Vec<U8> = U8::bit_reversed_range(3).collect();
+    /// assert_eq!(order, [0, 4, 2, 6, 1, 5, 3, 7].map(U8::from));
+    /// ```
+    #[inline]
+    pub fn bit_reversed_range(bits: usize) -> impl FusedIterator<Item = Self> {
+        assert!(
+            bits <= BITS,
+            "bit_reversed_range: window is wider than the type"
+        );
+        BitReversedRange {
+            next: Some(Self::ZERO),
+            bits,
+        }
+    }
+}
+
+/// Iterator returned by [`Uint::bit_reversed_range`].
+struct BitReversedRange<const BITS: usize, const LIMBS: usize> {
+    next: Option<Uint<BITS, LIMBS>>,
+    bits: usize,
+}
+
+impl<const BITS: usize, const LIMBS: usize> Iterator for BitReversedRange<BITS, LIMBS> {
+    type Item = Uint<BITS, LIMBS>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        let next = current.bit_reversed_increment(self.bits);
+        // Only the window changes, so wrapping brings it back to the start.
+        self.next = (next != Uint::ZERO).then_some(next);
+        Some(current)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> FusedIterator for BitReversedRange<BITS, LIMBS> {}
+
+/// Counter over the half-open range `front..back`.
+struct IterAll {
+    front: u64,
+    back:  u64,
+}
+
+impl Iterator for IterAll {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.front - 1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Fits by the assertion in `iter_all`.
+        #[allow(clippy::cast_possible_truncation)]
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for IterAll {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.back)
+    }
+}
+
+impl ExactSizeIterator for IterAll {}
+
+impl FusedIterator for IterAll {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::const_for;
+
+    #[test]
+    fn test_iter_all() {
+        assert_eq!(Uint::<0, 0>::iter_all().collect::<Vec<_>>(), [Uint::ZERO]);
+        assert_eq!(
+            Uint::<2, 1>::iter_all().rev().collect::<Vec<_>>(),
+            [3, 2, 1, 0].map(Uint::from)
+        );
+        const_for!(BITS in [1, 2, 3, 7, 8, 12, 16] {
+            const LIMBS: usize = crate::nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let mut iter = U::iter_all();
+            assert_eq!(iter.len(), 1 << BITS);
+            assert_eq!(iter.next(), Some(U::ZERO));
+            assert_eq!(iter.next_back(), Some(U::MAX));
+            assert_eq!(iter.len(), (1 << BITS) - 2);
+            let mut expected = U::ONE;
+            for value in iter.by_ref() {
+                assert_eq!(value, expected);
+                expected += U::ONE;
+            }
+            assert_eq!(expected, U::MAX);
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        });
+        assert_eq!(Uint::<32, 1>::iter_all().len(), 1 << 32);
+    }
+
+    #[test]
+    fn test_bit_reversed_range() {
+        assert_eq!(Uint::<0, 0>::bit_reversed_range(0).collect::<Vec<_>>(), [
+            Uint::ZERO
+        ]);
+        const_for!(BITS in [1, 2, 8, 63, 64, 65, 130] {
+            const LIMBS: usize = crate::nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            for bits in 0..=BITS.min(12) {
+                let order = U::bit_reversed_range(bits).collect::<Vec<_>>();
+                let naive = (0..1_u64 << bits)
+                    .map(|i| U::from(i).reverse_bits() >> (BITS - bits))
+                    .collect::<Vec<_>>();
+                assert_eq!(order, naive);
+                let mut sorted = order.clone();
+                sorted.sort_unstable();
+                sorted.dedup();
+                assert!(sorted.iter().copied().eq((0..1_u64 << bits).map(U::from)));
+            }
+        });
+    }
+
+    #[test]
+    fn test_bit_reversed_increment() {
+        type U = Uint<130, 3>;
+        // Carries cross limb boundaries and leave bits above the window alone.
+        let above = U::ONE << 129_usize;
+        let x = U::MAX >> 2_usize | above;
+        assert_eq!(x.bit_reversed_increment(128), above);
+        assert_eq!(
+            (U::MAX >> 2_usize ^ U::ONE).bit_reversed_increment(128),
+            U::ONE
+        );
+        assert_eq!(U::ZERO.bit_reversed_increment(128), U::ONE << 127_usize);
+        assert_eq!(U::ONE.bit_reversed_increment(0), U::ONE);
+        assert_eq!(U::ZERO.bit_reversed_increment(130), above);
+    }
+
+    #[test]
+    #[should_panic(expected = "window is wider than the type")]
+    fn test_bit_reversed_range_too_wide() {
+        let _ = Uint::<8, 1>::bit_reversed_range(9);
+    }
+}
@@ -5,11 +5,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 // Unstable features
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
-#![cfg_attr(feature = "nightly", feature(core_intrinsics, bigint_helper_methods))]
+#![cfg_attr(feature = "nightly", feature(core_intrinsics, const_unsigned_bigint_helpers))]
 #![cfg_attr(
     feature = "nightly",
     allow(internal_features, clippy::incompatible_msrv)
 )]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd, const_eval_select))]
 #![cfg_attr(
     feature = "generic_const_exprs",
     feature(generic_const_exprs),
@@ -29,21 +30,31 @@ mod macros;
 mod add;
 pub mod algorithms;
 pub mod aliases;
+mod alphabet;
+#[cfg(feature = "std")]
+mod atomic;
 mod base_convert;
 mod bit_arr;
 mod bits;
 mod bytes;
 mod cmp;
+mod comb;
 mod const_for;
+#[cfg(feature = "counters")]
+pub mod counters;
 mod div;
+mod duration;
+mod endian;
 mod fmt;
 mod from;
 mod gcd;
+mod iter;
 mod log;
 mod modular;
 mod mul;
 mod pow;
 mod root;
+mod simd;
 mod special;
 mod string;
 mod utils;
@@ -51,21 +62,34 @@ mod utils;
 pub mod support;
 
 #[doc(inline)]
-pub use bit_arr::Bits;
+pub use bit_arr::{Bits, ParseBitsError};
+
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use atomic::AtomicUint;
 
 #[doc(inline)]
 pub use self::{
+    add::SumError,
+    alphabet::{AlphabetError, CROCKFORD_BASE32},
     base_convert::BaseConvertError,
-    bytes::nbytes,
-    from::{FromUintError, ToFieldError, ToUintError, UintTryFrom, UintTryTo},
-    string::ParseError,
+    bytes::{DecodeError, Endian, FromBytesError, ImportError, Order, nbytes},
+    div::{Rounding, Ties},
+    endian::{Be, Le},
+    fmt::BufferTooSmall,
+    from::{
+        DisplayWithValue, FromUintCharError, FromUintError, FromUintNonZeroError, ToFieldError,
+        ToUintError, ToUintErrorWithInput, UintTryFrom, UintTryTo,
+    },
+    modular::ModAccumulator,
+    string::{EmptyItems, ParseError, ParseManyError},
 };
 
 // For documentation purposes we expose the macro directly, otherwise it is
 // wrapped in ./macros.rs.
 #[cfg(doc)]
 #[doc(inline)]
-pub use ruint_macro::uint;
+pub use ruint_macro::{uint, uint_hex};
 
 /// Extra features that are nightly only.
 #[cfg(feature = "generic_const_exprs")]
@@ -116,6 +140,42 @@ impl pu128 {
     }
 }
 
+/// Error for [`Uint::try_from_limbs_slice`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromLimbsError {
+    /// The slice is longer than `LIMBS` and the extra limbs are not all zero.
+    TooManyLimbs {
+        /// The number of limbs of the [`Uint`].
+        expected: usize,
+        /// The length of the slice.
+        got:      usize,
+    },
+
+    /// The most significant limb has bits set beyond `BITS`.
+    HighBitsSet {
+        /// The bit size of the [`Uint`].
+        expected: usize,
+        /// The bit length of the value.
+        bit_len:  usize,
+    },
+}
+
+impl core::error::Error for FromLimbsError {}
+
+impl core::fmt::Display for FromLimbsError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooManyLimbs { expected, got } => {
+                write!(f, "expected at most {expected} limbs, got {got}")
+            }
+            Self::HighBitsSet { expected, bit_len } => {
+                write!(f, "expected at most {expected} bits, got {bit_len}")
+            }
+        }
+    }
+}
+
 /// The ring of numbers modulo $2^{\mathtt{BITS}}$.
 ///
 /// [`Uint`] implements nearly all traits and methods from the `std` unsigned
@@ -141,8 +201,50 @@ impl pu128 {
 ///   the sum of the bit sizes of self and the argument). The std version
 ///   requires same-sized arguments and returns a pair of lower and higher bits.
 ///
+/// # Arithmetic
+///
+/// The operators wrap. The other overflow behaviours are available as methods:
+///
+/// | Operation | Wrapping                    | Checked                    | Overflowing                    | Saturating                    |
+/// |-----------|-----------------------------|----------------------------|--------------------------------|-------------------------------|
+/// | `+`       | [`Uint::wrapping_add`]      | [`Uint::checked_add`]      | [`Uint::overflowing_add`]      | [`Uint::saturating_add`]      |
+/// | `-`       | [`Uint::wrapping_sub`]      | [`Uint::checked_sub`]      | [`Uint::overflowing_sub`]      | [`Uint::saturating_sub`]      |
+/// | `*`       | [`Uint::wrapping_mul`]      | [`Uint::checked_mul`]      | [`Uint::overflowing_mul`]      | [`Uint::saturating_mul`]      |
+/// | `/`       | [`Uint::wrapping_div`]      | [`Uint::checked_div`]      | [`Uint::overflowing_div`]      | [`Uint::saturating_div`]      |
+/// | `%`       | [`Uint::wrapping_rem`]      | [`Uint::checked_rem`]      | [`Uint::overflowing_rem`]      |                               |
+/// | `pow`     | [`Uint::wrapping_pow`]      | [`Uint::checked_pow`]      | [`Uint::overflowing_pow`]      | [`Uint::saturating_pow`]      |
+/// | `<<`      | [`Uint::wrapping_shl`]      | [`Uint::checked_shl`]      | [`Uint::overflowing_shl`]      | [`Uint::saturating_shl`]      |
+/// | `>>`      | [`Uint::wrapping_shr`]      | [`Uint::checked_shr`]      | [`Uint::overflowing_shr`]      |                               |
+/// | `-x`      | [`Uint::wrapping_neg`]      | [`Uint::checked_neg`]      | [`Uint::overflowing_neg`]      |                               |
+///
+/// Division and remainder never overflow, and only panic on a zero divisor;
+/// the checked versions return [`None`] instead. Like `std`, there is no
+/// saturating remainder, right shift or negation.
+///
+/// # Conversions
+///
+/// Conversions from and to primitive integers, floats and other [`Uint`]
+/// sizes are available in the following flavours:
+///
+/// | On failure          | Into [`Uint`]                 | From [`Uint`]               |
+/// |---------------------|-------------------------------|-----------------------------|
+/// | panic               | [`Uint::from`]                | [`Uint::to`]                |
+/// | return [`Option`]   | [`Uint::checked_from`]        | [`Uint::checked_to`]        |
+/// | return [`Result`]   | [`TryFrom`]                   | [`Uint::try_to`]            |
+/// | saturate            | [`Uint::saturating_from`]     | [`Uint::saturating_to`]     |
+/// | wrap                | [`Uint::wrapping_from`]       | [`Uint::wrapping_to`]       |
+///
+/// Between [`Uint`] sizes, [`Uint::zero_extend`], [`Uint::truncate_low`] and
+/// [`Uint::truncate_high`] change the width and never fail. Prefer them when
+/// that is the intent, and the flavours above when the value is meant to be
+/// preserved.
+///
+/// The non-zero integers like [`NonZeroU64`](core::num::NonZeroU64) convert
+/// into [`Uint`] the same way, but from [`Uint`] only through [`TryFrom`], with
+/// a [`FromUintNonZeroError`].
+///
 /// [std-overflow]: https://doc.rust-lang.org/reference/expressions/operator-expr.html#overflow
-#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct Uint<const BITS: usize, const LIMBS: usize> {
     limbs: [u64; LIMBS],
@@ -159,7 +261,14 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         limbs
     };
 
+    /// The size of a limb in bits.
+    pub const LIMB_BITS: usize = 64;
+
     /// Bit mask for the last limb.
+    ///
+    /// The most significant limb only has `BITS % 64` valid bits (all 64 if
+    /// `BITS` is a multiple of 64), the bits outside this mask are always zero.
+    /// Zero if `BITS` is zero.
     pub const MASK: u64 = mask(BITS);
 
     const SHOULD_MASK: bool = BITS > 0 && Self::MASK != u64::MAX;
@@ -173,7 +282,8 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 
     /// The value one. This is useful to have as a constant for use in const fn.
     ///
-    /// Zero if `BITS` is zero.
+    /// Zero if `BITS` is zero, as one is not representable there. Code that
+    /// relies on `ONE != ZERO` must exclude `Uint<0, 0>`.
     pub const ONE: Self = Self::const_from_u64(1);
 
     /// The smallest value that can be represented by this integer type.
@@ -212,6 +322,343 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         self.limbs
     }
 
+    /// Returns the least significant 64 bits, discarding the rest.
+    ///
+    /// Unlike `as_limbs()[0]`, this is zero rather than a panic if
+    /// `LIMBS == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0x1_0000_0000_0000_002a_U128.low_u64(), 42);
+    /// assert_eq!(U0::ZERO.low_u64(), 0);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn low_u64(&self) -> u64 {
+        if LIMBS == 0 { 0 } else { self.limbs[0] }
+    }
+
+    /// Returns the least significant 128 bits, discarding the rest.
+    #[inline]
+    #[must_use]
+    pub const fn low_u128(&self) -> u128 {
+        match LIMBS {
+            0 => 0,
+            1 => self.limbs[0] as u128,
+            _ => self.limbs[0] as u128 | (self.limbs[1] as u128) << 64,
+        }
+    }
+
+    /// Returns the value as a [`u64`], or [`None`] if any higher bit is set.
+    ///
+    /// This is the `const` equivalent of `u64::try_from(self).ok()`.
+    #[inline]
+    #[must_use]
+    pub const fn try_low_u64(&self) -> Option<u64> {
+        let mut i = 1;
+        while i < LIMBS {
+            if self.limbs[i] != 0 {
+                return None;
+            }
+            i += 1;
+        }
+        Some(self.low_u64())
+    }
+
+    /// Returns the value as a [`u64`], or [`u64::MAX`] if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(42_U256.as_u64_saturating(), 42);
+    /// assert_eq!(0x1_0000_0000_0000_0000_U256.as_u64_saturating(), u64::MAX);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_u64_saturating(&self) -> u64 {
+        match self.try_low_u64() {
+            Some(value) => value,
+            None => u64::MAX,
+        }
+    }
+
+    /// Iterate over the value as little-endian 128-bit words.
+    ///
+    /// Each word packs two consecutive limbs. If `LIMBS` is odd, the final
+    /// word holds the last limb zero-extended to 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U192};
+    /// # uint!{
+    /// let value = 0x0123_4567_89ab_cdef_0011_2233_4455_6677_8899_aabb_ccdd_eeff_U192;
+    /// let words = value.u128_words().collect::<Vec<_>>();
+    /// assert_eq!(words, [
+    ///     0x0011_2233_4455_6677_8899_aabb_ccdd_eeff,
+    ///     0x0123_4567_89ab_cdef
+    /// ]);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn u128_words(&self) -> impl DoubleEndedIterator<Item = u128> + ExactSizeIterator + '_ {
+        self.limbs.chunks(2).map(|chunk| match *chunk {
+            [lo, hi] => u128::from(lo) | (u128::from(hi) << 64),
+            [lo] => u128::from(lo),
+            _ => unreachable!(),
+        })
+    }
+
+    /// Convert to an array of little-endian 128-bit words.
+    ///
+    /// If `LIMBS` is odd, the final word holds the last limb zero-extended to
+    /// 128 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generic parameter `WORDS` is not exactly
+    /// `LIMBS.div_ceil(2)`.
+    #[inline]
+    #[must_use]
+    pub const fn to_u128_words_le<const WORDS: usize>(&self) -> [u128; WORDS] {
+        const { Self::assert_u128_words(WORDS) }
+        self.to_u128_array()
+    }
+
+    /// Construct a new integer from an array of little-endian 128-bit words.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generic parameter `WORDS` is not exactly
+    /// `LIMBS.div_ceil(2)`.
+    ///
+    /// Panics if the value is too large for the bit-size of the Uint. If
+    /// `LIMBS` is odd, this includes any non-zero bits in the upper half of
+    /// the final word.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn from_u128_words_le<const WORDS: usize>(words: [u128; WORDS]) -> Self {
+        const { Self::assert_u128_words(WORDS) }
+        match Self::try_from_u128_array(words) {
+            Ok(value) => value,
+            Err(_) => panic!("Value too large for this Uint"),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    const fn assert_u128_words(words: usize) {
+        assert!(
+            words == LIMBS.div_ceil(2),
+            "WORDS must be equal to LIMBS.div_ceil(2)"
+        );
+    }
+
+    /// Convert to an array of `N` little-endian 128-bit words, zero-padded
+    /// to the array length.
+    ///
+    /// Unlike [`Self::to_u128_words_le`], `N` may exceed the minimal number of
+    /// words, e.g. to pass a [`U192`](crate::aliases::U192) to an API taking
+    /// `[u128; 3]`.
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `N * 128 < BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U192;
+    /// let value = U192::from_limbs([1, 2, 3]);
+    /// assert_eq!(value.to_u128_array(), [2 << 64 | 1, 3]);
+    /// assert_eq!(value.to_u128_array(), [2 << 64 | 1, 3, 0]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_u128_array<const N: usize>(&self) -> [u128; N] {
+        const { Self::assert_u128_array(N) }
+        let mut words = [0; N];
+        let mut i = 0;
+        while i < LIMBS {
+            words[i / 2] |= (self.limbs[i] as u128) << (64 * (i % 2));
+            i += 1;
+        }
+        words
+    }
+
+    #[inline]
+    #[track_caller]
+    const fn assert_u128_array(words: usize) {
+        assert!(words * 128 >= BITS, "N * 128 must be at least BITS");
+    }
+
+    /// Construct a new integer from an array of `N` little-endian 128-bit
+    /// words, the reverse of [`Self::to_u128_array`].
+    ///
+    /// # Errors
+    ///
+    /// * [`FromLimbsError::TooManyLimbs`] if any limb beyond `LIMBS` is
+    ///   non-zero. The array counts as `2 * N` limbs.
+    /// * [`FromLimbsError::HighBitsSet`] if the value does not fit in `BITS`
+    ///   bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{FromLimbsError, aliases::U192};
+    /// let value = U192::from_limbs([1, 2, 3]);
+    /// assert_eq!(U192::try_from_u128_array([2 << 64 | 1, 3, 0]), Ok(value));
+    /// assert_eq!(
+    ///     U192::try_from_u128_array([0, 1 << 64]),
+    ///     Err(FromLimbsError::TooManyLimbs {
+    ///         expected: 3,
+    ///         got:      4,
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    pub const fn try_from_u128_array<const N: usize>(
+        words: [u128; N],
+    ) -> Result<Self, FromLimbsError> {
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i < 2 * N {
+            let limb = (words[i / 2] >> (64 * (i % 2))) as u64;
+            if i < LIMBS {
+                limbs[i] = limb;
+            } else if limb != 0 {
+                return Err(FromLimbsError::TooManyLimbs {
+                    expected: LIMBS,
+                    got:      2 * N,
+                });
+            }
+            i += 1;
+        }
+        if LIMBS > 0 && limbs[LIMBS - 1] > Self::MASK {
+            return Err(FromLimbsError::HighBitsSet {
+                expected: BITS,
+                bit_len:  64 * LIMBS - limbs[LIMBS - 1].leading_zeros() as usize,
+            });
+        }
+        Ok(Self::from_limbs(limbs))
+    }
+
+    /// Convert to an array of little-endian 32-bit digits, the low half of
+    /// each limb first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generic parameter `DIGITS` is not exactly `2 * LIMBS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U128};
+    /// # uint!{
+    /// let value = 0x0123_4567_89ab_cdef_0011_2233_U128;
+    /// assert_eq!(value.to_u32_digits_le(), [
+    ///     0x0011_2233,
+    ///     0x89ab_cdef,
+    ///     0x0123_4567,
+    ///     0
+    /// ]);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // Intentional.
+    pub const fn to_u32_digits_le<const DIGITS: usize>(&self) -> [u32; DIGITS] {
+        const { Self::assert_u32_digits(DIGITS) }
+        let mut digits = [0; DIGITS];
+        let mut i = 0;
+        while i < DIGITS {
+            digits[i] = (self.limbs[i / 2] >> (32 * (i % 2))) as u32;
+            i += 1;
+        }
+        digits
+    }
+
+    #[inline]
+    #[track_caller]
+    const fn assert_u32_digits(digits: usize) {
+        assert!(digits == 2 * LIMBS, "DIGITS must be equal to 2 * LIMBS");
+    }
+
+    /// Convert to a vector of little-endian 32-bit digits with the trailing
+    /// zero digits removed. Zero gives an empty vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(0x1_0000_0002_U256.to_u32_digits_le_trimmed_vec(), [2, 1]);
+    /// assert!(U256::ZERO.to_u32_digits_le_trimmed_vec().is_empty());
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // Intentional.
+    pub fn to_u32_digits_le_trimmed_vec(&self) -> alloc::vec::Vec<u32> {
+        let mut digits = self
+            .limbs
+            .iter()
+            .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+            .collect::<alloc::vec::Vec<_>>();
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        digits
+    }
+
+    /// Construct a new integer from a slice of little-endian 32-bit digits.
+    ///
+    /// The slice may be longer than `2 * LIMBS` as long as the extra digits
+    /// are zero.
+    ///
+    /// # Errors
+    ///
+    /// * [`FromLimbsError::TooManyLimbs`] if any digit beyond `2 * LIMBS` is
+    ///   non-zero. The lengths are reported in 64-bit limbs.
+    /// * [`FromLimbsError::HighBitsSet`] if the value does not fit in `BITS`
+    ///   bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(U64::from_u32_digits_le(&[2, 1, 0]), Ok(0x1_0000_0002_U64));
+    /// assert!(U64::from_u32_digits_le(&[2, 1, 3]).is_err());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_u32_digits_le(digits: &[u32]) -> Result<Self, FromLimbsError> {
+        let mut limbs = [0; LIMBS];
+        for (i, &digit) in digits.iter().enumerate() {
+            match limbs.get_mut(i / 2) {
+                Some(limb) => *limb |= u64::from(digit) << (32 * (i % 2)),
+                None if digit != 0 => {
+                    return Err(FromLimbsError::TooManyLimbs {
+                        expected: LIMBS,
+                        got:      digits.len().div_ceil(2),
+                    });
+                }
+                None => {}
+            }
+        }
+        Self::try_from_limbs_slice(&limbs)
+    }
+
     #[inline]
     pub(crate) const fn as_double_words(&self) -> &[pu128] {
         assert!(LIMBS >= 2);
@@ -257,10 +704,96 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[must_use]
     #[track_caller]
     pub fn from_limbs_slice(slice: &[u64]) -> Self {
-        match Self::overflowing_from_limbs_slice(slice) {
-            (n, false) => n,
-            (_, true) => panic!("Value too large for this Uint"),
+        match Self::try_from_limbs_slice(slice) {
+            Ok(n) => n,
+            Err(_) => panic!("Value too large for this Uint"),
+        }
+    }
+
+    /// Construct a new integer from little-endian a slice of limbs.
+    ///
+    /// The slice may be longer than `LIMBS` as long as the extra limbs are
+    /// zero.
+    ///
+    /// # Errors
+    ///
+    /// * [`FromLimbsError::TooManyLimbs`] if any limb beyond `LIMBS` is
+    ///   non-zero.
+    /// * [`FromLimbsError::HighBitsSet`] if the value does not fit in `BITS`
+    ///   bits.
+    #[inline]
+    pub fn try_from_limbs_slice(slice: &[u64]) -> Result<Self, FromLimbsError> {
+        let (head, tail) = slice.split_at(slice.len().min(LIMBS));
+        if tail.iter().any(|&limb| limb != 0) {
+            return Err(FromLimbsError::TooManyLimbs {
+                expected: LIMBS,
+                got:      slice.len(),
+            });
+        }
+        let mut limbs = [0; LIMBS];
+        limbs[..head.len()].copy_from_slice(head);
+        if LIMBS > 0 && limbs[LIMBS - 1] > Self::MASK {
+            return Err(FromLimbsError::HighBitsSet {
+                expected: BITS,
+                bit_len:  64 * LIMBS - limbs[LIMBS - 1].leading_zeros() as usize,
+            });
+        }
+        Ok(Self::from_limbs(limbs))
+    }
+
+    /// Construct a new integer from an iterator of little-endian limbs, e.g.
+    /// limbs decoded one at a time off a wire.
+    ///
+    /// Like [`Self::try_from_limbs_slice`], the iterator may yield more than
+    /// `LIMBS` items as long as the extra ones are zero. It is always consumed
+    /// entirely.
+    ///
+    /// # Errors
+    ///
+    /// * [`FromLimbsError::TooManyLimbs`] if any limb beyond `LIMBS` is
+    ///   non-zero. `got` is the total number of items.
+    /// * [`FromLimbsError::HighBitsSet`] if the value does not fit in `BITS`
+    ///   bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{FromLimbsError, aliases::U128};
+    /// assert_eq!(
+    ///     U128::try_from_iter([1, 2]),
+    ///     Ok(U128::from(2_u128 << 64 | 1))
+    /// );
+    /// assert_eq!(
+    ///     U128::try_from_iter([1, 2, 0]),
+    ///     Ok(U128::from(2_u128 << 64 | 1))
+    /// );
+    /// assert_eq!(
+    ///     U128::try_from_iter([1, 2, 3]),
+    ///     Err(FromLimbsError::TooManyLimbs {
+    ///         expected: 2,
+    ///         got:      3,
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Result<Self, FromLimbsError> {
+        let mut limbs = [0; LIMBS];
+        let mut excess = false;
+        let mut got = 0;
+        for limb in iter {
+            match limbs.get_mut(got) {
+                Some(slot) => *slot = limb,
+                None => excess |= limb != 0,
+            }
+            got += 1;
         }
+        if excess {
+            return Err(FromLimbsError::TooManyLimbs {
+                expected: LIMBS,
+                got,
+            });
+        }
+        Self::try_from_limbs_slice(&limbs)
     }
 
     /// Construct a new integer from little-endian a slice of limbs, or `None`
@@ -268,10 +801,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[inline]
     #[must_use]
     pub fn checked_from_limbs_slice(slice: &[u64]) -> Option<Self> {
-        match Self::overflowing_from_limbs_slice(slice) {
-            (n, false) => Some(n),
-            (_, true) => None,
-        }
+        Self::try_from_limbs_slice(slice).ok()
     }
 
     /// Construct a new [`Uint`] from a little-endian slice of limbs. Returns
@@ -337,6 +867,134 @@ impl<const BITS: usize, const LIMBS: usize> Default for Uint<BITS, LIMBS> {
     }
 }
 
+/// The little-endian limbs, as in [`Uint::into_limbs`].
+///
+/// # Examples
+///
+/// Passing a value to an API that takes plain limb arrays, like
+/// `secp256k1::Scalar::from_words`:
+///
+/// ```
+/// # use ruint::{uint, aliases::U256};
+/// fn from_words(words: [u64; 4]) -> [u64; 4] {
+///     words
+/// }
+///
+/// let value = uint!(0x0123_4567_89ab_cdef_0011_2233_4455_6677_U256);
+/// let words = from_words(value.into());
+/// assert_eq!(words, [0x0011_2233_4455_6677, 0x0123_4567_89ab_cdef, 0, 0]);
+/// assert_eq!(U256::try_from(words), Ok(value));
+/// ```
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for [u64; LIMBS] {
+    #[inline]
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        value.into_limbs()
+    }
+}
+
+/// Little-endian limbs of the same length as the [`Uint`]. Changing the width
+/// of either side so that the lengths no longer match fails to compile.
+///
+/// Returns [`FromLimbsError::HighBitsSet`] if the most significant limb has
+/// bits set beyond `BITS`.
+impl<const BITS: usize, const LIMBS: usize> TryFrom<[u64; LIMBS]> for Uint<BITS, LIMBS> {
+    type Error = FromLimbsError;
+
+    #[inline]
+    fn try_from(limbs: [u64; LIMBS]) -> Result<Self, Self::Error> {
+        Self::try_from_limbs_slice(&limbs)
+    }
+}
+
+/// Collects little-endian limbs, least significant first.
+///
+/// # Panics
+///
+/// Panics if the value is too large for the bit-size of the Uint. Use
+/// [`Uint::try_from_iter`] to handle this.
+impl<const BITS: usize, const LIMBS: usize> FromIterator<u64> for Uint<BITS, LIMBS> {
+    #[inline]
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        match Self::try_from_iter(iter) {
+            Ok(value) => value,
+            Err(_) => panic!("Value too large for this Uint"),
+        }
+    }
+}
+
+/// Hashes each limb, least significant first, using [`Hasher::write_u64`].
+///
+/// The result is independent of the target's byte order if the hasher's
+/// `write_u64` is. Use [`Uint::hash_stable`] for a byte encoding that is the
+/// same on all targets regardless of the hasher.
+///
+/// [`Hasher::write_u64`]: core::hash::Hasher::write_u64
+impl<const BITS: usize, const LIMBS: usize> core::hash::Hash for Uint<BITS, LIMBS> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        for &limb in &self.limbs {
+            state.write_u64(limb);
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Feeds the canonical little-endian encoding of [`Self::BYTES`] bytes
+    /// into the hasher.
+    ///
+    /// Unlike [`Hash`](core::hash::Hash), this only uses
+    /// [`Hasher::write`](core::hash::Hasher::write), so a deterministic hasher
+    /// produces identical hashes on all targets.
+    #[inline]
+    pub fn hash_stable<H: core::hash::Hasher>(&self, state: &mut H) {
+        let mut remaining = Self::BYTES;
+        for limb in &self.limbs {
+            let bytes = limb.to_le_bytes();
+            let n = remaining.min(8);
+            state.write(&bytes[..n]);
+            remaining -= n;
+        }
+    }
+
+    /// Folds the limbs into a single `u64` for use as a key in fast,
+    /// non-cryptographic hash maps.
+    ///
+    /// Single limb values (up to 64 bits) return the value itself. Wider
+    /// values fold the limbs from least to most significant with the FxHash
+    /// step `h = (h.rotate_left(5) ^ limb) * 0x517c_c1b7_2722_0a95`, starting
+    /// from the least significant limb. The fold is part of the stable API.
+    ///
+    /// Equal values produce equal keys, but this is not collision resistant:
+    /// only use it with a fast hasher like FxHash when the keys are trusted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::{U64, U128};
+    /// assert_eq!(U64::from(1234).as_hash_key_u64(), 1234);
+    /// assert_ne!(
+    ///     U128::from(1).as_hash_key_u64(),
+    ///     U128::from(1_u128 << 64).as_hash_key_u64()
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_hash_key_u64(&self) -> u64 {
+        const K: u64 = 0x517c_c1b7_2722_0a95;
+        if LIMBS == 0 {
+            return 0;
+        }
+        let mut hash = self.limbs[0];
+        let mut i = 1;
+        while i < LIMBS {
+            hash = (hash.rotate_left(5) ^ self.limbs[i]).wrapping_mul(K);
+            i += 1;
+        }
+        hash
+    }
+}
+
 /// Number of `u64` limbs required to represent the given number of bits.
 /// This needs to be public because it is used in the `Uint` type.
 #[inline]
@@ -387,12 +1045,390 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_size_constants() {
+        macro_rules! assert_sizes {
+            ($($bits:literal => $limbs:literal, $bytes:literal, $mask:expr;)*) => {$(
+                const _: () = {
+                    type U = Uint<$bits, $limbs>;
+                    assert!(nlimbs($bits) == $limbs);
+                    assert!(nbytes($bits) == $bytes);
+                    assert!(U::LIMBS == $limbs);
+                    assert!(U::BYTES == $bytes);
+                    assert!(U::MASK == $mask);
+                    assert!(U::LIMB_BITS * U::LIMBS >= U::BITS);
+                };
+                let buffer = [0_u8; Uint::<$bits, $limbs>::BYTES];
+                assert_eq!(Uint::<$bits, $limbs>::MAX.to_be_bytes::<$bytes>().len(), buffer.len());
+            )*};
+        }
+        assert_sizes! {
+            0 => 0, 0, 0;
+            1 => 1, 1, 1;
+            63 => 1, 8, u64::MAX >> 1;
+            64 => 1, 8, u64::MAX;
+            65 => 2, 9, 1;
+            255 => 4, 32, u64::MAX >> 1;
+            256 => 4, 32, u64::MAX;
+        }
+    }
+
     #[test]
     fn test_constants() {
         const_for!(BITS in SIZES {
             const LIMBS: usize = nlimbs(BITS);
             assert_eq!(Uint::<BITS, LIMBS>::MIN, Uint::<BITS, LIMBS>::ZERO);
+            assert_eq!(Uint::<BITS, LIMBS>::default(), Uint::<BITS, LIMBS>::ZERO);
             let _ = Uint::<BITS, LIMBS>::MAX;
         });
+        assert_eq!(Uint::<0, 0>::MIN.as_limbs(), &[0_u64; 0]);
+        assert_eq!(Uint::<0, 0>::ONE.as_limbs(), &[0_u64; 0]);
+        assert_eq!(Uint::<0, 0>::ONE, Uint::<0, 0>::ZERO);
+        assert_eq!(Uint::<0, 0>::default().as_limbs(), &[0_u64; 0]);
+        assert_eq!(Uint::<1, 1>::MIN.as_limbs(), &[0]);
+        assert_eq!(Uint::<1, 1>::ONE.as_limbs(), &[1]);
+        assert_eq!(Uint::<1, 1>::MAX.as_limbs(), &[1]);
+        assert_eq!(Uint::<64, 1>::MIN.as_limbs(), &[0]);
+        assert_eq!(Uint::<64, 1>::ONE.as_limbs(), &[1]);
+        assert_eq!(Uint::<64, 1>::MAX.as_limbs(), &[u64::MAX]);
+        assert_eq!(Uint::<65, 2>::MIN.as_limbs(), &[0, 0]);
+        assert_eq!(Uint::<65, 2>::ONE.as_limbs(), &[1, 0]);
+        assert_eq!(Uint::<65, 2>::MAX.as_limbs(), &[u64::MAX, 1]);
+        assert_eq!(Uint::<65, 2>::default().as_limbs(), &[0, 0]);
+    }
+
+    #[test]
+    fn test_try_from_limbs_slice() {
+        type U = Uint<72, 2>;
+        assert_eq!(U::try_from_limbs_slice(&[]), Ok(U::ZERO));
+        assert_eq!(
+            U::try_from_limbs_slice(&[1, 0xff]),
+            Ok(U::from_limbs([1, 0xff]))
+        );
+        assert_eq!(
+            U::try_from_limbs_slice(&[1, 2, 0, 0]),
+            Ok(U::from_limbs([1, 2]))
+        );
+        assert_eq!(
+            U::try_from_limbs_slice(&[1, 2, 0, 3]),
+            Err(FromLimbsError::TooManyLimbs {
+                expected: 2,
+                got:      4,
+            })
+        );
+        assert_eq!(
+            U::try_from_limbs_slice(&[0, 0x100]),
+            Err(FromLimbsError::HighBitsSet {
+                expected: 72,
+                bit_len:  73,
+            })
+        );
+        assert_eq!(
+            Uint::<0, 0>::try_from_limbs_slice(&[1]),
+            Err(FromLimbsError::TooManyLimbs {
+                expected: 0,
+                got:      1,
+            })
+        );
+        assert_eq!(U::checked_from_limbs_slice(&[0, 0x100]), None);
+        assert_eq!(
+            FromLimbsError::TooManyLimbs {
+                expected: 2,
+                got:      4,
+            }
+            .to_string(),
+            "expected at most 2 limbs, got 4"
+        );
+        assert_eq!(
+            FromLimbsError::HighBitsSet {
+                expected: 72,
+                bit_len:  73,
+            }
+            .to_string(),
+            "expected at most 72 bits, got 73"
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)] // `SipHasher` is SipHash-2-4 with fixed keys.
+    fn test_hash_stable() {
+        use core::hash::{Hasher, SipHasher};
+
+        fn hash_stable<const BITS: usize, const LIMBS: usize>(value: Uint<BITS, LIMBS>) -> u64 {
+            let mut hasher = SipHasher::new_with_keys(0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210);
+            value.hash_stable(&mut hasher);
+            hasher.finish()
+        }
+
+        fn hash_bytes(bytes: &[u8]) -> u64 {
+            let mut hasher = SipHasher::new_with_keys(0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210);
+            hasher.write(bytes);
+            hasher.finish()
+        }
+
+        let value = Uint::<72, 2>::from_limbs([0x0807_0605_0403_0201, 0x09]);
+        assert_eq!(hash_stable(value), hash_bytes(&[1, 2, 3, 4, 5, 6, 7, 8, 9]));
+        assert_eq!(hash_stable(Uint::<0, 0>::ZERO), hash_bytes(&[]));
+        assert_eq!(hash_stable(value), 0xf01b_ee22_27d4_071d);
+        assert_eq!(hash_stable(Uint::<256, 4>::MAX), 0x412b_d6d2_7ab3_7951);
+    }
+
+    #[test]
+    fn test_hash_limbs() {
+        use core::hash::{Hash, Hasher};
+
+        #[derive(Default)]
+        struct Recorder(Vec<u64>);
+        impl Hasher for Recorder {
+            fn finish(&self) -> u64 {
+                0
+            }
+            fn write(&mut self, _: &[u8]) {
+                panic!("only write_u64 is expected");
+            }
+            fn write_u64(&mut self, value: u64) {
+                self.0.push(value);
+            }
+        }
+
+        let mut recorder = Recorder::default();
+        Uint::<192, 3>::from_limbs([1, 2, 3]).hash(&mut recorder);
+        assert_eq!(recorder.0, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_hash_compose() {
+        use crate::aliases::U256;
+        use std::collections::HashSet;
+
+        let pairs = HashSet::from([[U256::ZERO, U256::MAX], [U256::MAX, U256::ZERO]]);
+        assert!(pairs.contains(&[U256::MAX, U256::ZERO]));
+        assert!(!pairs.contains(&[U256::MAX, U256::MAX]));
+
+        let refs = HashSet::from([&U256::ONE, &U256::MAX]);
+        assert!(refs.contains(&&U256::ONE));
+    }
+
+    #[test]
+    fn test_as_hash_key_u64() {
+        use proptest::proptest;
+
+        assert_eq!(Uint::<0, 0>::ZERO.as_hash_key_u64(), 0);
+        assert_eq!(Uint::<7, 1>::MAX.as_hash_key_u64(), 127);
+        let value = Uint::<192, 3>::from_limbs([1, 2, 3]);
+        let step =
+            |hash: u64, limb: u64| (hash.rotate_left(5) ^ limb).wrapping_mul(0x517c_c1b7_2722_0a95);
+        assert_eq!(value.as_hash_key_u64(), step(step(1, 2), 3));
+        const_for!(BITS in [1, 2, 63, 64] {
+            proptest!(|(value: Uint<BITS, 1>)| {
+                assert_eq!(value.as_hash_key_u64(), value.as_limbs()[0]);
+            });
+        });
+    }
+
+    #[test]
+    fn test_low() {
+        let value = Uint::<192, 3>::from_limbs([1, 2, 3]);
+        assert_eq!(value.low_u64(), 1);
+        assert_eq!(value.low_u128(), 2 << 64 | 1);
+        assert_eq!(value.try_low_u64(), None);
+        assert_eq!(value.as_u64_saturating(), u64::MAX);
+
+        const ZERO: (u64, u128, Option<u64>, u64) = {
+            let zero = Uint::<0, 0>::ZERO;
+            (
+                zero.low_u64(),
+                zero.low_u128(),
+                zero.try_low_u64(),
+                zero.as_u64_saturating(),
+            )
+        };
+        assert_eq!(ZERO, (0, 0, Some(0), 0));
+
+        let small = Uint::<7, 1>::MAX;
+        assert_eq!(small.low_u64(), 127);
+        assert_eq!(small.low_u128(), 127);
+        assert_eq!(small.try_low_u64(), Some(127));
+        assert_eq!(small.as_u64_saturating(), 127);
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest::proptest!(|(value: U)| {
+                assert_eq!(value.low_u64(), value.wrapping_to::<u64>());
+                assert_eq!(value.low_u128(), value.wrapping_to::<u128>());
+                assert_eq!(value.try_low_u64(), u64::try_from(value).ok());
+                assert_eq!(value.as_u64_saturating(), value.saturating_to::<u64>());
+            });
+        });
+    }
+
+    #[test]
+    fn test_u128_words() {
+        let value = Uint::<192, 3>::from_limbs([1, 2, 3]);
+        assert_eq!(value.to_u128_words_le(), [2 << 64 | 1, 3]);
+        assert_eq!(value.u128_words().rev().collect::<Vec<_>>(), [
+            3,
+            2 << 64 | 1
+        ]);
+        assert_eq!(Uint::<192, 3>::from_u128_words_le([2 << 64 | 1, 3]), value);
+        assert_eq!(Uint::<0, 0>::ZERO.to_u128_words_le(), [0_u128; 0]);
+        assert_eq!(Uint::<0, 0>::ZERO.u128_words().len(), 0);
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            const WORDS: usize = LIMBS.div_ceil(2);
+            type U = Uint<BITS, LIMBS>;
+            proptest::proptest!(|(value: U)| {
+                let words = value.to_u128_words_le::<WORDS>();
+                assert_eq!(value.u128_words().collect::<Vec<_>>(), words);
+                for (i, limb) in value.as_limbs().iter().enumerate() {
+                    assert_eq!((words[i / 2] >> (64 * (i % 2))) as u64, *limb);
+                }
+                assert_eq!(U::from_u128_words_le(words), value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_u32_digits() {
+        let value = Uint::<96, 2>::from_limbs([0x89ab_cdef_0011_2233, 0x0123_4567]);
+        assert_eq!(value.to_u32_digits_le(), [
+            0x0011_2233,
+            0x89ab_cdef,
+            0x0123_4567,
+            0
+        ]);
+        assert_eq!(value.to_u32_digits_le_trimmed_vec(), [
+            0x0011_2233,
+            0x89ab_cdef,
+            0x0123_4567
+        ]);
+        assert_eq!(
+            Uint::<96, 2>::from_u32_digits_le(&[0x0011_2233, 0x89ab_cdef, 0x0123_4567]),
+            Ok(value)
+        );
+        assert_eq!(
+            Uint::<96, 2>::from_u32_digits_le(&[0, 0, 0, 1]),
+            Err(FromLimbsError::HighBitsSet {
+                expected: 96,
+                bit_len:  97,
+            })
+        );
+        assert_eq!(
+            Uint::<96, 2>::from_u32_digits_le(&[0, 0, 0, 0, 1]),
+            Err(FromLimbsError::TooManyLimbs {
+                expected: 2,
+                got:      3,
+            })
+        );
+        assert_eq!(Uint::<0, 0>::from_u32_digits_le(&[0, 0]), Ok(Uint::ZERO));
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            const DIGITS: usize = 2 * LIMBS;
+            type U = Uint<BITS, LIMBS>;
+            proptest::proptest!(|(value: U)| {
+                let digits = value.to_u32_digits_le::<DIGITS>();
+                assert_eq!(U::from_u32_digits_le(&digits), Ok(value));
+                let trimmed = value.to_u32_digits_le_trimmed_vec();
+                assert_eq!(trimmed.len(), value.bit_len().div_ceil(32));
+                assert_eq!(trimmed, digits[..trimmed.len()]);
+                assert_eq!(U::from_u32_digits_le(&trimmed), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_limb_arrays() {
+        type U = Uint<72, 2>;
+        let value = U::from_limbs([1, 0xff]);
+        let limbs: [u64; 2] = value.into();
+        assert_eq!(limbs, [1, 0xff]);
+        assert_eq!(U::try_from(limbs), Ok(value));
+        assert_eq!(
+            U::try_from([1, 0x100]),
+            Err(FromLimbsError::HighBitsSet {
+                expected: 72,
+                bit_len:  73,
+            })
+        );
+        assert_eq!(<[u64; 0]>::from(Uint::<0, 0>::ZERO), [0_u64; 0]);
+        assert_eq!(Uint::<0, 0>::try_from([]), Ok(Uint::ZERO));
+
+        assert_eq!(value.to_u128_array(), [0xff << 64 | 1]);
+        assert_eq!(value.to_u128_array(), [0xff << 64 | 1, 0]);
+        assert_eq!(U::try_from_u128_array([0xff << 64 | 1, 0]), Ok(value));
+        assert_eq!(
+            U::try_from_u128_array([1 << 72]),
+            Err(FromLimbsError::HighBitsSet {
+                expected: 72,
+                bit_len:  73,
+            })
+        );
+        assert_eq!(
+            Uint::<64, 1>::try_from_u128_array([1 << 64]),
+            Err(FromLimbsError::TooManyLimbs {
+                expected: 1,
+                got:      2,
+            })
+        );
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest::proptest!(|(value: U)| {
+                assert_eq!(U::try_from(<[u64; LIMBS]>::from(value)), Ok(value));
+                assert_eq!(U::try_from_u128_array(value.to_u128_array::<33>()), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_from_iter() {
+        type U = Uint<72, 2>;
+        assert_eq!(U::try_from_iter([]), Ok(U::ZERO));
+        assert_eq!(U::try_from_iter([5]), Ok(U::from(5)));
+        assert_eq!(U::try_from_iter([1, 0xff]), Ok(U::from_limbs([1, 0xff])));
+        assert_eq!(
+            U::try_from_iter([1, 0xff, 0, 0]),
+            Ok(U::from_limbs([1, 0xff]))
+        );
+        assert_eq!(
+            U::try_from_iter([1, 0xff, 0, 1, 0]),
+            Err(FromLimbsError::TooManyLimbs {
+                expected: 2,
+                got:      5,
+            })
+        );
+        assert_eq!(
+            U::try_from_iter([1, 0x100]),
+            Err(FromLimbsError::HighBitsSet {
+                expected: 72,
+                bit_len:  73,
+            })
+        );
+        assert_eq!([7, 1].into_iter().collect::<U>(), U::from_limbs([7, 1]));
+        assert_eq!(Uint::<0, 0>::try_from_iter([0, 0]), Ok(Uint::ZERO));
+
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest::proptest!(|(value: U)| {
+                assert_eq!(value.as_limbs().iter().copied().collect::<U>(), value);
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Value too large for this Uint")]
+    fn test_from_iter_overflow() {
+        let _ = [1, 2, 3].into_iter().collect::<Uint<128, 2>>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Value too large for this Uint")]
+    fn test_from_u128_words_odd_overflow() {
+        let _ = Uint::<64, 1>::from_u128_words_le([1 << 64]);
     }
 }
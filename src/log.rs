@@ -1,7 +1,107 @@
-#![cfg(feature = "std")]
-
 use crate::Uint;
 
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Returns $\floor{\log_2(\mathtt{self}) ⋅ 2^{\mathtt{frac\\_bits}}}$,
+    /// the binary logarithm as a fixed point number with `frac_bits`
+    /// fractional bits.
+    ///
+    /// This is computed from the bit length and the most significant 64 bits
+    /// using only integer arithmetic, so the result is the same on every
+    /// platform. It is monotonic: `a <= b` implies
+    /// `a.log2_fixed(n) <= b.log2_fixed(n)`, which makes it suitable as an
+    /// order of magnitude bucketing key. The result never exceeds the exact
+    /// value, is exact for powers of two, and the lowest of the roughly 64
+    /// significant bits may be off because the mantissa is truncated.
+    ///
+    /// Returns [`None`] if the number is zero, or if the result does not fit
+    /// in a [`u64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(0_U256.log2_fixed(8), None);
+    /// assert_eq!(1_U256.log2_fixed(8), Some(0));
+    /// assert_eq!(1024_U256.log2_fixed(8), Some(10 << 8));
+    /// // log2(3) = 1.5849..
+    /// assert_eq!(3_U256.log2_fixed(8), Some(405));
+    /// assert_eq!(U256::MAX.log2_fixed(8), Some((256 << 8) - 1));
+    /// # }
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // Intentional.
+    pub fn log2_fixed(&self, frac_bits: u32) -> Option<u64> {
+        let int = self.checked_log2()? as u64;
+        if int == 0 {
+            return Some(0);
+        }
+        if frac_bits > int.leading_zeros() {
+            return None;
+        }
+
+        // The mantissa in [1, 2) with 63 fractional bits. Each squaring
+        // yields the next bit of its logarithm. Truncating is monotonic, and a
+        // larger input either takes the same branch or sets a bit earlier, so
+        // the result is monotonic too.
+        let (bits, _) = self.most_significant_bits();
+        let mut y = bits << bits.leading_zeros();
+        let mut frac = 0;
+        for _ in 0..frac_bits {
+            let square = u128::from(y) * u128::from(y);
+            frac <<= 1;
+            if square >> 127 == 0 {
+                y = (square >> 63) as u64;
+            } else {
+                frac |= 1;
+                y = (square >> 64) as u64;
+            }
+        }
+        Some((int << frac_bits) | frac)
+    }
+
+    /// Returns $\floor{\log_{10}(\mathtt{self}) ⋅ 2^{\mathtt{frac\\_bits}}}$,
+    /// the decimal logarithm as a fixed point number with `frac_bits`
+    /// fractional bits.
+    ///
+    /// This is [`Self::log2_fixed`] multiplied by a fixed point $\log_{10} 2$,
+    /// so it is monotonic and platform independent as well. A few guard bits
+    /// are used when the range of the type allows it. The result never
+    /// exceeds the exact value, but can be one less than it.
+    ///
+    /// Returns [`None`] if [`Self::log2_fixed`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(0_U256.log10_fixed(8), None);
+    /// assert_eq!(1_U256.log10_fixed(8), Some(0));
+    /// // log10(1000) = 3
+    /// assert_eq!(1000_U256.log10_fixed(8), Some((3 << 8) - 1));
+    /// assert_eq!(1001_U256.log10_fixed(8), Some(3 << 8));
+    /// # }
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // Intentional.
+    pub fn log10_fixed(&self, frac_bits: u32) -> Option<u64> {
+        /// $\floor{\log_{10}(2) ⋅ 2^{64}}$.
+        const LOG10_2: u128 = 0x4d10_4d42_7de7_fbcc;
+        const GUARD_BITS: u32 = 8;
+        // This depends only on the type, not the value, to keep it monotonic.
+        let max_log2 = BITS.saturating_sub(1) as u64;
+        let guard = if frac_bits.saturating_add(GUARD_BITS) <= max_log2.leading_zeros() {
+            GUARD_BITS
+        } else {
+            0
+        };
+        let log2 = self.log2_fixed(frac_bits + guard)?;
+        Some(((u128::from(log2) * LOG10_2) >> (64 + guard)) as u64)
+    }
+}
+
+#[cfg(feature = "std")]
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Returns the logarithm of the number, rounded down.
     ///
@@ -24,17 +124,6 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         self.checked_log(Self::from(10))
     }
 
-    /// Returns the base 2 logarithm of the number, rounded down.
-    ///
-    /// This is equivalent to the index of the highest set bit.
-    ///
-    /// Returns None if the number is zero.
-    #[inline]
-    #[must_use]
-    pub fn checked_log2(self) -> Option<usize> {
-        self.checked_log(Self::from(2))
-    }
-
     /// Returns the logarithm of the number, rounded down.
     ///
     /// # Panics
@@ -169,6 +258,62 @@ mod tests {
         assert_eq!(U128::from(128).checked_log2(), Some(7));
     }
 
+    #[test]
+    fn test_log_fixed_monotonic() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, delta: u8, n in 0_u32..64)| {
+                let (a, b) = if a <= b { (a, b) } else { (b, a) };
+                let c = a.saturating_add(U::saturating_from(delta));
+                for (x, y) in [(a, b), (a, c)] {
+                    if let Some((x, y)) = x.log2_fixed(n).zip(y.log2_fixed(n)) {
+                        assert!(x <= y);
+                    }
+                    if let Some((x, y)) = x.log10_fixed(n).zip(y.log10_fixed(n)) {
+                        assert!(x <= y);
+                    }
+                }
+            });
+            // Around powers of two, where the integer part changes.
+            for n in [0, 8, 32, 57] {
+                for k in (1_usize..).take(BITS - 1) {
+                    let pow = U::ONE << k;
+                    let (below, at) = (pow - U::ONE, pow);
+                    if let Some((x, y)) = below.log2_fixed(n).zip(at.log2_fixed(n)) {
+                        assert!(x < y);
+                        assert_eq!(y, (k as u64) << n);
+                    }
+                    if let Some((x, y)) = below.log10_fixed(n).zip(at.log10_fixed(n)) {
+                        assert!(x <= y);
+                    }
+                }
+            }
+        });
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn test_log_fixed_approx() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, frac_bits in 0_u32..20)| {
+                prop_assume!(value > U::ZERO);
+                let scale = f64::from(1_u32 << frac_bits);
+                let log2 = (value.approx_log2() * scale).floor() as u64;
+                let log10 = (value.approx_log10() * scale).floor() as u64;
+                assert!(value.log2_fixed(frac_bits).unwrap().abs_diff(log2) <= 1);
+                assert!(value.log10_fixed(frac_bits).unwrap().abs_diff(log10) <= 1);
+            });
+        });
+        assert_eq!(U128::MAX.log2_fixed(57), Some(127 << 57 | ((1 << 57) - 1)));
+        assert_eq!(U128::MAX.log2_fixed(58), None);
+        assert_eq!(U128::ONE.log2_fixed(u32::MAX), Some(0));
+        let log10 = U128::from(10).pow(U128::from(30)).log10_fixed(16).unwrap();
+        assert!(log10 <= 30 << 16 && log10 + 1 >= 30 << 16);
+    }
+
     #[test]
     fn test_approx_log2_pow2() {
         const_for!(BITS in SIZES {
@@ -8,6 +8,68 @@ macro_rules! uint {
     }
 }
 
+/// Wrapper for [`ruint_macro::uint_hex!`]. See its documentation for details.
+#[macro_export]
+#[cfg(not(doc))] // Show the actual macro in docs.
+#[doc(hidden)]
+macro_rules! uint_hex {
+    ($($t:tt)*) => {
+        $crate::__private::ruint_macro::uint_hex_with_path! { [$crate] $($t)* }
+    }
+}
+
+/// Matches a [`Uint`](crate::Uint) against [`uint!`] literals.
+///
+/// [`Uint`](crate::Uint) can not be a const generic parameter or a `match`
+/// pattern yet, so this expands to a chain of
+/// [`const_eq`](crate::Uint::const_eq) comparisons instead. This works in
+/// `const` contexts, for example for dispatch tables keyed by chain IDs. Arms
+/// can list several literals separated by `|`, and a final `_` arm is
+/// required.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::{aliases::U256, uint_match};
+/// const fn chain_name(id: U256) -> &'static str {
+///     uint_match!(id, {
+///         1_U256 => "mainnet",
+///         0x89_U256 => "polygon",
+///         10_U256 | 8453_U256 => "op stack",
+///         _ => "unknown",
+///     })
+/// }
+///
+/// const POLYGON: &str = chain_name(U256::from_limbs([137, 0, 0, 0]));
+/// assert_eq!(POLYGON, "polygon");
+/// assert_eq!(chain_name(U256::from(8453)), "op stack");
+/// assert_eq!(chain_name(U256::from(5)), "unknown");
+/// ```
+#[macro_export]
+macro_rules! uint_match {
+    ($value:expr, {
+        $($first:literal $(| $rest:literal)* => $arm:expr,)*
+        _ => $default:expr $(,)?
+    }) => {{
+        #[allow(unused_variables)] // With only the `_` arm.
+        let value = $value;
+        $(
+            if value.const_eq(&$crate::uint!($first))
+                $(|| value.const_eq(&$crate::uint!($rest)))*
+            {
+                $arm
+            } else
+        )* {
+            $default
+        }
+    }};
+}
+
+/// Implements the operator traits for a binary operation in terms of
+/// `$fdel(self, rhs: Self) -> Self`.
+///
+/// If `$fdel_assign(&mut self, rhs: &Self)` is given, the assigning operator
+/// updates the value in place through it instead.
 macro_rules! impl_bin_op {
     ($trait:ident, $fn:ident, $trait_assign:ident, $fn_assign:ident, $fdel:ident) => {
         impl<const BITS: usize, const LIMBS: usize> $trait_assign<Uint<BITS, LIMBS>>
@@ -28,6 +90,33 @@ macro_rules! impl_bin_op {
                 *self = self.$fdel(*rhs);
             }
         }
+        impl_bin_op!(@ops $trait, $fn, $fdel);
+    };
+    (
+        $trait:ident, $fn:ident, $trait_assign:ident, $fn_assign:ident, $fdel:ident,
+        $fdel_assign:ident
+    ) => {
+        impl<const BITS: usize, const LIMBS: usize> $trait_assign<Uint<BITS, LIMBS>>
+            for Uint<BITS, LIMBS>
+        {
+            #[inline(always)]
+            #[track_caller]
+            fn $fn_assign(&mut self, rhs: Uint<BITS, LIMBS>) {
+                self.$fdel_assign(&rhs);
+            }
+        }
+        impl<const BITS: usize, const LIMBS: usize> $trait_assign<&Uint<BITS, LIMBS>>
+            for Uint<BITS, LIMBS>
+        {
+            #[inline(always)]
+            #[track_caller]
+            fn $fn_assign(&mut self, rhs: &Uint<BITS, LIMBS>) {
+                self.$fdel_assign(rhs);
+            }
+        }
+        impl_bin_op!(@ops $trait, $fn, $fdel);
+    };
+    (@ops $trait:ident, $fn:ident, $fdel:ident) => {
         impl<const BITS: usize, const LIMBS: usize> $trait<Uint<BITS, LIMBS>>
             for Uint<BITS, LIMBS>
         {
@@ -75,6 +164,15 @@ macro_rules! impl_bin_op {
     };
 }
 
+/// Counts a limb operation in [`crate::counters`] if the `counters` feature is
+/// enabled, and does nothing otherwise.
+macro_rules! count_op {
+    ($op:ident) => {
+        #[cfg(feature = "counters")]
+        $crate::counters::increment(|counts| counts.$op += 1);
+    };
+}
+
 macro_rules! assume {
     ($e:expr $(,)?) => {
         if !$e {
@@ -175,4 +273,40 @@ mod tests {
         assert_eq!(value, ruint_macro::uint_with_path!([ruint] 0x10U256));
         assert_eq!(value, ruint_macro::uint_with_path!([__ruint] 0x10U256));
     }
+
+    #[test]
+    fn test_uint_match() {
+        use crate::{
+            Uint,
+            aliases::{U0, U64},
+        };
+
+        const fn classify(value: &U64) -> u8 {
+            uint_match!(value, {
+                0_U64 => 0,
+                1_U64 | 0x2_U64 | 0b11_U64 => 1,
+                0xffff_ffff_ffff_ffff_U64 => {
+                    let max = u8::MAX;
+                    max - 253
+                },
+                _ => 3,
+            })
+        }
+        const MAX: u8 = classify(&U64::MAX);
+        const OTHER: u8 = classify(&U64::from_limbs([4]));
+        assert_eq!(MAX, 2);
+        assert_eq!(OTHER, 3);
+        for (value, expected) in [(0, 0), (1, 1), (2, 1), (3, 1), (4, 3), (u64::MAX, 2)] {
+            assert_eq!(classify(&U64::from(value)), expected);
+        }
+
+        let values = [U64::from(7)];
+        assert!(uint_match!(values[0].wrapping_add(U64::ONE), { 8_U64 => true, _ => false }));
+        assert!(uint_match!(U64::ZERO, { _ => true }));
+        assert!(uint_match!(U0::ZERO, { 0_U0 => true, _ => false, }));
+        assert!(uint_match!(Uint::<65, 2>::MAX, {
+            0x1_ffff_ffff_ffff_ffff_U65 => true,
+            _ => false,
+        }));
+    }
 }
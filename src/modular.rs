@@ -8,11 +8,27 @@ use crate::{Uint, algorithms};
 // FEATURE: Modular wrapper class, like Wrapping.
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
-    /// ⚠️ Compute $\mod{\mathtt{self}}_{\mathtt{modulus}}$.
+    /// Compute $\mod{\mathtt{self}}_{\mathtt{modulus}}$.
     ///
-    /// **Warning.** This function is not part of the stable API.
+    /// Returns zero if the modulus is zero, like the EVM `MOD` opcode. This is
+    /// the only difference with the ways to compute a remainder:
     ///
-    /// Returns zero if the modulus is zero.
+    /// | `modulus == 0` | Method                 |
+    /// |----------------|------------------------|
+    /// | panic          | `self % modulus`       |
+    /// | [`None`]       | [`Self::checked_rem`]  |
+    /// | zero           | [`Self::reduce_mod`]   |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// assert_eq!(10_U256.reduce_mod(4_U256), 2_U256);
+    /// assert_eq!(10_U256.reduce_mod(0_U256), 0_U256);
+    /// assert_eq!(10_U256.checked_rem(0_U256), None);
+    /// # }
+    /// ```
     // FEATURE: Reduce larger bit-sizes to smaller ones.
     #[inline]
     #[must_use]
@@ -26,6 +42,72 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         self
     }
 
+    /// Interprets `bytes` as a big-endian integer of any length and reduces it
+    /// modulo `modulus`.
+    ///
+    /// This is the integer step of RFC 9380 `hash_to_field`, which reduces
+    /// e.g. 48 bytes of hash output modulo a 256-bit group order. The input is
+    /// reduced one 64-bit word at a time, so it takes no wider type and does
+    /// not allocate.
+    ///
+    /// Returns zero if the modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint! {
+    /// assert_eq!(
+    ///     U64::from_be_bytes_mod(&[1, 0, 0, 0, 0, 0, 0, 0, 0], 7_U64),
+    ///     2_U64
+    /// );
+    /// assert_eq!(U64::from_be_bytes_mod(&[255; 100], 0_U64), 0_U64);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn from_be_bytes_mod(bytes: &[u8], modulus: Self) -> Self {
+        let words = bytes.rchunks(8).rev().map(|chunk| {
+            chunk
+                .iter()
+                .fold(0, |word, &byte| (word << 8) | u64::from(byte))
+        });
+        Self::from_words_mod(words, modulus)
+    }
+
+    /// Interprets `bytes` as a little-endian integer of any length and reduces
+    /// it modulo `modulus`.
+    ///
+    /// See [`Self::from_be_bytes_mod`].
+    #[must_use]
+    pub fn from_le_bytes_mod(bytes: &[u8], modulus: Self) -> Self {
+        let words = bytes.chunks(8).rev().map(|chunk| {
+            chunk
+                .iter()
+                .rev()
+                .fold(0, |word, &byte| (word << 8) | u64::from(byte))
+        });
+        Self::from_words_mod(words, modulus)
+    }
+
+    /// Horner's rule in base $2^{64}$: `acc = (acc << 64 | word) % modulus` for
+    /// each word, most significant first.
+    fn from_words_mod(words: impl Iterator<Item = u64>, modulus: Self) -> Self {
+        if modulus.is_zero() {
+            return Self::ZERO;
+        }
+        // `acc < modulus` so `acc << 64 | word` fits in `LIMBS + 1` limbs.
+        let mut numerator = [[0_u64; 2]; LIMBS];
+        let numerator = &mut numerator.as_flattened_mut()[..=LIMBS];
+        let mut acc = Self::ZERO;
+        for word in words {
+            numerator[0] = word;
+            numerator[1..].copy_from_slice(acc.as_limbs());
+            acc = modulus;
+            algorithms::div::div_inlined(numerator, &mut acc.limbs);
+        }
+        acc
+    }
+
     /// Compute $\mod{\mathtt{self} + \mathtt{rhs}}_{\mathtt{modulus}}$.
     ///
     /// Returns zero if the modulus is zero.
@@ -77,6 +159,126 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         algorithms::div::div_inlined(numerator, &mut modulus.limbs);
     }
 
+    /// Compute $\mod{2 ⋅ \mathtt{self}}_{\mathtt{modulus}}$.
+    ///
+    /// This is a shift and a conditional subtraction when `self` is already
+    /// reduced, so it is much cheaper than [`Self::mul_mod`] by two.
+    ///
+    /// Returns zero if the modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// assert_eq!(5_U256.double_mod(7_U256), 3_U256);
+    /// assert_eq!(
+    ///     (U256::MAX - 1_U256).double_mod(U256::MAX),
+    ///     U256::MAX - 2_U256
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn double_mod(self, modulus: Self) -> Self {
+        if modulus.is_zero() {
+            return Self::ZERO;
+        }
+        let value = self.reduce_mod(modulus);
+        let (result, carry) = (value.wrapping_shl(1), value.bit(BITS - 1));
+        // The true result `carry ⋅ 2^BITS + result` is less than `2 ⋅ modulus`.
+        if carry || result >= modulus {
+            result.wrapping_sub(modulus)
+        } else {
+            result
+        }
+    }
+
+    /// Compute $\mod{\mathtt{self} ⋅ 2^{-1}}_{\mathtt{modulus}}$ for an odd
+    /// modulus.
+    ///
+    /// Even values are shifted right, odd values have the modulus added first.
+    /// This is much cheaper than [`Self::mul_mod`] by the inverse of two.
+    ///
+    /// Returns zero if the modulus is zero.
+    ///
+    /// # Panics
+    ///
+    /// Two has no inverse modulo an even number. Panics in debug mode if the
+    /// modulus is even and non-zero. The result is unspecified in release
+    /// mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// assert_eq!(6_U256.halve_mod(7_U256), 3_U256);
+    /// assert_eq!(3_U256.halve_mod(7_U256), 5_U256);
+    /// assert_eq!(3_U256.halve_mod(7_U256).double_mod(7_U256), 3_U256);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn halve_mod(self, modulus: Self) -> Self {
+        if modulus.is_zero() {
+            return Self::ZERO;
+        }
+        debug_assert!(modulus.bit(0), "halve_mod requires an odd modulus");
+        let value = self.reduce_mod(modulus);
+        if !value.bit(0) {
+            return value.wrapping_shr(1);
+        }
+        let (sum, carry) = value.overflowing_add(modulus);
+        let mut result = sum.wrapping_shr(1);
+        result.set_bit(BITS - 1, carry);
+        result
+    }
+
+    /// Compute $\mod{\mathtt{self} ⋅ 2^{\mathtt{n}}}_{\mathtt{modulus}}$.
+    ///
+    /// Shifts by whole limbs at the cost of one short division each, and
+    /// doubles for the remaining bits. It takes time linear in `n`, see
+    /// [`Self::pow_mod`] for very large exponents.
+    ///
+    /// Returns zero if the modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// assert_eq!(1_U256.shl_mod(4, 11_U256), 5_U256);
+    /// let m = 1000_U256;
+    /// assert_eq!(
+    ///     3_U256.shl_mod(300, m),
+    ///     3_U256.mul_mod(2_U256.pow_mod(300_U256, m), m)
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn shl_mod(self, n: usize, modulus: Self) -> Self {
+        if modulus.is_zero() {
+            return Self::ZERO;
+        }
+        let mut acc = self.reduce_mod(modulus);
+        if n >= 64 {
+            // `acc < modulus` so `acc << 64` fits in `LIMBS + 1` limbs.
+            let mut numerator = [[0_u64; 2]; LIMBS];
+            let numerator = &mut numerator.as_flattened_mut()[..=LIMBS];
+            for _ in 0..n / 64 {
+                numerator[0] = 0;
+                numerator[1..].copy_from_slice(acc.as_limbs());
+                acc = modulus;
+                algorithms::div::div_inlined(numerator, &mut acc.limbs);
+            }
+        }
+        for _ in 0..n % 64 {
+            acc = acc.double_mod(modulus);
+        }
+        acc
+    }
+
     /// Compute $\mod{\mathtt{self} ⋅ \mathtt{rhs}}_{\mathtt{modulus}}$.
     ///
     /// Returns zero if the modulus is zero.
@@ -101,6 +303,40 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         Self::div_rem_double_bits(product, modulus);
     }
 
+    /// Compute $\mod{\mathtt{hi} ⋅ 2^{\mathtt{BITS}} +
+    /// \mathtt{lo}}_{\mathtt{modulus}}$.
+    ///
+    /// Reduces a double width value, like the halves returned by
+    /// [`widening_mul`](Self::widening_mul), without a larger [`Uint`] type.
+    ///
+    /// Returns zero if the modulus is zero.
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// uint! {
+    /// assert_eq!(U64::reduce_wide(5_U64, 1_U64, 7_U64), 0_U64);
+    /// assert_eq!(U64::reduce_wide(U64::MAX, U64::MAX, 1000_U64), 455_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reduce_wide(lo: Self, hi: Self, mut modulus: Self) -> Self {
+        if modulus.is_zero() {
+            return Self::ZERO;
+        }
+        let_double_bits!(numerator);
+        numerator[..LIMBS].copy_from_slice(lo.as_limbs());
+        let (offset, shift) = (BITS / 64, BITS % 64);
+        for (i, &limb) in hi.as_limbs().iter().enumerate() {
+            numerator[offset + i] |= limb << shift;
+            if shift != 0 && offset + i + 1 < numerator.len() {
+                numerator[offset + i + 1] |= limb >> (64 - shift);
+            }
+        }
+        Self::div_rem_double_bits(numerator, &mut modulus);
+        modulus
+    }
+
     #[inline]
     fn div_rem_double_bits(numerator: &mut [u64], modulus: &mut Self) {
         assume!(numerator.len() == crate::nlimbs(BITS * 2));
@@ -141,6 +377,152 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         algorithms::inv_mod(self, modulus)
     }
 
+    /// Solves the linear congruence $\mod{a ⋅ x}_m = \mod{b}_m$ for $x$.
+    ///
+    /// Returns the smallest solution $x$ and the spacing $m / \gcd(a, m)$
+    /// between consecutive solutions, so all solutions are $x + k ⋅
+    /// \mathtt{spacing}$. The returned $x$ is less than the spacing.
+    ///
+    /// Returns [`None`] if `m` is zero, or if $\gcd(a, m)$ does not divide $b$
+    /// so there is no solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint! {
+    /// // 4x ≡ 6 (mod 10) is solved by x = 4, 9.
+    /// assert_eq!(
+    ///     U64::solve_linear_mod(4_U64, 6_U64, 10_U64),
+    ///     Some((4_U64, 5_U64))
+    /// );
+    /// assert_eq!(U64::solve_linear_mod(4_U64, 5_U64, 10_U64), None);
+    /// // 0x ≡ 0 is solved by everything.
+    /// assert_eq!(
+    ///     U64::solve_linear_mod(0_U64, 10_U64, 10_U64),
+    ///     Some((0_U64, 1_U64))
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn solve_linear_mod(a: Self, b: Self, m: Self) -> Option<(Self, Self)> {
+        if m.is_zero() {
+            return None;
+        }
+        let (a, b) = (a.reduce_mod(m), b.reduce_mod(m));
+        // `gcd(0, m) = m`, so `a = 0` only has the solutions for `b = 0`.
+        let g = a.gcd(m);
+        let (b, rem) = b.div_rem(g);
+        if !rem.is_zero() {
+            return None;
+        }
+        let spacing = m / g;
+        if spacing == Self::ONE {
+            return Some((Self::ZERO, spacing));
+        }
+        // `a / g` is coprime to the spacing, so the inverse exists.
+        let inv = (a / g).inv_mod(spacing)?;
+        Some((b.mul_mod(inv, spacing), spacing))
+    }
+
+    /// Computes the multiplicative order of `self` modulo `modulus`, i.e. the
+    /// smallest $k > 0$ such that $\mod{\mathtt{self}^k}_{\mathtt{modulus}} =
+    /// 1$.
+    ///
+    /// Factoring is out of scope, so the caller supplies the factorization of
+    /// a multiple of the order as a list of distinct primes $p_i$ with their
+    /// exponents $e_i$, representing $\prod_i p_i^{e_i}$. The order of the
+    /// multiplicative group, $\varphi(\mathtt{modulus})$, is always a valid
+    /// choice. For a prime modulus $p$ it is $p - 1$.
+    ///
+    /// Returns [`None`] if `modulus` is zero, if `self` is not coprime to
+    /// `modulus` and hence has no multiplicative order, or if the product does
+    /// not fit or is not a multiple of the order. If the $p_i$ are not all
+    /// prime the result is a multiple of the order, but may not be the order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint! {
+    /// // The group modulo 13 has order 12 = 2^2 * 3.
+    /// let factors = [(2_U64, 2), (3_U64, 1)];
+    /// assert_eq!(3_U64.multiplicative_order(13_U64, &factors), Some(3_U64));
+    /// assert_eq!(2_U64.multiplicative_order(13_U64, &factors), Some(12_U64));
+    /// assert_eq!(0_U64.multiplicative_order(13_U64, &factors), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn multiplicative_order(
+        &self,
+        modulus: Self,
+        modulus_factorization: &[(Self, u32)],
+    ) -> Option<Self> {
+        let (mut order, one) = self.group_order(modulus, modulus_factorization)?;
+        for &(prime, exponent) in modulus_factorization {
+            for _ in 0..exponent {
+                let candidate = order / prime;
+                if self.pow_mod(candidate, modulus) != one {
+                    break;
+                }
+                order = candidate;
+            }
+        }
+        Some(order)
+    }
+
+    /// Returns `true` if `self` generates the multiplicative group modulo
+    /// `modulus`.
+    ///
+    /// The `factorization` must be that of the order of the group,
+    /// $\varphi(\mathtt{modulus})$, in the same form as for
+    /// [`Self::multiplicative_order`]. Returns `false` if `self` is not coprime
+    /// to `modulus`, or if the group order does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint! {
+    /// let factors = [(2_U64, 2), (3_U64, 1)];
+    /// assert!(2_U64.is_primitive_root(13_U64, &factors));
+    /// assert!(!3_U64.is_primitive_root(13_U64, &factors));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_primitive_root(&self, modulus: Self, factorization: &[(Self, u32)]) -> bool {
+        let Some((order, one)) = self.group_order(modulus, factorization) else {
+            return false;
+        };
+        factorization
+            .iter()
+            .all(|&(prime, exponent)| exponent == 0 || self.pow_mod(order / prime, modulus) != one)
+    }
+
+    /// Multiplies out the factorization and checks that it is a multiple of
+    /// the order of `self`. Also returns one reduced modulo `modulus`.
+    fn group_order(&self, modulus: Self, factorization: &[(Self, u32)]) -> Option<(Self, Self)> {
+        if modulus.is_zero() || self.gcd(modulus) != Self::ONE {
+            return None;
+        }
+        let mut order = Self::ONE;
+        for &(prime, exponent) in factorization {
+            if prime <= Self::ONE {
+                return None;
+            }
+            for _ in 0..exponent {
+                order = order.checked_mul(prime)?;
+            }
+        }
+        let one = Self::ONE.reduce_mod(modulus);
+        if self.pow_mod(order, modulus) != one {
+            return None;
+        }
+        Some((order, one))
+    }
+
     /// Montgomery multiplication.
     ///
     /// Requires `self` and `other` to be less than `modulus`.
@@ -209,12 +591,275 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     }
 }
 
+/// Reduces a big-endian byte stream modulo a [`Uint`] as it arrives.
+///
+/// This computes the same value as [`Uint::from_be_bytes_mod`] on the
+/// concatenation of all absorbed input, without buffering it. Bytes are
+/// collected into 64-bit words and folded in with Horner's rule, so the state
+/// is one reduced value plus a partial word and it never allocates.
+///
+/// Like [`Uint::from_be_bytes_mod`], the result is zero if the modulus is
+/// zero.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::{ModAccumulator, uint, aliases::U256};
+/// # uint! {
+/// let p = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001_U256;
+/// let mut acc = ModAccumulator::new(p);
+/// acc.absorb_bytes(b"domain separator");
+/// acc.absorb_uint(&42_U256);
+/// acc.absorb_bytes(&[0xff; 100]);
+/// let mut input = b"domain separator".to_vec();
+/// input.extend(42_U256.to_be_bytes::<32>());
+/// input.extend([0xff; 100]);
+/// assert_eq!(acc.finalize(), U256::from_be_bytes_mod(&input, p));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ModAccumulator<const BITS: usize, const LIMBS: usize> {
+    modulus: Uint<BITS, LIMBS>,
+    /// The reduced value of all complete words so far, `< modulus`.
+    acc:     Uint<BITS, LIMBS>,
+    /// Bytes not yet folded into `acc`, most significant first.
+    word:    u64,
+    /// The number of bytes in `word`, less than 8.
+    pending: u32,
+}
+
+impl<const BITS: usize, const LIMBS: usize> ModAccumulator<BITS, LIMBS> {
+    /// Creates an empty accumulator reducing modulo `modulus`.
+    #[inline]
+    #[must_use]
+    pub const fn new(modulus: Uint<BITS, LIMBS>) -> Self {
+        Self {
+            modulus,
+            acc: Uint::ZERO,
+            word: 0,
+            pending: 0,
+        }
+    }
+
+    /// Appends `bytes` to the input.
+    pub fn absorb_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.word = (self.word << 8) | u64::from(byte);
+            self.pending += 1;
+            if self.pending == 8 {
+                self.fold(self.word, 64);
+                self.word = 0;
+                self.pending = 0;
+            }
+        }
+    }
+
+    /// Appends `value` to the input as [`Uint::BYTES`] big-endian bytes, like
+    /// [`Uint::to_be_bytes`].
+    pub fn absorb_uint(&mut self, value: &Uint<BITS, LIMBS>) {
+        for i in (0..Uint::<BITS, LIMBS>::BYTES).rev() {
+            self.absorb_bytes(&[value.byte(i)]);
+        }
+    }
+
+    /// Returns the input reduced modulo the modulus.
+    #[inline]
+    #[must_use]
+    pub fn finalize(mut self) -> Uint<BITS, LIMBS> {
+        if self.pending > 0 {
+            self.fold(self.word, 8 * self.pending);
+        }
+        self.acc
+    }
+
+    /// `acc = (acc << bits | word) % modulus` for `0 < bits <= 64` and
+    /// `word < 2^bits`.
+    fn fold(&mut self, word: u64, bits: u32) {
+        if self.modulus.is_zero() {
+            return;
+        }
+        // `acc < modulus` so the shifted value fits in `LIMBS + 1` limbs.
+        let mut numerator = [[0_u64; 2]; LIMBS];
+        let numerator = &mut numerator.as_flattened_mut()[..=LIMBS];
+        numerator[0] = word;
+        for (i, &limb) in self.acc.as_limbs().iter().enumerate() {
+            let shifted = u128::from(limb) << bits;
+            numerator[i] |= shifted as u64;
+            numerator[i + 1] = (shifted >> 64) as u64;
+        }
+        self.acc = self.modulus;
+        algorithms::div::div_inlined(numerator, &mut self.acc.limbs);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{aliases::U64, const_for, nlimbs};
+    use crate::{
+        aliases::{U64, U256, U384, U512},
+        const_for, nlimbs,
+    };
     use proptest::{prop_assume, proptest, test_runner::Config};
 
+    #[test]
+    fn test_from_bytes_mod() {
+        // Reference values from arbitrary precision integer arithmetic.
+        let bytes48: [u8; 48] = core::array::from_fn(|i| i as u8 + 1);
+        let bytes96: [u8; 96] = core::array::from_fn(|i| (i * 37 + 11) as u8);
+        uint! {
+            // secp256k1 group order and BLS12-381 scalar field.
+            let n = 0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141_U256;
+            let r = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001_U256;
+            assert_eq!(
+                U256::from_be_bytes_mod(&bytes48, n),
+                0x1259f2ae83a986c3c506758609bd3a765bbb0c1aac771fe2995103d81d694c20_U256
+            );
+            assert_eq!(
+                U256::from_le_bytes_mod(&bytes48, n),
+                0x5d5a49454895d1e9787fcca32b9dbe046fcbf32cc284c2ab6463f539e876f6a0_U256
+            );
+            assert_eq!(
+                U256::from_be_bytes_mod(&bytes48, r),
+                0x4b60c20a2d263ac2c5122ea5388a4a05c1c485bc8643fdc70d5fdd0bb18c86f3_U256
+            );
+            assert_eq!(
+                U256::from_be_bytes_mod(&bytes96, r),
+                0x66f6cae80f641fc7d82094b80ac3751c4bfce2aa7fccd8480461ae6a8f69a7aa_U256
+            );
+            assert_eq!(
+                U256::from_le_bytes_mod(&bytes96, r),
+                0x3f988b24f1e3b71ebe5205679836fdcc1f23f148e9be86dbb3979a288887ce49_U256
+            );
+
+            // BLS12-381 base field.
+            let p = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab_U384;
+            assert_eq!(
+                U384::from_be_bytes_mod(&bytes96, p),
+                0x17e91fd30898ccc3aba1b8ce474a2169e3129efe434464832d88f5865f024d7b768480c5161710c787941166844be624_U384
+            );
+            assert_eq!(
+                U384::from_le_bytes_mod(&bytes96, p),
+                0x00ab003a2de216e8fa526572eb561b54fde7fab1984d402c11eaaf8b57fee6c15fd63d37ebbc9ba34edc0cb698551a8c_U384
+            );
+        }
+        assert_eq!(U256::from_be_bytes_mod(&[], U256::MAX), U256::ZERO);
+        assert_eq!(U256::from_be_bytes_mod(&bytes48, U256::ZERO), U256::ZERO);
+        assert_eq!(U256::from_be_bytes_mod(&bytes48, U256::ONE), U256::ZERO);
+        assert_eq!(
+            Uint::<0, 0>::from_le_bytes_mod(&bytes48, Uint::ZERO),
+            Uint::ZERO
+        );
+
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, m: U, pad in 0_usize..20)| {
+                // Leading zeros do not change the value.
+                let mut be = vec![0; pad];
+                be.extend(value.to_be_bytes_vec());
+                let mut le = value.to_le_bytes_vec();
+                le.resize(le.len() + pad, 0);
+                assert_eq!(U::from_be_bytes_mod(&be, m), value.reduce_mod(m));
+                assert_eq!(U::from_le_bytes_mod(&le, m), value.reduce_mod(m));
+            });
+        });
+        proptest!(|(bytes: [u8; 64], m: U256)| {
+            let wide = U512::from_be_bytes(bytes).reduce_mod(U512::from(m));
+            assert_eq!(U256::from_be_bytes_mod(&bytes, m), U256::from(wide));
+            let wide = U512::from_le_bytes(bytes).reduce_mod(U512::from(m));
+            assert_eq!(U256::from_le_bytes_mod(&bytes, m), U256::from(wide));
+        });
+    }
+
+    #[test]
+    fn test_mod_accumulator() {
+        assert_eq!(
+            ModAccumulator::new(Uint::<0, 0>::ZERO).finalize(),
+            Uint::ZERO
+        );
+        let mut acc = ModAccumulator::new(U64::ZERO);
+        acc.absorb_bytes(&[1, 2, 3]);
+        assert_eq!(acc.finalize(), U64::ZERO);
+
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(bytes: Vec<u8>, m: U, split in 0_usize..100, value: U)| {
+                let expected = U::from_be_bytes_mod(&bytes, m);
+
+                let mut whole = ModAccumulator::new(m);
+                whole.absorb_bytes(&bytes);
+                assert_eq!(whole.finalize(), expected);
+
+                let mut bytewise = ModAccumulator::new(m);
+                for byte in &bytes {
+                    bytewise.absorb_bytes(core::slice::from_ref(byte));
+                }
+                assert_eq!(bytewise.finalize(), expected);
+
+                let (head, tail) = bytes.split_at(split.min(bytes.len()));
+                let mut parts = ModAccumulator::new(m);
+                parts.absorb_bytes(head);
+                parts.absorb_uint(&value);
+                parts.absorb_bytes(tail);
+                let mut joined = head.to_vec();
+                joined.extend(value.to_be_bytes_vec());
+                joined.extend(tail);
+                assert_eq!(parts.finalize(), U::from_be_bytes_mod(&joined, m));
+            });
+        });
+    }
+
+    #[test]
+    fn test_double_halve_mod() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, m: U)| {
+                assert_eq!(a.double_mod(m), a.add_mod(a, m));
+                assert_eq!(a.shl_mod(0, m), a.reduce_mod(m));
+                assert_eq!(a.shl_mod(1, m), a.double_mod(m));
+                if BITS >= 2 {
+                    assert_eq!(a.double_mod(m), a.mul_mod(U::from(2), m));
+                }
+                if m.bit(0) {
+                    let half = a.halve_mod(m);
+                    assert!(half < m);
+                    assert_eq!(half.double_mod(m), a.reduce_mod(m));
+                    if BITS >= 2 {
+                        if let Some(inv) = U::from(2).inv_mod(m) {
+                            assert_eq!(half, a.mul_mod(inv, m));
+                        }
+                    }
+                }
+            });
+            proptest!(|(a: U, m: U, n in 0_usize..300)| {
+                let mut expected = a.reduce_mod(m);
+                for _ in 0..n {
+                    expected = expected.add_mod(expected, m);
+                }
+                assert_eq!(a.shl_mod(n, m), expected);
+            });
+            assert_eq!(U::MAX.double_mod(U::ZERO), U::ZERO);
+            assert_eq!(U::MAX.halve_mod(U::ZERO), U::ZERO);
+            assert_eq!(U::MAX.shl_mod(1000, U::ZERO), U::ZERO);
+            assert_eq!(U::MAX.double_mod(U::MAX), U::ZERO);
+            assert_eq!(U::MAX.halve_mod(U::MAX), U::ZERO);
+        });
+        uint! {
+            // secp256k1 base field.
+            let p = 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f_U256;
+            let inv2 = 2_U256.inv_mod(p).unwrap();
+            let mut x = p - 1_U256;
+            for _ in 0..100 {
+                assert_eq!(x.halve_mod(p), x.mul_mod(inv2, p));
+                assert_eq!(x.double_mod(p), x.mul_mod(2_U256, p));
+                x = x.mul_mod(x, p).add_mod(7_U256, p);
+            }
+            assert_eq!(x.shl_mod(1000, p), x.mul_mod(2_U256.pow_mod(1000_U256, p), p));
+        }
+    }
+
     #[test]
     fn test_commutative() {
         const_for!(BITS in SIZES {
@@ -316,6 +961,99 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_solve_linear_mod() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, m: U)| {
+                let solution = U::solve_linear_mod(a, b, m);
+                if m.is_zero() {
+                    assert_eq!(solution, None);
+                } else if let Some((x, spacing)) = solution {
+                    assert!(x < spacing);
+                    assert_eq!(spacing, m / a.gcd(m));
+                    assert_eq!(a.mul_mod(x, m), b.reduce_mod(m));
+                } else {
+                    assert!(!(b.reduce_mod(m) % a.gcd(m)).is_zero());
+                }
+                // Degenerate cases.
+                if !m.is_zero() {
+                    assert_eq!(U::solve_linear_mod(a, U::ZERO, m).map(|s| s.0), Some(U::ZERO));
+                    let zero = U::solve_linear_mod(U::ZERO, b, m);
+                    assert_eq!(zero.is_some(), b.reduce_mod(m).is_zero());
+                }
+            });
+        });
+        // Exhaustive for small moduli.
+        for m in 1..40_u64 {
+            for a in 0..m {
+                for b in 0..m {
+                    let expected = (0..m).find(|&x| (a * x) % m == b).map(|x| {
+                        let spacing = (1..=m).find(|&s| (a * (x + s)) % m == b).unwrap();
+                        (U64::from(x), U64::from(spacing))
+                    });
+                    let solution = U64::solve_linear_mod(U64::from(a), U64::from(b), U64::from(m));
+                    assert_eq!(solution, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reduce_wide() {
+        fn check<
+            const BITS: usize,
+            const LIMBS: usize,
+            const WIDE: usize,
+            const WIDE_LIMBS: usize,
+        >(
+            lo: Uint<BITS, LIMBS>,
+            hi: Uint<BITS, LIMBS>,
+            modulus: Uint<BITS, LIMBS>,
+        ) {
+            type W<const B: usize, const L: usize> = Uint<B, L>;
+            assert_eq!(WIDE, 2 * BITS);
+            let wide = (W::<WIDE, WIDE_LIMBS>::from(hi) << BITS) | W::from(lo);
+            let expected = if modulus.is_zero() {
+                Uint::ZERO
+            } else {
+                Uint::from(wide % W::from(modulus))
+            };
+            assert_eq!(Uint::reduce_wide(lo, hi, modulus), expected);
+            assert_eq!(
+                Uint::reduce_wide(lo, Uint::ZERO, modulus),
+                lo.reduce_mod(modulus)
+            );
+            assert_eq!(Uint::reduce_wide(lo, hi, Uint::ONE), Uint::ZERO);
+            if BITS > 0 {
+                let max = Uint::<BITS, LIMBS>::MAX;
+                assert_eq!(
+                    Uint::reduce_wide(lo, hi, max),
+                    Uint::from(wide % W::from(max))
+                );
+            }
+            let product: W<WIDE, WIDE_LIMBS> = lo.widening_mul(hi);
+            let (product_lo, product_hi) =
+                (Uint::wrapping_from(product), Uint::from(product >> BITS));
+            assert_eq!(
+                Uint::reduce_wide(product_lo, product_hi, modulus),
+                lo.mul_mod(hi, modulus)
+            );
+        }
+
+        proptest!(|(lo: U64, hi: U64, modulus: U64)| check::<64, 1, 128, 2>(lo, hi, modulus));
+        proptest!(|(lo: Uint<65, 2>, hi: Uint<65, 2>, modulus: Uint<65, 2>)| {
+            check::<65, 2, 130, 3>(lo, hi, modulus);
+        });
+        proptest!(|(lo: Uint<100, 2>, hi: Uint<100, 2>, modulus: Uint<100, 2>)| {
+            check::<100, 2, 200, 4>(lo, hi, modulus);
+        });
+        proptest!(|(lo: U256, hi: U256, modulus: U256)| check::<256, 4, 512, 8>(lo, hi, modulus));
+        check::<0, 0, 0, 0>(Uint::ZERO, Uint::ZERO, Uint::ZERO);
+        check::<1, 1, 2, 1>(Uint::ONE, Uint::ONE, Uint::ONE);
+    }
+
     #[test]
     fn test_mul_redc() {
         const_for!(BITS in NON_ZERO if BITS >= 16 {
@@ -360,4 +1098,112 @@ mod tests {
             });
         });
     }
+
+    /// Returns the factorization of Euler's totient of `n` and the totient.
+    fn totient_factorization(n: u64) -> (Vec<(U64, u32)>, u64) {
+        let mut totient = n;
+        let mut rest = n;
+        let mut p = 2;
+        while p * p <= rest {
+            if rest % p == 0 {
+                totient = totient / p * (p - 1);
+                while rest % p == 0 {
+                    rest /= p;
+                }
+            }
+            p += 1;
+        }
+        if rest > 1 {
+            totient = totient / rest * (rest - 1);
+        }
+        let mut factors = Vec::new();
+        let mut rest = totient;
+        for p in 2..=totient {
+            let mut exponent = 0;
+            while rest % p == 0 {
+                rest /= p;
+                exponent += 1;
+            }
+            if exponent > 0 {
+                factors.push((U64::from(p), exponent));
+            }
+        }
+        (factors, totient)
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        for modulus in 1..120_u64 {
+            let (factors, totient) = totient_factorization(modulus);
+            let m = U64::from(modulus);
+            let one = U64::ONE.reduce_mod(m);
+            for a in 0..modulus {
+                let a = U64::from(a);
+                let expected = (1..=modulus)
+                    .find(|&k| a.pow_mod(U64::from(k), m) == one)
+                    .filter(|_| a.gcd(m) == U64::ONE);
+                assert_eq!(a.multiplicative_order(m, &factors), expected.map(U64::from));
+                assert_eq!(a.is_primitive_root(m, &factors), expected == Some(totient));
+            }
+        }
+        assert_eq!(U64::ONE.multiplicative_order(U64::ZERO, &[]), None);
+        // Not a multiple of the order.
+        assert_eq!(
+            U64::from(2).multiplicative_order(U64::from(13), &[(U64::from(3), 1)]),
+            None
+        );
+        assert_eq!(
+            U64::from(2).multiplicative_order(U64::from(13), &[(U64::ONE, 1)]),
+            None
+        );
+        assert_eq!(
+            U64::MAX.multiplicative_order(U64::from(13), &[(U64::MAX, 2)]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bn254_two_adic_root() {
+        use crate::aliases::U256;
+        let modulus = uint!(
+            21888242871839275222246405745257275088548364400416034343698204186575808495617_U256
+        );
+        let factors = [
+            (2_u128, 28),
+            (3, 2),
+            (13, 1),
+            (29, 1),
+            (983, 1),
+            (11003, 1),
+            (237073, 1),
+            (405928799, 1),
+            (1670836401704629, 1),
+            (13818364434197438864469338081, 1),
+        ]
+        .map(|(p, e)| (U256::from(p), e));
+        let generator = U256::from(5);
+        assert!(generator.is_primitive_root(modulus, &factors));
+        assert_eq!(
+            generator.multiplicative_order(modulus, &factors),
+            Some(modulus - U256::ONE)
+        );
+
+        let root = uint!(
+            19103219067921713944291392827692070036145651957329286315305642004821462161904_U256
+        );
+        assert_eq!(
+            root,
+            generator.pow_mod((modulus - U256::ONE) >> 28, modulus)
+        );
+        assert!(!root.is_primitive_root(modulus, &factors));
+        assert_eq!(
+            root.multiplicative_order(modulus, &factors),
+            Some(U256::ONE << 28)
+        );
+        assert_eq!(
+            root.multiplicative_order(modulus, &factors[..1]),
+            Some(U256::ONE << 28)
+        );
+        assert_eq!(root.multiplicative_order(modulus, &factors[1..]), None);
+    }
 }
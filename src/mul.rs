@@ -60,11 +60,19 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Computes `self * rhs`, wrapping around at the boundary of the type.
     #[inline(always)]
     #[must_use]
-    pub fn wrapping_mul(self, rhs: Self) -> Self {
+    pub fn wrapping_mul(mut self, rhs: Self) -> Self {
+        self.wrapping_mul_assign(&rhs);
+        self
+    }
+
+    /// Computes `self *= rhs` in place, wrapping around at the boundary of the
+    /// type. This is what `*=` uses.
+    #[inline]
+    pub fn wrapping_mul_assign(&mut self, rhs: &Self) {
         let mut result = Self::ZERO;
         algorithms::addmul_n(&mut result.limbs, self.as_limbs(), rhs.as_limbs());
         result.apply_mask();
-        result
+        *self = result;
     }
 
     /// Computes the inverse modulo $2^{\mathtt{BITS}}$ of `self`, returning
@@ -145,6 +153,140 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 
         result
     }
+
+    /// Calculates the complete product `self * rhs` into a result type that
+    /// is at least as wide as the sum of the operand widths.
+    ///
+    /// Unlike [`Self::widening_mul`], the result may be wider than needed,
+    /// e.g. a Q64.64 price as [`U128`](crate::aliases::U128) times a
+    /// [`U256`](crate::aliases::U256) balance into a
+    /// [`U384`](crate::aliases::U384). The product is computed on the limbs of
+    /// the operands directly, without first extending them to the result
+    /// width.
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `BO < BITS + B2` or if `LO` is not the number of
+    /// limbs for `BO` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let price = 0x1_8000_0000_0000_0000_U128; // 1.5 in Q64.64
+    /// let balance = U256::MAX;
+    /// let value: U384 = price.widening_mul_into(balance);
+    /// assert_eq!(
+    ///     value >> 64_usize,
+    ///     U384::from(balance) + U384::from(balance >> 1_usize)
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn widening_mul_into<const BO: usize, const LO: usize, const B2: usize, const L2: usize>(
+        self,
+        rhs: Uint<B2, L2>,
+    ) -> Uint<BO, LO> {
+        const { Self::assert_widening_into(BO, LO, B2) }
+        let mut result = Uint::<BO, LO>::ZERO;
+        let overflow = algorithms::addmul(&mut result.limbs, self.as_limbs(), rhs.as_limbs());
+        debug_assert!(!overflow);
+        result
+    }
+
+    #[inline]
+    #[track_caller]
+    const fn assert_widening_into(bits: usize, limbs: usize, rhs_bits: usize) {
+        assert!(bits >= BITS + rhs_bits, "BO must be at least BITS + B2");
+        assert!(limbs == nlimbs(bits), "LO must be equal to nlimbs(BO)");
+    }
+
+    /// Computes `self += other * limb` in place and returns the carry out.
+    ///
+    /// This is the inner step of schoolbook multiplication and of Montgomery
+    /// and Barrett reduction. The carry is the part of the sum that does not
+    /// fit in `BITS` bits, so that
+    ///
+    /// $$
+    /// \mathsf{self'} + \mathsf{carry} ⋅ 2^{\mathsf{BITS}} = \mathsf{self} +
+    /// \mathsf{other} ⋅ \mathsf{limb}
+    /// $$
+    ///
+    /// It always fits a [`u64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U128};
+    /// # uint!{
+    /// let mut acc = 1_U128;
+    /// assert_eq!(acc.addmul_limb(&U128::MAX, 3), 2);
+    /// assert_eq!(acc, U128::MAX - 1_U128);
+    ///
+    /// let mut acc = U128::MAX;
+    /// assert_eq!(acc.addmul_limb(&U128::MAX, u64::MAX), u64::MAX);
+    /// assert_eq!(acc, U128::MAX << 64_usize);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn addmul_limb(&mut self, other: &Self, limb: u64) -> u64 {
+        if BITS == 0 {
+            return 0;
+        }
+        let carry = algorithms::addmul_nx1(&mut self.limbs, &other.limbs, limb);
+        let shift = BITS % 64;
+        if shift == 0 {
+            return carry;
+        }
+        // The bits above `BITS` in the top limb are the low part of the carry.
+        let high = (carry << (64 - shift)) | (self.limbs[LIMBS - 1] >> shift);
+        self.apply_mask();
+        high
+    }
+
+    /// Computes `self -= other * limb` in place and returns the borrow.
+    ///
+    /// The result wraps around and the borrow is the number of times
+    /// $2^{\mathsf{BITS}}$ was added back, so that
+    ///
+    /// $$
+    /// \mathsf{self'} - \mathsf{borrow} ⋅ 2^{\mathsf{BITS}} = \mathsf{self} -
+    /// \mathsf{other} ⋅ \mathsf{limb}
+    /// $$
+    ///
+    /// It always fits a [`u64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U128};
+    /// # uint!{
+    /// let mut acc = 10_U128;
+    /// assert_eq!(acc.submul_limb(&3_U128, 3), 0);
+    /// assert_eq!(acc, 1_U128);
+    /// assert_eq!(acc.submul_limb(&U128::MAX, u64::MAX), u64::MAX);
+    /// assert_eq!(acc, 1_U128 << 64_usize);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn submul_limb(&mut self, other: &Self, limb: u64) -> u64 {
+        if BITS == 0 {
+            return 0;
+        }
+        let borrow = algorithms::submul_nx1(&mut self.limbs, &other.limbs, limb);
+        let shift = BITS % 64;
+        if shift == 0 {
+            return borrow;
+        }
+        // The borrow out of the top limb counts multiples of `2^(64 * LIMBS)`,
+        // the bits above `BITS` have to be taken back off it. The true result
+        // fits a `u64`, so the wrapping intermediate is exact.
+        let high = (borrow << (64 - shift)).wrapping_sub(self.limbs[LIMBS - 1] >> shift);
+        self.apply_mask();
+        high
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Product<Self> for Uint<BITS, LIMBS> {
@@ -173,7 +315,14 @@ impl<'a, const BITS: usize, const LIMBS: usize> Product<&'a Self> for Uint<BITS,
     }
 }
 
-impl_bin_op!(Mul, mul, MulAssign, mul_assign, wrapping_mul);
+impl_bin_op!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    wrapping_mul,
+    wrapping_mul_assign
+);
 
 #[cfg(test)]
 mod tests {
@@ -239,6 +388,87 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_widening_mul_into() {
+        macro_rules! check {
+            ($($a:literal x $b:literal => $out:literal),* $(,)?) => {$({
+                const LA: usize = nlimbs($a);
+                const LB: usize = nlimbs($b);
+                const LO: usize = nlimbs($out);
+                type A = Uint<$a, LA>;
+                type B = Uint<$b, LB>;
+                type Out = Uint<$out, LO>;
+                let expected = Out::from(A::MAX) * Out::from(B::MAX);
+                assert_eq!(A::MAX.widening_mul_into::<$out, LO, $b, LB>(B::MAX), expected);
+                proptest!(|(a: A, b: B)| {
+                    let product: Out = a.widening_mul_into(b);
+                    assert_eq!(product, Out::from(a).wrapping_mul(Out::from(b)));
+                    assert_eq!(product, b.widening_mul_into::<$out, LO, $a, LA>(a));
+                });
+            })*};
+        }
+        check!(
+            0 x 0 => 0,
+            0 x 64 => 64,
+            1 x 1 => 2,
+            7 x 13 => 20,
+            7 x 13 => 64,
+            64 x 64 => 128,
+            64 x 64 => 200,
+            65 x 100 => 165,
+            65 x 100 => 192,
+            128 x 256 => 384,
+            256 x 256 => 512,
+            255 x 257 => 1000,
+        );
+    }
+
+    #[test]
+    fn test_addmul_limb() {
+        macro_rules! check {
+            ($($bits:literal),* $(,)?) => {$({
+                const LIMBS: usize = nlimbs($bits);
+                const WIDE: usize = $bits + 128;
+                type U = Uint<$bits, LIMBS>;
+                type W = Uint<WIDE, { nlimbs(WIDE) }>;
+                let check_add = |acc: U, other: U, limb: u64| {
+                    let mut result = acc;
+                    let carry = result.addmul_limb(&other, limb);
+                    let expected = W::from(acc) + W::from(other) * W::from(limb);
+                    assert_eq!(W::from(result) + (W::from(carry) << $bits), expected);
+                    carry
+                };
+                let check_sub = |acc: U, other: U, limb: u64| {
+                    let mut result = acc;
+                    let borrow = result.submul_limb(&other, limb);
+                    let expected = W::from(acc) + (W::from(borrow) << $bits);
+                    assert_eq!(W::from(result) + W::from(other) * W::from(limb), expected);
+                    borrow
+                };
+                let carry = check_add(U::MAX, U::MAX, u64::MAX);
+                let borrow = check_sub(U::ZERO, U::MAX, u64::MAX);
+                if $bits >= 64 {
+                    // The carry and borrow take up the whole extra limb.
+                    assert_eq!(carry, u64::MAX);
+                    assert_eq!(borrow, u64::MAX);
+                    assert_eq!(check_add(U::ZERO, U::MAX, u64::MAX), u64::MAX - 1);
+                    assert_eq!(check_sub(U::MAX, U::MAX, u64::MAX), u64::MAX - 1);
+                }
+                if $bits > 0 {
+                    assert_ne!(carry, 0);
+                    assert_ne!(borrow, 0);
+                    assert_eq!(check_add(U::MAX, U::ONE, 1), 1);
+                    assert_eq!(check_sub(U::ZERO, U::ONE, 1), 1);
+                }
+                proptest!(|(acc: U, other: U, limb: u64)| {
+                    check_add(acc, other, limb);
+                    check_sub(acc, other, limb);
+                });
+            })*};
+        }
+        check!(0, 1, 63, 64, 65, 127, 128, 200, 256);
+    }
+
     #[test]
     fn test_widening_mul() {
         // Left hand side
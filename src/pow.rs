@@ -1,5 +1,16 @@
 use crate::Uint;
 
+/// The powers of ten that fit in a limb, for [`Uint::pow10`].
+const POW10: [u64; 20] = {
+    let mut table = [1; 20];
+    let mut i = 1;
+    while i < table.len() {
+        table[i] = table[i - 1] * 10;
+        i += 1;
+    }
+    table
+};
+
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Raises self to the power of `exp`.
     ///
@@ -52,6 +63,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         if BITS == 0 {
             return (self, false);
         }
+        if exp.bit_len() <= 64 {
+            return self.overflowing_pow_u64(exp.limbs[0]);
+        }
 
         // Exponentiation by squaring
         let mut overflow = false;
@@ -98,6 +112,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         if BITS == 0 {
             return self;
         }
+        if exp.bit_len() <= 64 {
+            return self.pow_u64(exp.limbs[0]);
+        }
 
         // Exponentiation by squaring
         let mut result = Self::ONE;
@@ -114,6 +131,163 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         result
     }
 
+    /// Raises self to the power of a `u64` exponent, wrapping around on
+    /// overflow.
+    ///
+    /// This is the same as [`Self::wrapping_pow`], but avoids the
+    /// multi-limb exponent.
+    #[inline]
+    #[must_use]
+    pub fn pow_u64(mut self, mut exp: u64) -> Self {
+        if BITS == 0 {
+            return self;
+        }
+
+        // Exponentiation by squaring
+        let mut result = Self::ONE;
+        while exp != 0 {
+            if exp & 1 != 0 {
+                result = result.wrapping_mul(self);
+            }
+            exp >>= 1;
+            if exp != 0 {
+                self = self.wrapping_mul(self);
+            }
+        }
+        result
+    }
+
+    /// Raises self to the power of a `u64` exponent, returning [`None`] if the
+    /// result would overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_pow_u64(self, exp: u64) -> Option<Self> {
+        match self.overflowing_pow_u64(exp) {
+            (x, false) => Some(x),
+            (_, true) => None,
+        }
+    }
+
+    /// Raises self to the power of a `u64` exponent and if the result would
+    /// overflow.
+    ///
+    /// This is the same as [`Self::overflowing_pow`], but avoids the
+    /// multi-limb exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// assert_eq!(
+    ///     36_U64.overflowing_pow_u64(12),
+    ///     (0x41c21cb8e1000000_U64, false)
+    /// );
+    /// assert_eq!(
+    ///     36_U64.overflowing_pow_u64(13),
+    ///     (0x3f4c09ffa4000000_U64, true)
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_pow_u64(mut self, mut exp: u64) -> (Self, bool) {
+        if BITS == 0 {
+            return (self, false);
+        }
+
+        // Exponentiation by squaring
+        let mut overflow = false;
+        let mut base_overflow = false;
+        let mut result = Self::ONE;
+        while exp != 0 {
+            if exp & 1 != 0 {
+                let (r, o) = result.overflowing_mul(self);
+                result = r;
+                overflow |= o | base_overflow;
+            }
+            exp >>= 1;
+            if exp != 0 {
+                let (s, o) = self.overflowing_mul(self);
+                self = s;
+                base_overflow |= o;
+            }
+        }
+        (result, overflow)
+    }
+
+    /// Returns $2^n$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if $n ≥ \mathtt{BITS}$. Use [`Self::checked_shl`] on
+    /// [`Self::ONE`] for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(U256::pow2(0), 1_U256);
+    /// assert_eq!(U256::pow2(255), 1_U256 << 255);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn pow2(n: usize) -> Self {
+        assert!(n < BITS, "Uint::pow2: exponent is out of range");
+        let mut result = Self::ZERO;
+        result.limbs[n / 64] = 1 << (n % 64);
+        result
+    }
+
+    /// Returns $10^n$, or [`None`] if it does not fit.
+    ///
+    /// This multiplies by precomputed powers of ten a limb at a time, so it is
+    /// much faster than [`Self::pow`] for use in decimal scaling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(U256::pow10(18), Some(1_000_000_000_000_000_000_U256));
+    /// assert_eq!(U64::pow10(19), Some(10_000_000_000_000_000_000_U64));
+    /// assert_eq!(U64::pow10(20), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn pow10(n: usize) -> Option<Self> {
+        // `10^n >= 2^n`, so this also bounds the loop below.
+        if BITS == 0 || (n >= BITS && n > 0) {
+            return None;
+        }
+        let chunk = POW10.len() - 1;
+        let mut result = Self::ZERO;
+        result.limbs[0] = POW10[n % chunk];
+        let mut remaining = n / chunk;
+        while remaining > 0 {
+            let mut carry = 0;
+            let mut i = 0;
+            while i < LIMBS {
+                let product = result.limbs[i] as u128 * POW10[chunk] as u128 + carry as u128;
+                result.limbs[i] = product as u64;
+                carry = (product >> 64) as u64;
+                i += 1;
+            }
+            if carry != 0 {
+                return None;
+            }
+            remaining -= 1;
+        }
+        if result.limbs[LIMBS - 1] > Self::MASK {
+            return None;
+        }
+        Some(result)
+    }
+
     /// Construct from double precision binary logarithm.
     ///
     /// # Examples
@@ -207,4 +381,88 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_pow_u64() {
+        const_for!(BITS in NON_ZERO if BITS >= 64 {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(b: U, e in 0_u64..300)| {
+                let expected = repeat_n(b, e as usize).fold((U::ONE, false), |(acc, o), b| {
+                    let (acc, o2) = acc.overflowing_mul(b);
+                    (acc, o | o2)
+                });
+                assert_eq!(b.overflowing_pow(U::from(e)), expected);
+                assert_eq!(b.overflowing_pow_u64(e), expected);
+                assert_eq!(b.pow_u64(e), expected.0);
+                assert_eq!(b.checked_pow_u64(e), b.checked_pow(U::from(e)));
+            });
+            // Too slow.
+            if LIMBS > 8 {
+                return;
+            }
+            proptest!(|(b: U, e: U)| {
+                // Exponents wider than a limb take the general path.
+                let (x, o) = b.overflowing_pow(e);
+                assert_eq!(b.wrapping_pow(e), x);
+                if b > U::ONE && e.bit_len() > 64 {
+                    assert!(o);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_pow2() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            for n in 0..BITS {
+                assert_eq!(U::pow2(n), U::ONE << n);
+            }
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Uint::pow2: exponent is out of range")]
+    fn test_pow2_overflow() {
+        let _ = Uint::<256, 4>::pow2(256);
+    }
+
+    #[test]
+    fn test_pow10() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let ten = U::try_from(10).ok();
+            let mut expected = U::try_from(1).ok();
+            for n in 0..=BITS + 1 {
+                assert_eq!(U::pow10(n), expected, "10^{n}");
+                expected = expected.zip(ten).and_then(|(x, ten)| x.checked_mul(ten));
+            }
+            assert_eq!(U::pow10(usize::MAX), None);
+        });
+    }
+
+    #[test]
+    fn test_pow10_max() {
+        use crate::aliases::{U64, U128, U256, U512};
+
+        // The largest `k` with `10^k` fitting in each width.
+        assert_eq!(U64::pow10(19), Some(U64::from(10_u64.pow(19))));
+        assert_eq!(U64::pow10(20), None);
+        assert_eq!(U128::pow10(38), Some(U128::from(10_u128.pow(38))));
+        assert_eq!(U128::pow10(39), None);
+        assert_eq!(U256::pow10(77), U256::from(10).checked_pow(U256::from(77)));
+        assert!(U256::pow10(77).is_some());
+        assert_eq!(U256::pow10(78), None);
+        assert!(U512::pow10(154).is_some());
+        assert_eq!(U512::pow10(155), None);
+
+        const SCALE: U256 = match U256::pow10(18) {
+            Some(x) => x,
+            None => panic!(),
+        };
+        assert_eq!(SCALE, U256::from(10_u64.pow(18)));
+    }
 }
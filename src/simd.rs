@@ -0,0 +1,112 @@
+//! Vectorized limb loops for the `portable-simd` feature.
+//!
+//! The limbs are processed in 256-bit chunks of [`u64x4`] with a scalar loop
+//! for the remaining tail. The scalar implementations in [`crate::bits`] and
+//! [`crate::cmp`] remain the default and serve as the reference in tests.
+
+#![cfg(feature = "portable-simd")]
+
+use core::simd::{num::SimdUint, u64x4};
+
+/// The number of limbs in a vector. Integers with fewer limbs than this use
+/// the scalar loops.
+pub(crate) const LANES: usize = 4;
+
+macro_rules! zip_assign {
+    ($name:ident, $op:tt, $assign_op:tt) => {
+        #[inline]
+        pub(crate) fn $name(lhs: &mut [u64], rhs: &[u64]) {
+            debug_assert_eq!(lhs.len(), rhs.len());
+            let (lhs_chunks, lhs_tail) = lhs.as_chunks_mut::<LANES>();
+            let (rhs_chunks, rhs_tail) = rhs.as_chunks::<LANES>();
+            for (l, r) in lhs_chunks.iter_mut().zip(rhs_chunks) {
+                *l = (u64x4::from_array(*l) $op u64x4::from_array(*r)).to_array();
+            }
+            for (l, r) in lhs_tail.iter_mut().zip(rhs_tail) {
+                *l $assign_op *r;
+            }
+        }
+    };
+}
+
+zip_assign!(bitand_assign, &, &=);
+zip_assign!(bitor_assign, |, |=);
+zip_assign!(bitxor_assign, ^, ^=);
+
+/// Inverts all the bits. The caller is responsible for masking the top limb.
+#[inline]
+pub(crate) fn not_assign(limbs: &mut [u64]) {
+    let (chunks, tail) = limbs.as_chunks_mut::<LANES>();
+    for chunk in chunks {
+        *chunk = (!u64x4::from_array(*chunk)).to_array();
+    }
+    for limb in tail {
+        *limb = !*limb;
+    }
+}
+
+/// Branch-free equality: ORs together the differences of all limbs.
+#[inline]
+pub(crate) fn eq(lhs: &[u64], rhs: &[u64]) -> bool {
+    debug_assert_eq!(lhs.len(), rhs.len());
+    let (lhs_chunks, lhs_tail) = lhs.as_chunks::<LANES>();
+    let (rhs_chunks, rhs_tail) = rhs.as_chunks::<LANES>();
+    let mut diff = u64x4::splat(0);
+    for (l, r) in lhs_chunks.iter().zip(rhs_chunks) {
+        diff |= u64x4::from_array(*l) ^ u64x4::from_array(*r);
+    }
+    let mut tail = 0;
+    for (l, r) in lhs_tail.iter().zip(rhs_tail) {
+        tail |= l ^ r;
+    }
+    (diff.reduce_or() | tail) == 0
+}
+
+#[inline]
+#[allow(clippy::cast_possible_truncation)] // Bounded by the bit length.
+pub(crate) fn count_ones(limbs: &[u64]) -> usize {
+    let (chunks, tail) = limbs.as_chunks::<LANES>();
+    let mut ones = u64x4::splat(0);
+    for chunk in chunks {
+        ones += u64x4::from_array(*chunk).count_ones();
+    }
+    let tail: u32 = tail.iter().map(|limb| limb.count_ones()).sum();
+    ones.reduce_sum() as usize + tail as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Uint, const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_simd_matches_scalar() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                // The inherent `const fn`s always take the scalar path.
+                assert_eq!(a & b, a.bitand(b));
+                assert_eq!(a | b, a.bitor(b));
+                assert_eq!(a ^ b, a.bitxor(b));
+                assert_eq!(!a, a.not());
+                assert_eq!(!a, U::MAX.wrapping_sub(a));
+                assert_eq!(
+                    a.count_ones(),
+                    a.as_limbs().iter().map(|l| l.count_ones() as usize).sum::<usize>()
+                );
+                assert_eq!(a.const_eq(&b), a.as_limbs() == b.as_limbs());
+                assert!(a.const_eq(&a));
+            });
+        });
+    }
+
+    #[test]
+    fn test_const_eval() {
+        type U = Uint<1000, 16>;
+        const ONES: usize = U::MAX.count_ones();
+        const EQ: bool = U::MAX.const_eq(&U::ZERO.not());
+        assert_eq!(ONES, 1000);
+        assert_eq!(EQ, U::MAX.const_eq(&!U::ZERO));
+    }
+}
@@ -1,7 +1,7 @@
 #![allow(clippy::missing_inline_in_public_items)] // allow format functions
 
-use crate::{Uint, base_convert::BaseConvertError};
-use core::{fmt, str::FromStr};
+use crate::{Uint, algorithms::DoubleWord, base_convert::BaseConvertError};
+use core::{fmt, ops::Range, str::FromStr};
 
 /// Error for [`from_str_radix`](Uint::from_str_radix).
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -14,12 +14,20 @@ pub enum ParseError {
 
     /// Error from [`Uint::from_base_be`].
     BaseConvertError(BaseConvertError),
+
+    /// [`Uint::from_str_prefixed`] was given a string without a radix prefix.
+    MissingPrefix,
+
+    /// The input is longer than the limit passed to
+    /// [`Uint::from_str_with_limit`].
+    ///
+    /// `.0` is the limit in bytes.
+    InputTooLong(usize),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ParseError {
+impl core::error::Error for ParseError {
     #[inline]
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             Self::BaseConvertError(e) => Some(e),
             _ => None,
@@ -27,6 +35,19 @@ impl std::error::Error for ParseError {
     }
 }
 
+impl ParseError {
+    /// Moves the position of an overflow `offset` bytes further into the
+    /// input, for errors from parsing a suffix of it.
+    const fn offset(self, offset: usize) -> Self {
+        match self {
+            Self::BaseConvertError(BaseConvertError::Overflow(index)) => {
+                Self::BaseConvertError(BaseConvertError::Overflow(index + offset))
+            }
+            error => error,
+        }
+    }
+}
+
 impl From<BaseConvertError> for ParseError {
     #[inline]
     fn from(value: BaseConvertError) -> Self {
@@ -40,6 +61,50 @@ impl fmt::Display for ParseError {
             Self::BaseConvertError(e) => e.fmt(f),
             Self::InvalidDigit(c) => write!(f, "invalid digit: {c}"),
             Self::InvalidRadix(r) => write!(f, "invalid radix {r}, up to 64 is supported"),
+            Self::MissingPrefix => f.write_str("missing radix prefix, expected 0x, 0o or 0b"),
+            Self::InputTooLong(max) => write!(f, "input is longer than {max} bytes"),
+        }
+    }
+}
+
+/// How [`Uint::parse_many`] treats items that are empty after trimming.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EmptyItems {
+    /// Report empty items as errors.
+    Error,
+
+    /// Skip empty items, e.g. blank lines and a trailing separator.
+    Skip,
+}
+
+/// Error for [`Uint::parse_many`] and [`Uint::parse_all`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseManyError {
+    /// Index of the item, counting empty items.
+    pub index: usize,
+
+    /// Byte range of the trimmed item in the input.
+    pub span: Range<usize>,
+
+    /// Error parsing the item, or [`None`] if it is empty.
+    pub error: Option<ParseError>,
+}
+
+impl core::error::Error for ParseManyError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.error.as_ref().map(|e| e as _)
+    }
+}
+
+impl fmt::Display for ParseManyError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { index, span, .. } = self;
+        write!(f, "item {index} at bytes {}..{}: ", span.start, span.end)?;
+        match &self.error {
+            Some(e) => e.fmt(f),
+            None => f.write_str("empty item"),
         }
     }
 }
@@ -60,69 +125,694 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     // FEATURE: Support proper unicode. Ignore zero-width spaces, joiners, etc.
     // Recognize digits from other alphabets.
     pub fn from_str_radix(src: &str, radix: u64) -> Result<Self, ParseError> {
+        Self::parse_radix(src.as_bytes(), radix)
+    }
+
+    /// Parses `bytes` as [`Self::from_str_radix`].
+    fn parse_radix(bytes: &[u8], radix: u64) -> Result<Self, ParseError> {
         if radix > 64 {
             return Err(ParseError::InvalidRadix(radix));
         }
-        let mut err = None;
-        let digits = src.chars().filter_map(|c| {
-            if err.is_some() {
-                return None;
-            }
-            let digit = if radix <= 36 {
-                // Case insensitive 0—9, a—z.
-                match c {
-                    '0'..='9' => u64::from(c) - u64::from('0'),
-                    'a'..='z' => u64::from(c) - u64::from('a') + 10,
-                    'A'..='Z' => u64::from(c) - u64::from('A') + 10,
-                    '_' => return None, // Ignored character.
-                    _ => {
-                        err = Some(ParseError::InvalidDigit(c));
-                        return None;
-                    }
-                }
+        if radix < 2 {
+            return Err(BaseConvertError::InvalidBase(radix).into());
+        }
+        if radix == 10 {
+            return Self::parse_dec(bytes).map_err(|error| match error {
+                DecError::Overflow => Self::overflow(bytes, 10),
+                DecError::Invalid(index) => invalid_dec_digit(char_at(bytes, index)),
+            });
+        }
+        Self::parse_digits(bytes, radix)
+    }
+
+    /// Parses `bytes` one digit at a time as [`Self::from_str_radix`] for a
+    /// valid `radix`.
+    fn parse_digits(bytes: &[u8], radix: u64) -> Result<Self, ParseError> {
+        let overflow = |Overflow| Self::overflow(bytes, radix);
+        let mut result = Self::ZERO;
+        let mut used = 0;
+        let mut chunk = Chunk::new(radix);
+        for (i, &byte) in bytes.iter().enumerate() {
+            // Digits are ASCII, so a multi-byte character is always invalid.
+            let c = if byte.is_ascii() {
+                char::from(byte)
             } else {
-                // The Base-64 alphabets
-                match c {
-                    'A'..='Z' => u64::from(c) - u64::from('A'),
-                    'a'..='f' => u64::from(c) - u64::from('a') + 26,
-                    '0'..='9' => u64::from(c) - u64::from('0') + 52,
-                    '+' | '-' => 62,
-                    '/' | ',' | '_' => 63,
-                    '=' | '\r' | '\n' => return None, // Ignored characters.
-                    _ => {
-                        err = Some(ParseError::InvalidDigit(c));
-                        return None;
-                    }
+                char_at(bytes, i)
+            };
+            let digit = match parse_digit(c, radix) {
+                Ok(Some(digit)) if digit < radix => digit,
+                Ok(None) => continue,
+                // Digits before the error may already overflow, which takes
+                // precedence.
+                Ok(Some(digit)) => {
+                    chunk.flush(&mut result, &mut used).map_err(overflow)?;
+                    return Err(BaseConvertError::InvalidDigit(digit, radix).into());
+                }
+                Err(err) => {
+                    chunk.flush(&mut result, &mut used).map_err(overflow)?;
+                    return Err(err);
                 }
             };
-            Some(digit)
-        });
-        let value = Self::from_base_be(radix, digits)?;
-        err.map_or(Ok(value), Err)
+            chunk
+                .push(&mut result, &mut used, digit)
+                .map_err(overflow)?;
+        }
+        chunk.flush(&mut result, &mut used).map_err(overflow)?;
+        Ok(result)
+    }
+
+    /// Returns the overflow error for `bytes` in `radix`, which are known to
+    /// overflow.
+    ///
+    /// The fast paths only detect overflow per word of digits, so this parses
+    /// again one digit at a time to find the exact digit.
+    #[cold]
+    fn overflow(bytes: &[u8], radix: u64) -> ParseError {
+        let mut result = Self::ZERO;
+        let mut used = 0;
+        for (i, &byte) in bytes.iter().enumerate() {
+            if let Ok(Some(digit)) = parse_digit(char::from(byte), radix) {
+                if digit < radix && result.push_digit(&mut used, radix, digit).is_err() {
+                    return BaseConvertError::Overflow(i).into();
+                }
+            }
+        }
+        BaseConvertError::Overflow(bytes.len()).into()
+    }
+
+    /// Parses a decimal string of at most [`Self::max_dec_len`] ASCII
+    /// digits.
+    ///
+    /// Longer strings are rejected before any arithmetic, even if they only
+    /// have leading zeros, which bounds the work spent on untrusted input.
+    /// Unlike [`Self::from_str_radix`], `_` separators are not accepted.
+    ///
+    /// # Errors
+    ///
+    /// * [`BaseConvertError::Overflow`] if the string is longer than
+    ///   [`Self::max_dec_len`], at that byte offset, or the value does not fit.
+    /// * [`ParseError::InvalidDigit`] if a character is not an ASCII digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{BaseConvertError, ParseError, aliases::U8};
+    /// assert_eq!(U8::from_dec_str_fixed("255"), Ok(U8::from(255)));
+    /// assert_eq!(U8::from_dec_str_fixed("007"), Ok(U8::from(7)));
+    /// let overflow = |i| Err(ParseError::BaseConvertError(BaseConvertError::Overflow(i)));
+    /// assert_eq!(U8::from_dec_str_fixed("256"), overflow(2));
+    /// assert_eq!(U8::from_dec_str_fixed("0007"), overflow(3));
+    /// assert_eq!(
+    ///     U8::from_dec_str_fixed("1_0"),
+    ///     Err(ParseError::InvalidDigit('_'))
+    /// );
+    /// ```
+    pub fn from_dec_str_fixed(src: &str) -> Result<Self, ParseError> {
+        if src.len() > Self::max_dec_len() {
+            return Err(BaseConvertError::Overflow(Self::max_dec_len()).into());
+        }
+        let overflow = |Overflow| Self::overflow(src.as_bytes(), 10);
+        let mut result = Self::ZERO;
+        let mut used = 0;
+        let mut chunk = Chunk::new(10);
+        for c in src.chars() {
+            let digit = c.to_digit(10).ok_or(ParseError::InvalidDigit(c))?;
+            chunk
+                .push(&mut result, &mut used, digit.into())
+                .map_err(overflow)?;
+        }
+        chunk.flush(&mut result, &mut used).map_err(overflow)?;
+        Ok(result)
+    }
+
+    /// Computes `self = self * radix + digit`, where `used` is the number of
+    /// limbs in use.
+    ///
+    /// This accumulates directly into the limbs, without allocating. Only the
+    /// limbs in use are multiplied, so overflow is reported at the digit where
+    /// it happens.
+    #[inline]
+    pub(crate) fn push_digit(
+        &mut self,
+        used: &mut usize,
+        radix: u64,
+        digit: u64,
+    ) -> Result<(), Overflow> {
+        let mut carry = digit;
+        for limb in &mut self.limbs[..*used] {
+            (*limb, carry) = u128::muladd(*limb, radix, carry).split();
+        }
+        if carry != 0 {
+            if *used == LIMBS {
+                return Err(Overflow);
+            }
+            self.limbs[*used] = carry;
+            *used += 1;
+        }
+        if *used == LIMBS && LIMBS != 0 && self.limbs[LIMBS - 1] > Self::MASK {
+            return Err(Overflow);
+        }
+        Ok(())
+    }
+
+    /// Parse a string with a required radix prefix: `0x` for hexadecimal, `0o`
+    /// for octal or `0b` for binary. The prefix is case insensitive.
+    ///
+    /// The digits are parsed as in [`Self::from_str_radix`], so `_` separators
+    /// are ignored and an empty digit string is zero.
+    ///
+    /// Hexadecimal numbers can have a binary exponent, like hexadecimal float
+    /// literals: `0xHpN` is the hexadecimal integer `H` shifted left by the
+    /// decimal number `N` of bits. This is an integer construct only, so
+    /// fractional digits and negative exponents are rejected.
+    ///
+    /// # Errors
+    ///
+    /// * [`ParseError::MissingPrefix`] if the string does not start with one of
+    ///   the prefixes.
+    /// * [`BaseConvertError::Overflow`] if a binary exponent shifts set bits
+    ///   out of the [`Uint`], at the offset of the `p`.
+    /// * [`ParseError::InvalidDigit`] if a binary exponent contains a character
+    ///   other than an ASCII digit or `_`, or is empty.
+    /// * Otherwise as [`Self::from_str_radix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{ParseError, aliases::{U64, U256}};
+    /// assert_eq!(U64::from_str_prefixed("0x1F"), Ok(U64::from(31)));
+    /// assert_eq!(U64::from_str_prefixed("0O17"), Ok(U64::from(15)));
+    /// assert_eq!(U64::from_str_prefixed("0B1_01"), Ok(U64::from(5)));
+    /// assert_eq!(U64::from_str_prefixed("31"), Err(ParseError::MissingPrefix));
+    /// assert_eq!(U256::from_str_prefixed("0x1p255"), Ok(U256::ONE << 255));
+    /// assert_eq!(U64::from_str_prefixed("0x3P4"), Ok(U64::from(48)));
+    /// assert!(U64::from_str_prefixed("0x1p64").is_err());
+    /// ```
+    pub fn from_str_prefixed(src: &str) -> Result<Self, ParseError> {
+        Self::parse_prefixed(src.as_bytes())
+    }
+
+    /// Parses `bytes` as [`Self::from_str_prefixed`].
+    fn parse_prefixed(bytes: &[u8]) -> Result<Self, ParseError> {
+        let (digits, radix) = split_prefix(bytes).ok_or(ParseError::MissingPrefix)?;
+        if radix == 16 {
+            if let Some(index) = digits.iter().position(|&b| b == b'p' || b == b'P') {
+                return Self::from_hex_power(&digits[..index], &digits[index..])
+                    .map_err(|error| error.offset(2));
+            }
+        }
+        Self::parse_radix(digits, radix).map_err(|error| error.offset(2))
+    }
+
+    /// Parses hexadecimal `mantissa` shifted left by `exponent`, which still
+    /// starts with the `p` or `P`.
+    fn from_hex_power(mantissa: &[u8], exponent: &[u8]) -> Result<Self, ParseError> {
+        let separator_index = mantissa.len();
+        let mantissa = Self::parse_radix(mantissa, 16)?;
+        let separator = exponent.first().map_or('p', |&b| char::from(b));
+        let mut shift = None::<usize>;
+        for (i, &byte) in exponent.iter().enumerate().skip(1) {
+            if byte == b'_' {
+                continue;
+            }
+            if !byte.is_ascii_digit() {
+                return Err(ParseError::InvalidDigit(char_at(exponent, i)));
+            }
+            let digit = byte - b'0';
+            // Saturate, any exponent this large overflows a non-zero mantissa.
+            let shift = shift.get_or_insert(0);
+            *shift = shift.saturating_mul(10).saturating_add(usize::from(digit));
+        }
+        let shift = shift.ok_or(ParseError::InvalidDigit(separator))?;
+        if mantissa.is_zero() {
+            return Ok(mantissa);
+        }
+        if shift >= BITS || mantissa.bit_len() > BITS - shift {
+            return Err(BaseConvertError::Overflow(separator_index).into());
+        }
+        Ok(mantissa << shift)
+    }
+
+    /// Parses a byte string like [`FromStr`], for parsers that work on bytes.
+    ///
+    /// Only ASCII characters are valid in numbers, so the bytes are parsed
+    /// directly without validating UTF-8 first. A non-ASCII character is
+    /// reported as an invalid digit like in [`FromStr`], and bytes that are
+    /// not UTF-8 as an invalid [`char::REPLACEMENT_CHARACTER`] digit.
+    ///
+    /// # Errors
+    ///
+    /// As [`FromStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{ParseError, aliases::U64};
+    /// assert_eq!(U64::parse_ascii(b"0x1f"), Ok(U64::from(31)));
+    /// assert_eq!(U64::parse_ascii(b"1_000"), Ok(U64::from(1000)));
+    /// assert_eq!(
+    ///     U64::parse_ascii(b"1\xff"),
+    ///     Err(ParseError::InvalidDigit('\u{fffd}'))
+    /// );
+    /// ```
+    pub fn parse_ascii(bytes: &[u8]) -> Result<Self, ParseError> {
+        match Self::parse_prefixed(bytes) {
+            Err(ParseError::MissingPrefix) => Self::parse_radix(bytes, 10),
+            result => result,
+        }
+    }
+
+    /// Parses like [`FromStr`], but first rejects strings longer than
+    /// `max_len` bytes.
+    ///
+    /// The length check is done before looking at any digit, so untrusted
+    /// input costs no more than `max_len` bytes worth of parsing.
+    ///
+    /// # Errors
+    ///
+    /// * [`ParseError::InputTooLong`] if `src` is longer than `max_len` bytes.
+    /// * Otherwise as [`FromStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{ParseError, aliases::U256};
+    /// let limit = 2 + U256::max_dec_len();
+    /// assert_eq!(U256::from_str_with_limit("0x1f", limit), Ok(U256::from(31)));
+    /// let long = "0".repeat(1 << 20);
+    /// assert_eq!(
+    ///     U256::from_str_with_limit(&long, limit),
+    ///     Err(ParseError::InputTooLong(limit))
+    /// );
+    /// ```
+    pub fn from_str_with_limit(src: &str, max_len: usize) -> Result<Self, ParseError> {
+        if src.len() > max_len {
+            return Err(ParseError::InputTooLong(max_len));
+        }
+        src.parse()
+    }
+
+    /// Parse a decimal string. No prefix is accepted.
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::from_str_radix`] with radix 10.
+    pub fn from_dec_str(src: &str) -> Result<Self, ParseError> {
+        Self::from_str_radix(src, 10)
+    }
+
+    /// Parses decimal ASCII bytes, for trusted pipelines that do not validate
+    /// UTF-8 first.
+    ///
+    /// This is [`Self::from_dec_str`] on bytes: `_` separators are ignored
+    /// and an empty input is zero. Any non-ASCII byte is reported as an
+    /// invalid [`char::REPLACEMENT_CHARACTER`] digit.
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::from_dec_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{ParseError, aliases::U256};
+    /// let value = U256::from_dec_bytes(b"1_000_000_000_000_000_000_000_000_000_000");
+    /// assert_eq!(value, Ok(U256::from(10).pow(U256::from(30))));
+    /// assert_eq!(
+    ///     U256::from_dec_bytes(b"12 "),
+    ///     Err(ParseError::InvalidDigit(' '))
+    /// );
+    /// assert_eq!(
+    ///     U256::from_dec_bytes(b"1\xff"),
+    ///     Err(ParseError::InvalidDigit('\u{fffd}'))
+    /// );
+    /// ```
+    pub fn from_dec_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::parse_dec(bytes).map_err(|error| match error {
+            DecError::Overflow => Self::overflow(bytes, 10),
+            DecError::Invalid(index) => {
+                let byte = bytes[index];
+                invalid_dec_digit(if byte.is_ascii() {
+                    char::from(byte)
+                } else {
+                    char::REPLACEMENT_CHARACTER
+                })
+            }
+        })
+    }
+
+    /// Parses decimal digits, ignoring `_`.
+    ///
+    /// Digits are accumulated into a `u64`, eight at a time where possible,
+    /// which is pushed into the limbs with a single multiply-add once it holds
+    /// 16 to 19 digits.
+    ///
+    /// As in [`Self::from_str_radix`], overflow of the digits before an
+    /// invalid byte takes precedence over it.
+    ///
+    /// Unlike formatting, this does not split the digits by divide-and-conquer.
+    /// Combining halves with one multiplication only pays off with
+    /// subquadratic multiplication. With the schoolbook one, it is about three
+    /// times slower at 16384 bits than pushing a word of digits at a time,
+    /// which is quadratic as well but only multiplies the limbs in use.
+    fn parse_dec(bytes: &[u8]) -> Result<Self, DecError> {
+        let mut result = Self::ZERO;
+        let mut used = 0;
+        let mut chunk = 0_u64;
+        let mut digits = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if digits > 11 {
+                result
+                    .push_digit(&mut used, POW10[digits], chunk)
+                    .map_err(|Overflow| DecError::Overflow)?;
+                (chunk, digits) = (0, 0);
+            }
+            if let Some(eight) = bytes.get(i..i + 8).and_then(parse_eight_digits) {
+                chunk = chunk * 100_000_000 + eight;
+                digits += 8;
+                i += 8;
+                continue;
+            }
+            match bytes[i] {
+                byte @ b'0'..=b'9' => {
+                    chunk = chunk * 10 + u64::from(byte - b'0');
+                    digits += 1;
+                }
+                b'_' => {}
+                _ => {
+                    result
+                        .push_digit(&mut used, POW10[digits], chunk)
+                        .map_err(|Overflow| DecError::Overflow)?;
+                    return Err(DecError::Invalid(i));
+                }
+            }
+            i += 1;
+        }
+        result
+            .push_digit(&mut used, POW10[digits], chunk)
+            .map_err(|Overflow| DecError::Overflow)?;
+        Ok(result)
+    }
+
+    /// Parse a hexadecimal string with an optional `0x` or `0X` prefix.
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::from_str_radix`] with radix 16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U64;
+    /// assert_eq!(U64::from_hex_str("0xff"), U64::from_hex_str("FF"));
+    /// assert!(U64::from_hex_str("0o1").is_err());
+    /// ```
+    pub fn from_hex_str(src: &str) -> Result<Self, ParseError> {
+        let digits = src
+            .strip_prefix("0x")
+            .or_else(|| src.strip_prefix("0X"))
+            .unwrap_or(src);
+        Self::from_str_radix(digits, 16).map_err(|error| error.offset(src.len() - digits.len()))
+    }
+
+    /// Parses the items of `s` separated by `sep`, e.g. the lines of a file of
+    /// test vectors.
+    ///
+    /// Whitespace around each item is trimmed and the items are parsed like
+    /// [`FromStr`]. Items that are empty after trimming, including the one
+    /// after a trailing separator, are handled as `empty` requests. Errors
+    /// carry the index and byte span of the item and do not stop the
+    /// iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{EmptyItems, aliases::U64};
+    /// let mut items = U64::parse_many("1, 0x2,, 3x", ',', EmptyItems::Error);
+    /// assert_eq!(items.next(), Some(Ok(U64::from(1))));
+    /// assert_eq!(items.next(), Some(Ok(U64::from(2))));
+    /// let error = items.next().unwrap().unwrap_err();
+    /// assert_eq!(error.to_string(), "item 2 at bytes 7..7: empty item");
+    /// let error = items.next().unwrap().unwrap_err();
+    /// assert_eq!((error.index, error.span), (3, 9..11));
+    /// assert_eq!(items.next(), None);
+    /// ```
+    pub fn parse_many(
+        s: &str,
+        sep: char,
+        empty: EmptyItems,
+    ) -> impl Iterator<Item = Result<Self, ParseManyError>> + '_ {
+        let mut offset = 0;
+        s.split(sep).enumerate().filter_map(move |(index, item)| {
+            let start = offset + item.len() - item.trim_start().len();
+            offset += item.len() + sep.len_utf8();
+            let item = item.trim();
+            let span = start..start + item.len();
+            if item.is_empty() {
+                return match empty {
+                    EmptyItems::Error => Some(Err(ParseManyError {
+                        index,
+                        span,
+                        error: None,
+                    })),
+                    EmptyItems::Skip => None,
+                };
+            }
+            Some(item.parse().map_err(|error| ParseManyError {
+                index,
+                span,
+                error: Some(error),
+            }))
+        })
+    }
+
+    /// Parses all items of `s` separated by `sep` like [`Self::parse_many`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error for the first item that fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{EmptyItems, aliases::U256};
+    /// let vectors = "0x01\n0x02\n\n0x03\n";
+    /// let values = U256::parse_all(vectors, '\n', EmptyItems::Skip).unwrap();
+    /// assert_eq!(values, [1, 2, 3].map(U256::from));
+    /// let error = U256::parse_all(vectors, '\n', EmptyItems::Error).unwrap_err();
+    /// assert_eq!(error.index, 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_all(
+        s: &str,
+        sep: char,
+        empty: EmptyItems,
+    ) -> Result<alloc::vec::Vec<Self>, ParseManyError> {
+        Self::parse_many(s, sep, empty).collect()
+    }
+}
+
+/// Digits accumulated in a `u64` before they are pushed into the limbs, so
+/// the limbs are multiplied once per word of digits rather than per digit.
+struct Chunk {
+    radix: u64,
+    value: u64,
+    /// `radix` to the number of digits in `value`.
+    power: u64,
+}
+
+impl Chunk {
+    #[inline]
+    const fn new(radix: u64) -> Self {
+        Self {
+            radix,
+            value: 0,
+            power: 1,
+        }
+    }
+
+    #[inline]
+    fn push<const BITS: usize, const LIMBS: usize>(
+        &mut self,
+        result: &mut Uint<BITS, LIMBS>,
+        used: &mut usize,
+        digit: u64,
+    ) -> Result<(), Overflow> {
+        if self.power == crate::utils::max_pow_u64(self.radix) {
+            self.flush(result, used)?;
+        }
+        self.value = self.value * self.radix + digit;
+        self.power *= self.radix;
+        Ok(())
+    }
+
+    #[inline]
+    fn flush<const BITS: usize, const LIMBS: usize>(
+        &mut self,
+        result: &mut Uint<BITS, LIMBS>,
+        used: &mut usize,
+    ) -> Result<(), Overflow> {
+        result.push_digit(used, self.power, self.value)?;
+        *self = Self::new(self.radix);
+        Ok(())
+    }
+}
+
+/// The value does not fit, without the position of the digit that overflows.
+pub(crate) struct Overflow;
+
+/// Error of [`Uint::parse_dec`].
+enum DecError {
+    Overflow,
+    /// Index of an invalid byte.
+    Invalid(usize),
+}
+
+/// Returns the error [`Uint::from_str_radix`] reports for an invalid decimal
+/// digit `c`.
+fn invalid_dec_digit(c: char) -> ParseError {
+    match parse_digit(c, 10) {
+        // Letters are digits in larger radixes.
+        Ok(Some(digit)) => BaseConvertError::InvalidDigit(digit, 10).into(),
+        Ok(None) | Err(_) => ParseError::InvalidDigit(c),
+    }
+}
+
+/// Powers of ten up to the largest that fits a `u64`.
+const POW10: [u64; 20] = {
+    let mut pow10 = [1; 20];
+    let mut i = 1;
+    while i < pow10.len() {
+        pow10[i] = pow10[i - 1] * 10;
+        i += 1;
+    }
+    pow10
+};
+
+/// Returns the value of eight ASCII decimal digits, or [`None`] if there is a
+/// non-digit.
+#[inline]
+fn parse_eight_digits(bytes: &[u8]) -> Option<u64> {
+    // See <https://lemire.me/blog/2022/01/21/swar-explained-parsing-eight-digits/>.
+    let value = u64::from_le_bytes(bytes.try_into().ok()?);
+    let high = value & 0xf0f0_f0f0_f0f0_f0f0;
+    let carry = (value.wrapping_add(0x0606_0606_0606_0606) & 0xf0f0_f0f0_f0f0_f0f0) >> 4;
+    if high | carry != 0x3333_3333_3333_3333 {
+        return None;
+    }
+    let value = value - 0x3030_3030_3030_3030;
+    // Pairs, then quads of digits, with the first digit most significant.
+    let value = (value * 10) + (value >> 8);
+    let mask = 0x0000_00ff_0000_00ff;
+    let low = (value & mask).wrapping_mul(100 + (1_000_000 << 32));
+    let high = ((value >> 16) & mask).wrapping_mul(1 + (10_000 << 32));
+    Some(low.wrapping_add(high) >> 32)
+}
+
+/// Returns the value of a digit in the alphabet for `radix`, or [`None`] for
+/// an ignored character.
+#[inline]
+fn parse_digit(c: char, radix: u64) -> Result<Option<u64>, ParseError> {
+    let digit = if radix <= 36 {
+        // Case insensitive 0—9, a—z.
+        match c {
+            '0'..='9' => u64::from(c) - u64::from('0'),
+            'a'..='z' => u64::from(c) - u64::from('a') + 10,
+            'A'..='Z' => u64::from(c) - u64::from('A') + 10,
+            '_' => return Ok(None), // Ignored character.
+            _ => return Err(ParseError::InvalidDigit(c)),
+        }
+    } else {
+        // The Base-64 alphabets
+        match c {
+            'A'..='Z' => u64::from(c) - u64::from('A'),
+            'a'..='f' => u64::from(c) - u64::from('a') + 26,
+            '0'..='9' => u64::from(c) - u64::from('0') + 52,
+            '+' | '-' => 62,
+            '/' | ',' | '_' => 63,
+            '=' | '\r' | '\n' => return Ok(None), // Ignored characters.
+            _ => return Err(ParseError::InvalidDigit(c)),
+        }
+    };
+    Ok(Some(digit))
+}
+
+/// Returns the character starting at byte `index`, or
+/// [`char::REPLACEMENT_CHARACTER`] if the bytes there are not UTF-8.
+fn char_at(bytes: &[u8], index: usize) -> char {
+    bytes[index..]
+        .utf8_chunks()
+        .next()
+        .and_then(|chunk| chunk.valid().chars().next())
+        .unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// Splits off a case insensitive `0x`, `0o` or `0b` prefix and returns the
+/// remaining digits with their radix.
+fn split_prefix(bytes: &[u8]) -> Option<(&[u8], u64)> {
+    match bytes {
+        [b'0', b'x' | b'X', rest @ ..] => Some((rest, 16)),
+        [b'0', b'o' | b'O', rest @ ..] => Some((rest, 8)),
+        [b'0', b'b' | b'B', rest @ ..] => Some((rest, 2)),
+        _ => None,
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> FromStr for Uint<BITS, LIMBS> {
     type Err = ParseError;
 
+    /// Parses with [`Uint::from_str_prefixed`] if the string has a radix
+    /// prefix and with [`Uint::from_dec_str`] otherwise.
     fn from_str(src: &str) -> Result<Self, Self::Err> {
-        let (src, radix) = if let Some((prefix, rest)) = src.split_at_checked(2) {
-            match prefix {
-                "0x" | "0X" => (rest, 16),
-                "0o" | "0O" => (rest, 8),
-                "0b" | "0B" => (rest, 2),
-                _ => (src, 10),
-            }
-        } else {
-            (src, 10)
-        };
-        Self::from_str_radix(src, radix)
+        Self::parse_ascii(src.as_bytes())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&str> for Uint<BITS, LIMBS> {
+    type Error = ParseError;
+
+    /// Parses like [`FromStr`].
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Parses like [`FromStr`], e.g. for `#[serde(try_from = "String")]`:
+///
+/// ```
+/// # use ruint::{ParseError, aliases::U256};
+/// #[derive(Debug, serde::Deserialize)]
+/// #[serde(try_from = "String")]
+/// struct Amount(U256);
+///
+/// impl TryFrom<String> for Amount {
+///     type Error = ParseError;
+///
+///     fn try_from(value: String) -> Result<Self, Self::Error> {
+///         U256::try_from(value).map(Self)
+///     }
+/// }
+///
+/// let amount: Amount = serde_json::from_str(r#""0x2a""#).unwrap();
+/// assert_eq!(amount.0, U256::from(42));
+/// assert!(serde_json::from_str::<Amount>(r#""4x2""#).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+impl<const BITS: usize, const LIMBS: usize> TryFrom<alloc::string::String> for Uint<BITS, LIMBS> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(value: alloc::string::String) -> Result<Self, Self::Error> {
+        value.parse()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{const_for, nlimbs};
     use proptest::{prop_assert_eq, proptest};
 
     #[test]
@@ -136,4 +826,402 @@ mod tests {
             prop_assert_eq!(U::from_str(&format!("{value:#X}")), Ok(U::from(value)));
         });
     }
+
+    /// The previous implementation, on top of [`Uint::from_base_be`].
+    fn from_str_radix_reference<const BITS: usize, const LIMBS: usize>(
+        src: &str,
+        radix: u64,
+    ) -> Result<Uint<BITS, LIMBS>, ParseError> {
+        if radix > 64 {
+            return Err(ParseError::InvalidRadix(radix));
+        }
+        let mut err = None;
+        let mut offsets = Vec::new();
+        let digits = src.char_indices().filter_map(|(i, c)| {
+            if err.is_some() {
+                return None;
+            }
+            match parse_digit(c, radix) {
+                Ok(digit) => {
+                    offsets.extend(digit.map(|_| i));
+                    digit
+                }
+                Err(e) => {
+                    err = Some(e);
+                    None
+                }
+            }
+        });
+        let value = Uint::from_base_be(radix, digits).map_err(|error| match error {
+            // The index counts digits, not bytes.
+            BaseConvertError::Overflow(index) => BaseConvertError::Overflow(offsets[index]),
+            error => error,
+        })?;
+        err.map_or(Ok(value), Err)
+    }
+
+    #[test]
+    fn test_from_str_radix_reference() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            for src in [
+                "", "0", "_", "1", "00000000000000000000000000001", "ff", "FF", "zz", "Zz",
+                "0x", "1_000", "+/=", "abc\n", "1 2", "é", "18446744073709551616",
+                "340282366920938463463374607431768211455",
+                "340282366920938463463374607431768211456",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                "10000000000000000000000000000000000000000000000000000000000000000",
+                "999999999999999999999999999999999999999999999999999999999999999999999999999999x",
+            ] {
+                for radix in [0, 1, 2, 8, 10, 16, 36, 37, 64, 65] {
+                    assert_eq!(
+                        U::from_str_radix(src, radix),
+                        from_str_radix_reference(src, radix),
+                        "{src:?} in radix {radix}"
+                    );
+                }
+            }
+            proptest!(|(src in "[0-9a-zA-Z_+/=]{0,80}", radix in 2_u64..=64)| {
+                prop_assert_eq!(
+                    U::from_str_radix(&src, radix),
+                    from_str_radix_reference(&src, radix)
+                );
+            });
+            proptest!(|(value: U, radix in 2_u64..=36)| {
+                let src = value
+                    .to_base_be(radix)
+                    .map(|digit| char::from_digit(digit as u32, radix as u32).unwrap())
+                    .collect::<String>();
+                prop_assert_eq!(U::from_str_radix(&src, radix), Ok(value));
+            });
+        });
+    }
+
+    /// The error [`Uint::from_dec_bytes`] reports where `from_str_radix`
+    /// reports `result`, which has no character for a non-ASCII byte.
+    fn ascii_error<T>(result: Result<T, ParseError>) -> Result<T, ParseError> {
+        result.map_err(|error| match error {
+            ParseError::InvalidDigit(c) if !c.is_ascii() => {
+                ParseError::InvalidDigit(char::REPLACEMENT_CHARACTER)
+            }
+            error => error,
+        })
+    }
+
+    #[test]
+    fn test_parse_dec() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let max = U::MAX.to_string();
+            let nines = format!("{}x", "9".repeat(80));
+            for src in [
+                "", "_", "0", "12345678", "123456789", "1234567_89", "1234567a", "12345678z",
+                "1234567\u{e9}", &nines, &max, &format!("{max}0"), &format!("{max}_"),
+                &format!("{max}a"), &format!("0000{max}"),
+            ] {
+                let expected = U::parse_digits(src.as_bytes(), 10);
+                assert_eq!(U::from_str_radix(src, 10), expected, "{src:?}");
+                assert_eq!(U::from_dec_bytes(src.as_bytes()), ascii_error(expected), "{src:?}");
+            }
+            proptest!(|(src in "[0-9]{0,160}|[0-9_]{0,160}|[0-9a-z_ \u{e9}]{0,80}")| {
+                let expected = U::parse_digits(src.as_bytes(), 10);
+                prop_assert_eq!(U::from_str_radix(&src, 10), expected);
+                prop_assert_eq!(U::from_dec_str(&src), expected);
+                prop_assert_eq!(U::from_dec_bytes(src.as_bytes()), ascii_error(expected));
+            });
+            proptest!(|(value: U)| {
+                prop_assert_eq!(U::from_dec_bytes(value.to_string().as_bytes()), Ok(value));
+            });
+        });
+        type U = Uint<64, 1>;
+        let invalid = Err(ParseError::InvalidDigit(char::REPLACEMENT_CHARACTER));
+        assert_eq!(U::from_dec_bytes(b"12345678\xff"), invalid);
+        assert_eq!(U::from_dec_bytes(b"\xc3\xa9"), invalid);
+    }
+
+    #[test]
+    fn test_parse_eight_digits() {
+        proptest!(|(bytes: [u8; 8])| {
+            let expected = bytes.iter().try_fold(0, |value, &byte| {
+                byte.is_ascii_digit().then(|| value * 10 + u64::from(byte - b'0'))
+            });
+            prop_assert_eq!(parse_eight_digits(&bytes), expected);
+        });
+        proptest!(|(value in 0_u64..100_000_000)| {
+            let src = format!("{value:08}");
+            prop_assert_eq!(parse_eight_digits(src.as_bytes()), Some(value));
+        });
+        assert_eq!(parse_eight_digits(b"1234567"), None);
+        assert_eq!(parse_eight_digits(b"1234567:"), None);
+        assert_eq!(parse_eight_digits(b"/1234567"), None);
+    }
+
+    #[test]
+    fn test_prefixed() {
+        type U = Uint<64, 1>;
+        for (src, value) in [
+            ("0x1f", 31),
+            ("0X1F", 31),
+            ("0o17", 15),
+            ("0O17", 15),
+            ("0b101", 5),
+            ("0B1_01", 5),
+            ("0x", 0),
+            ("0b", 0),
+        ] {
+            assert_eq!(U::from_str_prefixed(src), Ok(U::from(value)), "{src}");
+            assert_eq!(U::from_str(src), Ok(U::from(value)), "{src}");
+        }
+        assert_eq!(U::from_str_prefixed("31"), Err(ParseError::MissingPrefix));
+        assert_eq!(U::from_str_prefixed(""), Err(ParseError::MissingPrefix));
+        assert_eq!(U::from_str_prefixed("0d31"), Err(ParseError::MissingPrefix));
+        assert!(U::from_str_prefixed("0b102").is_err());
+        assert_eq!(
+            ParseError::MissingPrefix.to_string(),
+            "missing radix prefix, expected 0x, 0o or 0b"
+        );
+
+        assert_eq!(U::from_dec_str("1_000"), Ok(U::from(1000)));
+        assert!(U::from_dec_str("0x10").is_err());
+        assert_eq!(U::from_hex_str("0x10"), Ok(U::from(16)));
+        assert_eq!(U::from_hex_str("10"), Ok(U::from(16)));
+        assert_eq!(U::from_hex_str("0b10"), Ok(U::from(0xb10)));
+        assert!(U::from_hex_str("0o10").is_err());
+
+        // Overflow is reported at the byte offset in the input, including the
+        // prefix and separators.
+        let overflow = Err(BaseConvertError::Overflow(22).into());
+        assert_eq!(U::from_str("0x1_0000_0000_0000_0000"), overflow);
+        assert_eq!(U::from_hex_str("0x1_0000_0000_0000_0000"), overflow);
+        assert_eq!(
+            U::from_hex_str("1_0000_0000_0000_0000"),
+            Err(BaseConvertError::Overflow(20).into())
+        );
+        assert_eq!(
+            U::from_str("0b1_0000000000000000000000000000000000000000000000000000000000000000"),
+            Err(BaseConvertError::Overflow(67).into())
+        );
+        assert_eq!(U::from_str("31"), Ok(U::from(31)));
+        assert_eq!(U::from_str(""), Ok(U::ZERO));
+    }
+
+    #[test]
+    fn test_hex_power() {
+        use crate::aliases::U256;
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, shift in 0..BITS)| {
+                let value = value >> shift;
+                assert_eq!(U::from_str(&format!("{value:#x}p{shift}")), Ok(value << shift));
+            });
+            let top = U::ONE << (BITS - 1);
+            assert_eq!(U::from_str(&format!("0x1p{}", BITS - 1)), Ok(top));
+            // At the `p`, unless the mantissa alone already overflows.
+            let overflow = Err(BaseConvertError::Overflow(3).into());
+            assert_eq!(U::from_str(&format!("0x1p{BITS}")), overflow);
+            let overflow = Err(BaseConvertError::Overflow(if BITS == 1 { 2 } else { 3 }).into());
+            assert_eq!(U::from_str(&format!("0x2p{}", BITS - 1)), overflow);
+            assert_eq!(U::from_str("0x0p100000"), Ok(U::ZERO));
+        });
+
+        // The curve25519 base field prime.
+        let p = U256::from_str("0x1p255").unwrap() - U256::from_str("0x13").unwrap();
+        assert_eq!(
+            p,
+            U256::from_str("0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed")
+                .unwrap()
+        );
+        assert_eq!(U256::from_str("0x1_0P2_0"), Ok(U256::from(1_u64 << 24)));
+
+        type U = Uint<64, 1>;
+        assert_eq!(U::from_str("0x1.8p4"), Err(ParseError::InvalidDigit('.')));
+        assert_eq!(U::from_str("0x1p-4"), Err(ParseError::InvalidDigit('-')));
+        assert_eq!(U::from_str("0x1p"), Err(ParseError::InvalidDigit('p')));
+        assert_eq!(U::from_str("0x1P"), Err(ParseError::InvalidDigit('P')));
+        assert_eq!(U::from_str("0x1p4p4"), Err(ParseError::InvalidDigit('p')));
+        assert_eq!(U::from_str("0x1pa"), Err(ParseError::InvalidDigit('a')));
+        assert_eq!(
+            U::from_str("0x1p99999999999999999999999"),
+            Err(BaseConvertError::Overflow(3).into())
+        );
+        // Only hexadecimal numbers have exponents.
+        assert!(U::from_str("0b1p4").is_err());
+        assert!(U::from_str("1p4").is_err());
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        type U = Uint<64, 1>;
+        for src in [
+            "",
+            "0",
+            "31",
+            "0x1f",
+            "0X1F",
+            "0o17",
+            "0b101",
+            "1_000",
+            "0x",
+            "0b102",
+            "x",
+            "é",
+            "+1",
+            "18446744073709551615",
+            "18446744073709551616",
+            "99999999999999999999x",
+        ] {
+            let expected = U::from_str(src);
+            assert_eq!(U::try_from(src), expected, "{src:?}");
+            assert_eq!(U::try_from(src.to_string()), expected, "{src:?}");
+            assert_eq!(U::parse_ascii(src.as_bytes()), expected, "{src:?}");
+        }
+        let invalid = Err(ParseError::InvalidDigit(char::REPLACEMENT_CHARACTER));
+        assert_eq!(U::parse_ascii(b"\xff"), invalid);
+        assert_eq!(U::parse_ascii(b"0x\xff"), invalid);
+        assert_eq!(U::parse_ascii(b"12\xc3"), invalid);
+        assert_eq!(
+            U::parse_ascii(b"12\xc3\xa9"),
+            Err(ParseError::InvalidDigit('\u{e9}'))
+        );
+        assert_eq!(U::parse_ascii(b"0x1p\xff"), invalid);
+        assert_eq!(
+            U::parse_ascii(b"1z\xff"),
+            Err(BaseConvertError::InvalidDigit(35, 10).into())
+        );
+        assert_eq!(
+            U::parse_ascii(b"99999999999999999999\xff"),
+            Err(ParseError::BaseConvertError(BaseConvertError::Overflow(19)))
+        );
+        proptest!(|(value: U, bytes: Vec<u8>)| {
+            prop_assert_eq!(U::parse_ascii(value.to_string().as_bytes()), Ok(value));
+            if let Ok(src) = core::str::from_utf8(&bytes) {
+                prop_assert_eq!(U::parse_ascii(&bytes), U::from_str(src));
+            }
+        });
+    }
+
+    #[test]
+    fn test_from_dec_str_fixed() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let max = U::MAX.to_string();
+            assert_eq!(U::from_dec_str_fixed(&max), Ok(U::MAX));
+            assert_eq!(
+                U::from_dec_str_fixed(&format!("0{max}")),
+                Err(BaseConvertError::Overflow(max.len()).into())
+            );
+            let nines = "9".repeat(max.len());
+            assert_eq!(U::from_dec_str_fixed(&nines), U::from_str_radix(&nines, 10));
+            proptest!(|(value: U)| {
+                let padded = format!("{value:0width$}", width = U::max_dec_len());
+                prop_assert_eq!(U::from_dec_str_fixed(&padded), Ok(value));
+            });
+        });
+        assert_eq!(Uint::<64, 1>::from_dec_str_fixed(""), Ok(Uint::ZERO));
+        assert_eq!(
+            Uint::<64, 1>::from_dec_str_fixed("+1"),
+            Err(ParseError::InvalidDigit('+'))
+        );
+        assert_eq!(
+            Uint::<64, 1>::from_dec_str_fixed("١"),
+            Err(ParseError::InvalidDigit('١'))
+        );
+    }
+
+    #[test]
+    fn test_from_str_with_limit() {
+        type U = Uint<64, 1>;
+        assert_eq!(U::from_str_with_limit("", 0), Ok(U::ZERO));
+        assert_eq!(U::from_str_with_limit("123", 3), Ok(U::from(123)));
+        assert_eq!(U::from_str_with_limit("0x7b", 4), Ok(U::from(123)));
+        assert_eq!(
+            U::from_str_with_limit("1234", 3),
+            Err(ParseError::InputTooLong(3))
+        );
+        // The limit counts bytes, not characters.
+        assert_eq!(
+            U::from_str_with_limit("١", 1),
+            Err(ParseError::InputTooLong(1))
+        );
+        assert_eq!(
+            U::from_str_with_limit("١", 2),
+            Err(ParseError::InvalidDigit('١'))
+        );
+
+        // Megabytes of input are rejected on the length alone: leading zeros
+        // that would parse fine, and invalid digits or overflow that would only
+        // be found while parsing.
+        let limit = U::max_dec_len();
+        for long in [
+            "0".repeat(1 << 20),
+            format!("{}x", "0".repeat(1 << 20)),
+            "9".repeat(1 << 20),
+            format!("0x{}", "f".repeat(1 << 20)),
+        ] {
+            assert_eq!(
+                U::from_str_with_limit(&long, limit),
+                Err(ParseError::InputTooLong(limit))
+            );
+        }
+        assert_eq!(U::from_str(&"0".repeat(1 << 20)), Ok(U::ZERO));
+        assert_eq!(
+            ParseError::InputTooLong(20).to_string(),
+            "input is longer than 20 bytes"
+        );
+    }
+
+    #[test]
+    fn test_parse_many() {
+        type U = Uint<256, 4>;
+        let vectors = "\
+0x00
+  0x1f\r
+115792089237316195423570985008687907853269984665640564039457584007913129639935
+0x12#4
+
+0b101
+115792089237316195423570985008687907853269984665640564039457584007913129639936
+";
+        let items = U::parse_many(vectors, '\n', EmptyItems::Skip).collect::<Vec<_>>();
+        assert_eq!(items.len(), 6);
+        assert_eq!(items[0], Ok(U::ZERO));
+        assert_eq!(items[1], Ok(U::from(0x1f)));
+        assert_eq!(items[2], Ok(U::MAX));
+        let error = items[3].clone().unwrap_err();
+        assert_eq!(error.index, 3);
+        assert_eq!(&vectors[error.span.clone()], "0x12#4");
+        assert_eq!(error.error, Some(ParseError::InvalidDigit('#')));
+        assert_eq!(items[4], Ok(U::from(5)));
+        let error = items[5].clone().unwrap_err();
+        assert_eq!(error.index, 6);
+        assert_eq!(
+            error.error,
+            Some(ParseError::BaseConvertError(BaseConvertError::Overflow(77)))
+        );
+        assert_eq!(error.span.len(), 78);
+
+        let error = U::parse_all(vectors, '\n', EmptyItems::Skip).unwrap_err();
+        assert_eq!(error.index, 3);
+        let error = U::parse_all("1,\t2 ,, 3", ',', EmptyItems::Error).unwrap_err();
+        assert_eq!(
+            (error.index, error.span.clone(), error.error),
+            (2, 6..6, None)
+        );
+        assert_eq!(error.to_string(), "item 2 at bytes 6..6: empty item");
+        assert_eq!(
+            U::parse_all("1,\t2 ,, 3", ',', EmptyItems::Skip),
+            Ok(vec![U::from(1), U::from(2), U::from(3)])
+        );
+        assert_eq!(U::parse_all("", ',', EmptyItems::Skip), Ok(vec![]));
+        assert_eq!(
+            U::parse_all(" ١ ;2", ';', EmptyItems::Skip)
+                .unwrap_err()
+                .span,
+            1..3
+        );
+    }
 }
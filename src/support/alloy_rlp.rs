@@ -17,13 +17,7 @@ const MAX_BITS: usize = 55 * 8;
 impl<const BITS: usize, const LIMBS: usize> Encodable for Uint<BITS, LIMBS> {
     #[inline]
     fn length(&self) -> usize {
-        let bits = self.bit_len();
-        if bits <= 7 {
-            1
-        } else {
-            let bytes = bits.div_ceil(8);
-            bytes + length_of_length(bytes)
-        }
+        self.rlp_len()
     }
 
     #[inline]
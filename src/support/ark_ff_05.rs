@@ -2,7 +2,7 @@
 #![cfg(feature = "ark-ff-05")]
 #![cfg_attr(docsrs, doc(cfg(feature = "ark-ff-05")))]
 
-use crate::{ToFieldError, Uint};
+use crate::{FromUintError, ToFieldError, ToUintError, Uint, UintTryFrom, UintTryTo};
 use ark_ff_05::{
     PrimeField,
     biginteger::BigInt,
@@ -37,6 +37,41 @@ impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for BigInt<
     }
 }
 
+// BigInt with any number of limbs, through `Uint::checked_from`,
+// `Uint::checked_to` and friends.
+
+impl<const N: usize, const BITS: usize, const LIMBS: usize> UintTryFrom<BigInt<N>>
+    for Uint<BITS, LIMBS>
+{
+    #[inline]
+    fn uint_try_from(value: BigInt<N>) -> Result<Self, ToUintError<Self>> {
+        match Self::overflowing_from_limbs_slice(&value.0) {
+            (n, false) => Ok(n),
+            (n, true) => Err(ToUintError::ValueTooLarge(BITS, n)),
+        }
+    }
+}
+
+impl<const N: usize, const BITS: usize, const LIMBS: usize> UintTryTo<BigInt<N>>
+    for Uint<BITS, LIMBS>
+{
+    #[inline]
+    fn uint_try_to(&self) -> Result<BigInt<N>, FromUintError<BigInt<N>>> {
+        let len = N.min(LIMBS);
+        let mut limbs = [0; N];
+        limbs[..len].copy_from_slice(&self.as_limbs()[..len]);
+        if self.as_limbs()[len..].iter().all(|&limb| limb == 0) {
+            Ok(BigInt::new(limbs))
+        } else {
+            Err(FromUintError::Overflow(
+                BITS,
+                BigInt::new(limbs),
+                BigInt::new([u64::MAX; N]),
+            ))
+        }
+    }
+}
+
 // Fp
 
 impl<P: FpConfig<LIMBS>, const BITS: usize, const LIMBS: usize> From<Fp<P, LIMBS>>
@@ -55,23 +90,25 @@ impl<P: FpConfig<LIMBS>, const BITS: usize, const LIMBS: usize> From<&Fp<P, LIMB
     }
 }
 
-impl<P: FpConfig<LIMBS>, const BITS: usize, const LIMBS: usize> TryFrom<Uint<BITS, LIMBS>>
-    for Fp<P, LIMBS>
+/// Converts the canonical representative, for any number of limbs.
+impl<P: FpConfig<N>, const N: usize, const BITS: usize, const LIMBS: usize>
+    TryFrom<Uint<BITS, LIMBS>> for Fp<P, N>
 {
     type Error = ToFieldError;
 
     fn try_from(value: Uint<BITS, LIMBS>) -> Result<Self, ToFieldError> {
-        Self::from_bigint(value.into()).ok_or(ToFieldError::NotInField)
+        Self::try_from(&value)
     }
 }
 
-impl<P: FpConfig<LIMBS>, const BITS: usize, const LIMBS: usize> TryFrom<&Uint<BITS, LIMBS>>
-    for Fp<P, LIMBS>
+impl<P: FpConfig<N>, const N: usize, const BITS: usize, const LIMBS: usize>
+    TryFrom<&Uint<BITS, LIMBS>> for Fp<P, N>
 {
     type Error = ToFieldError;
 
     fn try_from(value: &Uint<BITS, LIMBS>) -> Result<Self, ToFieldError> {
-        Self::from_bigint(value.into()).ok_or(ToFieldError::NotInField)
+        let bigint = value.uint_try_to().map_err(|_| ToFieldError::NotInField)?;
+        Self::from_bigint(bigint).ok_or(ToFieldError::NotInField)
     }
 }
 
@@ -80,9 +117,24 @@ mod tests {
     use super::*;
     use crate::aliases::U256;
     use ark_bn254_05::{Fq, FqConfig, Fr, FrConfig};
-    use ark_ff_05::MontConfig;
+    use ark_ff_05::{Field, MontBackend, MontConfig};
     use proptest::proptest;
 
+    /// The BLS12-381 scalar field, as defined in `ark-bls12-381`.
+    #[allow(unexpected_cfgs)] // `MontConfig` checks for an `asm` feature.
+    mod bls12_381 {
+        use ark_ff_05 as ark_ff;
+        use ark_ff_05::{Fp256, MontBackend, MontConfig};
+
+        #[derive(MontConfig)]
+        #[modulus = "52435875175126190479447740508185965837690552500527637822603658699938581184513"]
+        #[generator = "7"]
+        #[small_subgroup_base = "3"]
+        #[small_subgroup_power = "1"]
+        pub struct FrConfig;
+        pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+    }
+
     macro_rules! test_roundtrip {
         ($ark:ty, $bits:expr, $limbs:expr) => {
             proptest!(|(value: Uint<$bits, $limbs>)| {
@@ -117,6 +169,86 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_bigint_other_limbs() {
+        use crate::aliases::{U128, U512};
+        use ark_ff_05::{BigInteger128, BigInteger256, BigInteger384};
+
+        proptest!(|(value: U256)| {
+            let ark: BigInteger384 = value.checked_to().unwrap();
+            assert_eq!(U256::checked_from(ark), Some(value));
+            assert_eq!(U512::from(ark), U512::from(value));
+            assert_eq!(U128::checked_from(BigInteger256::from(value)), value.checked_to());
+            assert_eq!(
+                value.checked_to::<BigInteger128>(),
+                U128::checked_from(value).map(BigInteger128::from)
+            );
+        });
+        assert_eq!(
+            U128::saturating_from(BigInteger256::from(U256::MAX)),
+            U128::MAX
+        );
+        assert_eq!(
+            U256::MAX.saturating_to::<BigInteger128>(),
+            BigInteger128::new([u64::MAX; 2])
+        );
+        assert_eq!(
+            U256::MAX.wrapping_to::<BigInteger128>(),
+            BigInteger128::new([u64::MAX; 2])
+        );
+        assert_eq!(
+            Uint::<255, 4>::checked_from(BigInteger256::from(U256::MAX)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_field_bounds() {
+        fn check<P: FpConfig<4>>(modulus: U256) {
+            let below = modulus - U256::ONE;
+            let f = Fp::<P, 4>::try_from(below).unwrap();
+            let back: U256 = f.into();
+            assert_eq!(back, below);
+            assert_eq!(f + Fp::ONE, Fp::from(0_u64));
+            assert!(matches!(
+                Fp::<P, 4>::try_from(modulus),
+                Err(ToFieldError::NotInField)
+            ));
+            let above = modulus + U256::ONE;
+            assert!(matches!(
+                Fp::<P, 4>::try_from(&above),
+                Err(ToFieldError::NotInField)
+            ));
+            assert!(Fp::<P, 4>::try_from(U256::MAX).is_err());
+
+            // Other widths go through the canonical representative as well.
+            assert_eq!(Fp::<P, 4>::try_from(U512::from(below)).unwrap(), f);
+            assert!(Fp::<P, 4>::try_from(U512::from(modulus)).is_err());
+            assert!(Fp::<P, 4>::try_from(U512::MAX).is_err());
+            assert_eq!(Fp::<P, 4>::try_from(U64::MAX).unwrap(), Fp::from(u64::MAX));
+        }
+
+        use crate::aliases::{U64, U512};
+        check::<MontBackend<FqConfig, 4>>(FqConfig::MODULUS.into());
+        check::<MontBackend<FrConfig, 4>>(FrConfig::MODULUS.into());
+        check::<MontBackend<bls12_381::FrConfig, 4>>(bls12_381::FrConfig::MODULUS.into());
+        assert_eq!(
+            U256::from(bls12_381::FrConfig::MODULUS),
+            uint!(0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001_U256)
+        );
+    }
+
+    #[test]
+    fn test_bls12_381_roundtrip() {
+        let modulus: U256 = bls12_381::FrConfig::MODULUS.into();
+        proptest!(|(value: U256)| {
+            let value: U256 = value % modulus;
+            let f: bls12_381::Fr = value.try_into().unwrap();
+            let back: U256 = f.into();
+            assert_eq!(back, value);
+        });
+    }
+
     #[test]
     fn test_fr_roundtrip() {
         let modulus: U256 = FrConfig::MODULUS.into();
@@ -0,0 +1,193 @@
+//! Support for the [`bitvec`](https://crates.io/crates/bitvec) crate.
+//!
+//! The [`Lsb0`] conversions map bit `i` of the slice to bit `i` of the
+//! [`Uint`], so the storage of a [`BitVec<u64, Lsb0>`] is exactly the limbs.
+//! The [`Msb0`] conversions instead read the slice as a binary string, with
+//! the first bit being the most significant. This is the natural order for
+//! paths in binary trees.
+
+#![cfg(feature = "bitvec")]
+#![cfg_attr(docsrs, doc(cfg(feature = "bitvec")))]
+
+use crate::{Uint, from::ToUintError};
+use bitvec::{
+    field::BitField,
+    order::{Lsb0, Msb0},
+    slice::BitSlice,
+    vec::BitVec,
+};
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Returns the `BITS` bits of the value in [`Lsb0`] order, i.e. bit `i` of
+    /// the result is [`Self::bit(i)`](Self::bit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::Uint;
+    /// let bits = Uint::<5, 1>::from(0b00110).to_bitvec();
+    /// assert_eq!(bits.len(), 5);
+    /// assert_eq!(bits.iter_ones().collect::<Vec<_>>(), [1, 2]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_bitvec(&self) -> BitVec<u64, Lsb0> {
+        let mut bits = BitVec::from_slice(self.as_limbs());
+        bits.truncate(BITS);
+        bits
+    }
+
+    /// Returns the `BITS` bits of the value most significant first, i.e. bit
+    /// `i` of the result is [`Self::bit(BITS - 1 - i)`](Self::bit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::Uint;
+    /// let bits = Uint::<5, 1>::from(0b00110).to_bitvec_msb0();
+    /// assert_eq!(bits.len(), 5);
+    /// assert_eq!(bits.iter_ones().collect::<Vec<_>>(), [2, 3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_bitvec_msb0(&self) -> BitVec<u64, Msb0> {
+        (0..BITS).rev().map(|i| self.bit(i)).collect()
+    }
+
+    /// Reads a binary string with the most significant bit first.
+    ///
+    /// Slices shorter than `BITS` are zero-extended at the most significant
+    /// end, so leading zeros do not change the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToUintError::ValueTooLarge`] with the value wrapped to `BITS`
+    /// if a bit other than the last `BITS` bits of the slice is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::Uint;
+    /// # use bitvec::prelude::*;
+    /// let path = bits![u64, Msb0; 0, 0, 1, 1, 0];
+    /// assert_eq!(Uint::<3, 1>::try_from_bitslice_msb0(path), Ok(Uint::from(0b110)));
+    /// assert!(Uint::<2, 1>::try_from_bitslice_msb0(path).is_err());
+    /// ```
+    #[inline]
+    pub fn try_from_bitslice_msb0(slice: &BitSlice<u64, Msb0>) -> Result<Self, ToUintError<Self>> {
+        let mut result = Self::ZERO;
+        let mut overflow = false;
+        for index in slice.iter_ones() {
+            let bit = slice.len() - 1 - index;
+            if bit < BITS {
+                result.set_bit(bit, true);
+            } else {
+                overflow = true;
+            }
+        }
+        if overflow {
+            Err(ToUintError::ValueTooLarge(BITS, result))
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for BitVec<u64, Lsb0> {
+    #[inline]
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        value.to_bitvec()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for BitVec<u64, Lsb0> {
+    #[inline]
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        value.to_bitvec()
+    }
+}
+
+/// Slices shorter than `BITS` are zero-extended. Longer slices are accepted if
+/// all bits past `BITS` are zero.
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&BitSlice<u64, Lsb0>> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: &BitSlice<u64, Lsb0>) -> Result<Self, Self::Error> {
+        let mut limbs = [0; LIMBS];
+        let mut overflow = false;
+        for (i, chunk) in value.chunks(64).enumerate() {
+            match limbs.get_mut(i) {
+                Some(limb) => *limb = chunk.load_le(),
+                None => overflow |= chunk.any(),
+            }
+        }
+        let (result, high_bits) = Self::overflowing_from_limbs_slice(&limbs);
+        if overflow || high_bits {
+            Err(ToUintError::ValueTooLarge(BITS, result))
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use bitvec::bits;
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in [0, 1, 3, 63, 64, 65, 127, 129, 255, 257] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let lsb = BitVec::from(&value);
+                assert_eq!(lsb.len(), BITS);
+                assert!(lsb.iter().eq(value.to_bitvec_msb0().iter().rev()));
+                assert_eq!(U::try_from(lsb.as_bitslice()), Ok(value));
+
+                let msb = value.to_bitvec_msb0();
+                assert_eq!(msb.len(), BITS);
+                assert_eq!(U::try_from_bitslice_msb0(&msb), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_lengths() {
+        type U = Uint<65, 2>;
+        assert_eq!(U::try_from(bits![u64, Lsb0;]), Ok(U::ZERO));
+        assert_eq!(U::try_from(bits![u64, Lsb0; 0, 1]), Ok(U::from(2)));
+        assert_eq!(
+            U::try_from_bitslice_msb0(bits![u64, Msb0; 1, 0]),
+            Ok(U::from(2))
+        );
+
+        let mut long = BitVec::<u64, Lsb0>::repeat(false, 200);
+        long.set(64, true);
+        assert_eq!(U::try_from(long.as_bitslice()), Ok(U::from(1_u128 << 64)));
+        long.set(65, true);
+        assert_eq!(
+            U::try_from(long.as_bitslice()),
+            Err(ToUintError::ValueTooLarge(65, U::from(1_u128 << 64)))
+        );
+        long.set(65, false);
+        long.set(199, true);
+        assert!(U::try_from(long.as_bitslice()).is_err());
+
+        let mut long = BitVec::<u64, Msb0>::repeat(false, 200);
+        long.set(199 - 64, true);
+        assert_eq!(U::try_from_bitslice_msb0(&long), Ok(U::from(1_u128 << 64)));
+        long.set(0, true);
+        assert_eq!(
+            U::try_from_bitslice_msb0(&long),
+            Err(ToUintError::ValueTooLarge(65, U::from(1_u128 << 64)))
+        );
+
+        assert_eq!(U256::MAX.to_bitvec().count_ones(), 256);
+        assert_eq!(Uint::<0, 0>::ZERO.to_bitvec_msb0().len(), 0);
+    }
+}
@@ -56,7 +56,7 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<&BigNumber> for Uint<BITS, L
     fn try_from(value: &BigNumber) -> Result<Self, Self::Error> {
         let hex = value.hex();
         Self::from_str_radix(&hex, 16).map_err(|e| match e {
-            ParseError::BaseConvertError(BaseConvertError::Overflow) => {
+            ParseError::BaseConvertError(BaseConvertError::Overflow(_)) => {
                 ToUintError::ValueTooLarge(BITS, Self::ZERO)
             }
             _ => ToUintError::NotANumber(BITS),
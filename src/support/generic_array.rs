@@ -0,0 +1,79 @@
+//! Support for the [`generic-array`](https://crates.io/crates/generic-array) crate.
+#![cfg(feature = "generic-array")]
+#![cfg_attr(docsrs, doc(cfg(feature = "generic-array")))]
+
+use crate::{Bits, ToUintError, Uint, UintTryFrom};
+use generic_array::{GenericArray, typenum};
+
+// Implements the big-endian conversions for the sizes of common hash outputs
+// and aliases. Other sizes do not compile, as there is no `From` impl.
+macro_rules! impl_generic_array {
+    ($(($bits:expr, $limbs:expr, $bytes:ident)),+ $(,)?) => {
+        $(
+            impl From<GenericArray<u8, typenum::$bytes>> for Uint<$bits, $limbs> {
+                #[inline]
+                fn from(value: GenericArray<u8, typenum::$bytes>) -> Self {
+                    Self::from_be_slice(&value)
+                }
+            }
+
+            // Makes the inherent `Uint::from` accept the array too.
+            impl UintTryFrom<GenericArray<u8, typenum::$bytes>> for Uint<$bits, $limbs> {
+                #[inline]
+                fn uint_try_from(
+                    value: GenericArray<u8, typenum::$bytes>,
+                ) -> Result<Self, ToUintError<Self>> {
+                    Ok(Self::from_be_slice(&value))
+                }
+            }
+
+            impl From<Uint<$bits, $limbs>> for GenericArray<u8, typenum::$bytes> {
+                #[inline]
+                fn from(value: Uint<$bits, $limbs>) -> Self {
+                    Self::clone_from_slice(&value.to_be_bytes::<{ $bits / 8 }>())
+                }
+            }
+
+            impl From<GenericArray<u8, typenum::$bytes>> for Bits<$bits, $limbs> {
+                #[inline]
+                fn from(value: GenericArray<u8, typenum::$bytes>) -> Self {
+                    Uint::<$bits, $limbs>::from_be_slice(&value).into()
+                }
+            }
+
+            impl From<Bits<$bits, $limbs>> for GenericArray<u8, typenum::$bytes> {
+                #[inline]
+                fn from(value: Bits<$bits, $limbs>) -> Self {
+                    value.into_inner().into()
+                }
+            }
+        )+
+    };
+}
+
+impl_generic_array! {
+    (8, 1, U1),
+    (16, 1, U2),
+    (32, 1, U4),
+    (64, 1, U8),
+    (128, 2, U16),
+    (160, 3, U20),
+    (192, 3, U24),
+    (224, 4, U28),
+    (256, 4, U32),
+    (320, 5, U40),
+    (384, 6, U48),
+    (448, 7, U56),
+    (512, 8, U64),
+    (768, 12, U96),
+    (1024, 16, U128),
+    (2048, 32, U256),
+    (4096, 64, U512),
+}
+
+#[cfg(test)]
+mod tests {
+    use generic_array::{GenericArray, typenum};
+
+    crate::support::test_byte_array!(GenericArray, typenum, sha2);
+}
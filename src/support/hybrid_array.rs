@@ -0,0 +1,79 @@
+//! Support for the [`hybrid-array`](https://crates.io/crates/hybrid-array) crate.
+#![cfg(feature = "hybrid-array")]
+#![cfg_attr(docsrs, doc(cfg(feature = "hybrid-array")))]
+
+use crate::{Bits, ToUintError, Uint, UintTryFrom};
+use hybrid_array::{Array, sizes};
+
+// Implements the big-endian conversions for the sizes of common hash outputs
+// and aliases. Other sizes do not compile, as there is no `From` impl.
+macro_rules! impl_hybrid_array {
+    ($(($bits:expr, $limbs:expr, $bytes:ident)),+ $(,)?) => {
+        $(
+            impl From<Array<u8, sizes::$bytes>> for Uint<$bits, $limbs> {
+                #[inline]
+                fn from(value: Array<u8, sizes::$bytes>) -> Self {
+                    Self::from_be_bytes(value.0)
+                }
+            }
+
+            // Makes the inherent `Uint::from` accept the array too.
+            impl UintTryFrom<Array<u8, sizes::$bytes>> for Uint<$bits, $limbs> {
+                #[inline]
+                fn uint_try_from(
+                    value: Array<u8, sizes::$bytes>,
+                ) -> Result<Self, ToUintError<Self>> {
+                    Ok(Self::from_be_bytes(value.0))
+                }
+            }
+
+            impl From<Uint<$bits, $limbs>> for Array<u8, sizes::$bytes> {
+                #[inline]
+                fn from(value: Uint<$bits, $limbs>) -> Self {
+                    Self(value.to_be_bytes())
+                }
+            }
+
+            impl From<Array<u8, sizes::$bytes>> for Bits<$bits, $limbs> {
+                #[inline]
+                fn from(value: Array<u8, sizes::$bytes>) -> Self {
+                    Uint::<$bits, $limbs>::from_be_bytes(value.0).into()
+                }
+            }
+
+            impl From<Bits<$bits, $limbs>> for Array<u8, sizes::$bytes> {
+                #[inline]
+                fn from(value: Bits<$bits, $limbs>) -> Self {
+                    value.into_inner().into()
+                }
+            }
+        )+
+    };
+}
+
+impl_hybrid_array! {
+    (8, 1, U1),
+    (16, 1, U2),
+    (32, 1, U4),
+    (64, 1, U8),
+    (128, 2, U16),
+    (160, 3, U20),
+    (192, 3, U24),
+    (224, 4, U28),
+    (256, 4, U32),
+    (320, 5, U40),
+    (384, 6, U48),
+    (448, 7, U56),
+    (512, 8, U64),
+    (768, 12, U96),
+    (1024, 16, U128),
+    (2048, 32, U256),
+    (4096, 64, U512),
+}
+
+#[cfg(test)]
+mod tests {
+    use hybrid_array::{Array, sizes};
+
+    crate::support::test_byte_array!(Array, sizes, sha2_011);
+}
@@ -0,0 +1,180 @@
+//! Support for the [`malachite`](https://crates.io/crates/malachite) crate.
+//!
+//! This is primarily meant for differential testing against an independent
+//! big integer implementation, and for migrating code that uses
+//! [`Natural`]. Note that `malachite` is licensed under LGPL-3.0.
+
+#![cfg(feature = "malachite")]
+#![cfg_attr(docsrs, doc(cfg(feature = "malachite")))]
+
+use crate::{Uint, from::ToUintError};
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use malachite_nz::natural::Natural;
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Natural> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: Natural) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&Natural> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: &Natural) -> Result<Self, Self::Error> {
+        // Going through 64-bit digits keeps this independent of the limb size
+        // `malachite` was built with.
+        let digits = PowerOf2Digits::<u64>::to_power_of_2_digits_asc(value, 64);
+        let (n, overflow) = Self::overflowing_from_limbs_slice(&digits);
+        if overflow {
+            Err(ToUintError::ValueTooLarge(BITS, n))
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Natural {
+    #[inline]
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for Natural {
+    #[inline]
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        Self::from_power_of_2_digits_asc(64, value.as_limbs().iter().copied())
+            .expect("limbs are valid 64-bit digits")
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> PartialEq<Natural> for Uint<BITS, LIMBS> {
+    #[inline]
+    fn eq(&self, other: &Natural) -> bool {
+        Self::try_from(other).is_ok_and(|other| *self == other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> PartialEq<Uint<BITS, LIMBS>> for Natural {
+    #[inline]
+    fn eq(&self, other: &Uint<BITS, LIMBS>) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use malachite_base::num::{
+        arithmetic::traits::{DivRem, Gcd, ModPow},
+        logic::traits::SignificantBits,
+    };
+    use proptest::{proptest, test_runner::Config};
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big = Natural::from(value);
+                assert_eq!(big.significant_bits() as usize, value.bit_len());
+                assert_eq!(U::try_from(&big), Ok(value));
+                assert_eq!(value, big);
+                assert_eq!(big, value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(U256::try_from(Natural::from(0_u32)), Ok(U256::ZERO));
+        assert_eq!(
+            Uint::<0, 0>::try_from(Natural::from(1_u32)),
+            Err(ToUintError::ValueTooLarge(0, Uint::ZERO))
+        );
+        assert_eq!(
+            U256::try_from(Natural::from(1_u32) << 256_u32),
+            Err(ToUintError::ValueTooLarge(256, U256::ZERO))
+        );
+        assert_eq!(
+            Uint::<65, 2>::try_from((Natural::from(1_u32) << 65_u32) + Natural::from(3_u32)),
+            Err(ToUintError::ValueTooLarge(65, Uint::from(3)))
+        );
+        assert_ne!(U256::MAX, Natural::from(1_u32) << 256_u32);
+        assert_ne!(Natural::from(1_u32) << 256_u32, U256::ZERO);
+    }
+
+    /// Differential tests of the arithmetic against `malachite`.
+    mod differential {
+        use super::*;
+
+        #[test]
+        fn test_mul() {
+            const_for!(BITS in SIZES {
+                const LIMBS: usize = nlimbs(BITS);
+                type U = Uint<BITS, LIMBS>;
+                proptest!(|(a: U, b: U)| {
+                    let wide = Natural::from(a) * Natural::from(b);
+                    let (low, overflow) = a.overflowing_mul(b);
+                    assert_eq!(low, &wide % (Natural::from(1_u32) << BITS));
+                    assert_eq!(overflow, wide >> BITS != 0_u32);
+                });
+            });
+        }
+
+        #[test]
+        fn test_div_rem() {
+            const_for!(BITS in NON_ZERO {
+                const LIMBS: usize = nlimbs(BITS);
+                type U = Uint<BITS, LIMBS>;
+                proptest!(|(a: U, b: U)| {
+                    if b != U::ZERO {
+                        let (q, r) = Natural::from(a).div_rem(Natural::from(b));
+                        assert_eq!(a / b, q);
+                        assert_eq!(a % b, r);
+                    }
+                });
+            });
+        }
+
+        #[test]
+        fn test_pow_mod() {
+            const_for!(BITS in NON_ZERO {
+                const LIMBS: usize = nlimbs(BITS);
+                type U = Uint<BITS, LIMBS>;
+
+                // Too slow.
+                if LIMBS > 8 {
+                    return;
+                }
+
+                let config = Config { cases: 20, ..Default::default() };
+                proptest!(config, |(b: U, e: U, m: U)| {
+                    if m != U::ZERO {
+                        let m_big = Natural::from(m);
+                        let b_big = Natural::from(b) % &m_big;
+                        let expected = b_big.mod_pow(Natural::from(e), m_big);
+                        assert_eq!(b.pow_mod(e, m), expected);
+                    }
+                });
+            });
+        }
+
+        #[test]
+        fn test_gcd() {
+            const_for!(BITS in SIZES {
+                const LIMBS: usize = nlimbs(BITS);
+                type U = Uint<BITS, LIMBS>;
+                proptest!(|(a: U, b: U)| {
+                    assert_eq!(a.gcd(b), Natural::from(a).gcd(Natural::from(b)));
+                });
+            });
+        }
+    }
+}
@@ -2,6 +2,56 @@
 
 #![allow(missing_docs, clippy::missing_inline_in_public_items)]
 
+// Tests the conversions of a byte array type `$array<u8, $sizes::N>`, with
+// hashes computed by the `$sha2` crate.
+#[cfg(all(test, any(feature = "generic-array", feature = "hybrid-array")))]
+macro_rules! test_byte_array {
+    ($array:ident, $sizes:ident, $sha2:ident) => {
+        use crate::aliases::{B160, B256, U160, U256, U384, U512};
+        use hex_literal::hex;
+        use proptest::proptest;
+        use $sha2::{Digest, Sha256, Sha384, Sha512, Sha512_256};
+
+        #[test]
+        fn test_digest() {
+            let digest = U256::from(Sha256::digest(b"abc"));
+            let expected =
+                hex!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+            assert_eq!(digest, U256::from_be_bytes(expected));
+            let digest = U256::from(Sha512_256::digest(b"abc"));
+            let expected =
+                hex!("53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23");
+            assert_eq!(digest, U256::from_be_bytes(expected));
+            assert_eq!(B256::from(Sha512_256::digest(b"abc")), B256::from(digest));
+
+            let digest = Sha384::digest(b"abc");
+            assert_eq!(
+                U384::from(digest).to_be_bytes::<48>().as_slice(),
+                digest.as_slice()
+            );
+            let digest = Sha512::digest(b"abc");
+            assert_eq!(
+                U512::from(digest).to_be_bytes::<64>().as_slice(),
+                digest.as_slice()
+            );
+        }
+
+        #[test]
+        fn test_roundtrip() {
+            proptest!(|(value: U256, address: U160)| {
+                let array = $array::<u8, $sizes::U32>::from(value);
+                assert_eq!(array.as_slice(), value.to_be_bytes::<32>());
+                assert_eq!(U256::from(array), value);
+                let array = $array::<u8, $sizes::U20>::from(B160::from(address));
+                assert_eq!(U160::from(array), address);
+                assert_eq!(B160::from(array), B160::from(address));
+            });
+        }
+    };
+}
+#[cfg(all(test, any(feature = "generic-array", feature = "hybrid-array")))]
+pub(crate) use test_byte_array;
+
 mod alloy_rlp;
 mod arbitrary;
 mod ark_ff;
@@ -9,6 +59,7 @@ mod ark_ff_04;
 mod ark_ff_05;
 mod bigdecimal;
 mod bincode_2;
+mod bitvec;
 mod bn_rs;
 mod borsh;
 mod bytemuck;
@@ -16,6 +67,10 @@ mod der;
 pub mod diesel;
 mod fastrlp_03;
 mod fastrlp_04;
+mod generic_array;
+mod hybrid_array;
+mod malachite;
+mod nohash_hasher;
 mod num_bigint;
 mod num_integer;
 mod num_traits;
@@ -26,10 +81,13 @@ mod pyo3;
 mod quickcheck;
 mod rand;
 mod rand_09;
+mod rand_common;
 pub mod rkyv;
 mod rlp;
+mod rlp_len;
+mod rug;
 pub mod scale;
-mod serde;
+pub mod serde;
 pub mod sqlx;
 pub mod ssz;
 mod subtle;
@@ -38,13 +96,11 @@ mod zeroize;
 
 // FEATURE: Support for many more traits and crates.
 // * https://crates.io/crates/der
-// * https://crates.io/crates/bitvec
 
 // * open-fastrlp
 
 // Big int types:
 // * https://crates.io/crates/crypto-bigint
-// * https://crates.io/crates/rug
 // * https://crates.io/crates/rust_decimal
 
 // * wasm-bindgen `JsValue` bigint: https://docs.rs/wasm-bindgen/latest/wasm_bindgen/struct.JsValue.html#method.bigint_from_str
@@ -0,0 +1,46 @@
+//! Support for the [`nohash-hasher`](https://crates.io/crates/nohash-hasher) crate.
+
+#![cfg(feature = "nohash-hasher")]
+#![cfg_attr(docsrs, doc(cfg(feature = "nohash-hasher")))]
+
+use crate::Uint;
+use nohash_hasher::IsEnabled;
+
+/// Single limb values hash with exactly one call to
+/// [`Hasher::write_u64`](core::hash::Hasher::write_u64), so
+/// `IntMap<U64, V>` uses the value itself as the hash.
+impl<const BITS: usize> IsEnabled for Uint<BITS, 1> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases::{U8, U64},
+        const_for, nlimbs,
+    };
+    use nohash_hasher::{IntMap, IntSet};
+    use proptest::proptest;
+
+    #[test]
+    fn test_int_map() {
+        const_for!(BITS in [1, 7, 8, 32, 63, 64] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(keys: Vec<U>)| {
+                let set = keys.iter().copied().collect::<IntSet<U>>();
+                for key in &keys {
+                    assert!(set.contains(key));
+                }
+            });
+        });
+        let mut map = IntMap::<U64, &str>::default();
+        map.insert(U64::from(1), "one");
+        map.insert(U64::MAX, "max");
+        assert_eq!(map[&U64::from(1)], "one");
+        assert_eq!(map[&U64::MAX], "max");
+        assert_eq!(
+            IntSet::<U8>::from_iter([U8::ZERO, U8::MAX, U8::ZERO]).len(),
+            2
+        );
+    }
+}
@@ -54,6 +54,31 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         self.randomize_with(&mut rand::rng());
     }
 
+    /// Creates a new [`Uint`] uniformly distributed in `[0, modulus)` with the
+    /// given random number generator.
+    ///
+    /// Returns zero if `modulus` is zero.
+    ///
+    /// See [`random_le`](Self::random_le) for the sampling procedure.
+    #[inline]
+    #[must_use]
+    pub fn random_mod<R: rand::RngCore + ?Sized>(rng: &mut R, modulus: &Self) -> Self {
+        Self::random_mod_impl(modulus, |limbs| rng.fill(limbs))
+    }
+
+    /// Creates a new [`Uint`] uniformly distributed in `[0, max_inclusive]`
+    /// with the given random number generator.
+    ///
+    /// This uses rejection sampling: the lowest `max_inclusive.bit_len()` bits
+    /// are filled from the generator limb by limb, and the candidate is
+    /// rejected if it exceeds `max_inclusive`. Less than two candidates are
+    /// needed on average.
+    #[inline]
+    #[must_use]
+    pub fn random_le<R: rand::RngCore + ?Sized>(rng: &mut R, max_inclusive: &Self) -> Self {
+        Self::random_le_impl(max_inclusive, |limbs| rng.fill(limbs))
+    }
+
     /// Fills this [`Uint`] with the given random number generator.
     #[inline]
     #[doc(alias = "randomize_using")]
@@ -66,7 +91,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{const_for, nlimbs};
+    use crate::{const_for, nlimbs, support::rand_common};
 
     #[test]
     fn test_rand() {
@@ -78,4 +103,37 @@ mod tests {
             }
         });
     }
+
+    /// Yields a fixed sequence of `u64` words.
+    struct Sequence(std::vec::IntoIter<u64>);
+
+    impl rand::RngCore for Sequence {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0.next().unwrap()
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for chunk in dest.chunks_mut(8) {
+                chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..chunk.len()]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_le_procedure() {
+        rand_common::tests::test_random_le_procedure(|words| {
+            let mut rng = Sequence(words.into_iter());
+            move |limbs: &mut [u64]| rng.fill(limbs)
+        });
+    }
+
+    #[test]
+    fn test_random_mod() {
+        let mut rng = rand::rng();
+        rand_common::tests::test_random_mod(|limbs| rng.fill(limbs));
+    }
 }
@@ -0,0 +1,102 @@
+//! Sampling shared by the `rand` 0.8 and 0.9 support.
+
+#![cfg(any(feature = "rand", feature = "rand-09"))]
+
+use crate::Uint;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// [`Uint::random_mod`] with `fill` drawing random limbs.
+    #[inline]
+    pub(crate) fn random_mod_impl(modulus: &Self, fill: impl FnMut(&mut [u64])) -> Self {
+        if modulus.is_zero() {
+            return Self::ZERO;
+        }
+        Self::random_le_impl(&(*modulus - Self::ONE), fill)
+    }
+
+    /// [`Uint::random_le`] with `fill` drawing random limbs.
+    #[inline]
+    pub(crate) fn random_le_impl(max_inclusive: &Self, mut fill: impl FnMut(&mut [u64])) -> Self {
+        let bits = max_inclusive.bit_len();
+        if bits == 0 {
+            return Self::ZERO;
+        }
+        let limbs = bits.div_ceil(64);
+        let mask = u64::MAX >> (64 * limbs - bits);
+        loop {
+            let mut candidate = Self::ZERO;
+            fill(&mut candidate.limbs[..limbs]);
+            candidate.limbs[limbs - 1] &= mask;
+            if candidate <= *max_inclusive {
+                return candidate;
+            }
+        }
+    }
+}
+
+/// Tests shared by the `rand` 0.8 and 0.9 support.
+///
+/// Both versions have their own `random_mod` and `random_le`, of which only
+/// one set exists when both features are enabled. The helpers therefore take
+/// a `fill` closure built from the version's `Rng::fill`, exactly like those
+/// methods do.
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{Uint, const_for, nlimbs};
+
+    /// Checks the sampling procedure against fixed sequences of random words.
+    ///
+    /// `sequence` builds a `fill` closure from a generator yielding the given
+    /// words.
+    pub(crate) fn test_random_le_procedure<F: FnMut(&mut [u64])>(sequence: impl Fn(Vec<u64>) -> F) {
+        type U8 = Uint<8, 1>;
+        type U130 = Uint<130, 3>;
+
+        // Candidates are masked to the bit length of the bound and rejected
+        // if they exceed it.
+        let mut fill = sequence(vec![0xff, 0x1c, 0x0a, 0x09, 0x03]);
+        assert_eq!(U8::random_mod_impl(&U8::from(10), &mut fill), U8::from(9));
+        assert_eq!(U8::random_mod_impl(&U8::from(10), &mut fill), U8::from(3));
+
+        // Only the limbs needed for the bound are drawn.
+        let max = U130::from_limbs([0, 0, 2]);
+        let mut fill = sequence(vec![1, 2, 0xff, 3, 4, 0x05]);
+        assert_eq!(
+            U130::random_le_impl(&max, &mut fill),
+            U130::from_limbs([3, 4, 1])
+        );
+
+        assert_eq!(U8::random_mod_impl(&U8::ZERO, &mut fill), U8::ZERO);
+        assert_eq!(U8::random_le_impl(&U8::ZERO, &mut fill), U8::ZERO);
+        assert_eq!(
+            Uint::<0, 0>::random_le_impl(&Uint::ZERO, &mut fill),
+            Uint::ZERO
+        );
+    }
+
+    /// Checks the range and uniformity of the samples, with `fill` drawing
+    /// from a real generator.
+    pub(crate) fn test_random_mod(mut fill: impl FnMut(&mut [u64])) {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            for _ in 0..100 {
+                let modulus = U::random_le_impl(&U::MAX, &mut fill);
+                let value = U::random_mod_impl(&modulus, &mut fill);
+                assert!(value < modulus || modulus.is_zero());
+                assert!(U::random_le_impl(&modulus, &mut fill) <= modulus);
+            }
+        });
+
+        // Each residue is expected 10,000 times with a standard deviation of
+        // 95, so a 10% deviation is practically impossible without bias.
+        let mut counts = [0_u32; 10];
+        for _ in 0..100_000 {
+            let value = Uint::<8, 1>::random_mod_impl(&Uint::from(10), &mut fill);
+            counts[value.to::<usize>()] += 1;
+        }
+        for count in counts {
+            assert!((9_000..11_000).contains(&count), "{counts:?}");
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! RLP encoded lengths, shared by the [`alloy-rlp`](https://crates.io/crates/alloy-rlp),
+//! [`fastrlp`](https://crates.io/crates/fastrlp) and [`rlp`](https://crates.io/crates/rlp)
+//! support.
+
+#![cfg(any(
+    feature = "alloy-rlp",
+    feature = "fastrlp",
+    feature = "fastrlp-04",
+    feature = "rlp"
+))]
+#![cfg_attr(
+    docsrs,
+    doc(cfg(any(
+        feature = "alloy-rlp",
+        feature = "fastrlp",
+        feature = "fastrlp-04",
+        feature = "rlp"
+    )))
+)]
+
+use crate::Uint;
+
+/// Length of the RLP header for a string payload of `len` bytes.
+const fn length_of_length(len: usize) -> usize {
+    if len < 56 {
+        1
+    } else {
+        1 + size_of::<usize>() - len.leading_zeros() as usize / 8
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// The length of the largest RLP encoding of a value of this type, which
+    /// is that of [`Self::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::{U0, U256};
+    /// assert_eq!(U256::rlp_max_len(), 33);
+    /// assert_eq!(U0::rlp_max_len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn rlp_max_len() -> usize {
+        if BITS <= 7 {
+            1
+        } else {
+            Self::BYTES + length_of_length(Self::BYTES)
+        }
+    }
+
+    /// The length of the RLP encoding of `self`, without encoding it.
+    ///
+    /// Integers are encoded as big-endian strings without leading zeros, so
+    /// this depends on the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U256;
+    /// assert_eq!(U256::ZERO.rlp_len(), 1);
+    /// assert_eq!(U256::from(0x7f).rlp_len(), 1);
+    /// assert_eq!(U256::from(0x80).rlp_len(), 2);
+    /// assert_eq!(U256::from(1024).rlp_len(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn rlp_len(&self) -> usize {
+        let bits = self.bit_len();
+        if bits <= 7 {
+            1
+        } else {
+            let bytes = bits.div_ceil(8);
+            bytes + length_of_length(bytes)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[allow(unused_variables)]
+    fn check<const BITS: usize, const LIMBS: usize>(value: Uint<BITS, LIMBS>) {
+        let len = value.rlp_len();
+        assert!(len <= Uint::<BITS, LIMBS>::rlp_max_len());
+        #[cfg(feature = "alloy-rlp")]
+        assert_eq!(alloy_rlp::encode(value).len(), len);
+        #[cfg(feature = "fastrlp")]
+        {
+            let mut buf = vec![];
+            fastrlp_03::Encodable::encode(&value, &mut buf);
+            assert_eq!(buf.len(), len);
+        }
+        #[cfg(feature = "fastrlp-04")]
+        {
+            let mut buf = vec![];
+            fastrlp_04::Encodable::encode(&value, &mut buf);
+            assert_eq!(buf.len(), len);
+        }
+        #[cfg(feature = "rlp")]
+        assert_eq!(rlp::encode(&value).len(), len);
+    }
+
+    #[test]
+    fn test_rlp_len() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            check(U::ZERO);
+            check(U::MAX);
+            assert_eq!(U::MAX.rlp_len(), U::rlp_max_len());
+            proptest!(|(value: U)| {
+                check(value);
+            });
+        });
+        const_for!(BITS in [448, 1024, 4096] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            for bits in [439, 440, 441, 447, 448, BITS - 1] {
+                check(U::ONE << bits);
+            }
+        });
+    }
+}
@@ -0,0 +1,153 @@
+//! Support for the [`rug`](https://crates.io/crates/rug) crate.
+
+#![cfg(feature = "rug")]
+#![cfg_attr(docsrs, doc(cfg(feature = "rug")))]
+
+use crate::{Uint, from::ToUintError};
+use rug::{Integer, integer::Order};
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<Integer> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: Integer) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&Integer> for Uint<BITS, LIMBS> {
+    type Error = ToUintError<Self>;
+
+    #[inline]
+    fn try_from(value: &Integer) -> Result<Self, Self::Error> {
+        let (n, overflow) = if value.significant_digits::<u64>() <= LIMBS {
+            let mut limbs = [0; LIMBS];
+            value.write_digits(&mut limbs, Order::Lsf);
+            Self::overflowing_from_limbs_slice(&limbs)
+        } else {
+            // Only values that don't fit in `LIMBS` need to allocate.
+            Self::overflowing_from_limbs_slice(&value.to_digits(Order::Lsf))
+        };
+        if value.cmp0().is_lt() {
+            Err(ToUintError::ValueNegative(BITS, n.wrapping_neg()))
+        } else if overflow {
+            Err(ToUintError::ValueTooLarge(BITS, n))
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Integer {
+    #[inline]
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for Integer {
+    #[inline]
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        Self::from_digits(value.as_limbs(), Order::Lsf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use proptest::{proptest, test_runner::Config};
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big = Integer::from(value);
+                assert_eq!(big.significant_bits() as usize, value.bit_len());
+                assert_eq!(U::try_from(&big), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(U256::try_from(Integer::new()), Ok(U256::ZERO));
+        assert_eq!(
+            Uint::<0, 0>::try_from(Integer::from(1)),
+            Err(ToUintError::ValueTooLarge(0, Uint::ZERO))
+        );
+        assert_eq!(
+            U256::try_from(Integer::from(-1)),
+            Err(ToUintError::ValueNegative(256, U256::MAX))
+        );
+        assert_eq!(
+            U256::try_from(Integer::from(1) << 256_u32),
+            Err(ToUintError::ValueTooLarge(256, U256::ZERO))
+        );
+        assert_eq!(
+            Uint::<65, 2>::try_from((Integer::from(1) << 65_u32) + 3),
+            Err(ToUintError::ValueTooLarge(65, Uint::from(3)))
+        );
+        assert_eq!(
+            Uint::<65, 2>::try_from(-(Integer::from(1) << 64_u32)),
+            Err(ToUintError::ValueNegative(65, Uint::from(1_u128 << 64)))
+        );
+    }
+
+    /// Differential tests of the arithmetic against GMP.
+    mod differential {
+        use super::*;
+
+        #[test]
+        fn test_mul() {
+            const_for!(BITS in SIZES {
+                const LIMBS: usize = nlimbs(BITS);
+                type U = Uint<BITS, LIMBS>;
+                proptest!(|(a: U, b: U)| {
+                    let expected = (Integer::from(a) * Integer::from(b)).keep_bits(BITS as u32);
+                    assert_eq!(Integer::from(a.wrapping_mul(b)), expected);
+                });
+            });
+        }
+
+        #[test]
+        fn test_div_rem() {
+            const_for!(BITS in NON_ZERO {
+                const LIMBS: usize = nlimbs(BITS);
+                type U = Uint<BITS, LIMBS>;
+                proptest!(|(a: U, b: U)| {
+                    if b != U::ZERO {
+                        let (q, r) = Integer::from(a).div_rem(Integer::from(b));
+                        assert_eq!(Integer::from(a / b), q);
+                        assert_eq!(Integer::from(a % b), r);
+                    }
+                });
+            });
+        }
+
+        #[test]
+        fn test_pow_mod() {
+            const_for!(BITS in NON_ZERO {
+                const LIMBS: usize = nlimbs(BITS);
+                type U = Uint<BITS, LIMBS>;
+
+                // Too slow.
+                if LIMBS > 8 {
+                    return;
+                }
+
+                let config = Config { cases: 20, ..Default::default() };
+                proptest!(config, |(b: U, e: U, m: U)| {
+                    if m != U::ZERO {
+                        let expected = Integer::from(b)
+                            .pow_mod(&Integer::from(e), &Integer::from(m))
+                            .unwrap();
+                        assert_eq!(Integer::from(b.pow_mod(e, m)), expected);
+                    }
+                });
+            });
+        }
+    }
+}
@@ -3,17 +3,27 @@
 #![cfg(feature = "serde")]
 #![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 
-use crate::{Bits, Uint, fmt::StackString, nbytes};
-use core::{fmt, str};
+use crate::{Bits, ParseBitsError, Uint, fmt::StackString, nbytes};
+use core::{fmt, iter, marker::PhantomData, str};
 use serde_core::{
     Deserialize, Deserializer, Serialize, Serializer,
-    de::{Error, Unexpected, Visitor},
+    de::{Error, SeqAccess, Unexpected, Visitor},
 };
 
 /// Canonical serialization for all human-readable instances of `Uint<0, 0>`,
 /// and minimal human-readable `Uint<BITS, LIMBS>::ZERO` for any bit size.
 const ZERO_STR: &str = "0x0";
 
+/// Longest human readable string accepted by the lenient visitors, in bytes.
+///
+/// This is twice the length of the largest value in binary with a prefix, so
+/// it leaves room for leading zeros and `_` separators in any radix. Longer
+/// strings are rejected before parsing.
+const fn max_str_len(bits: usize) -> usize {
+    let bits = if bits < 64 { 64 } else { bits };
+    2 * (bits + 2)
+}
+
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     fn serialize_human<const FULL: bool, S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
         // Ideally `SIZE` is `2 + Self::BYTES * 2`.
@@ -53,6 +63,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 ///
 /// For human readable formats a `0x` prefixed lower case hex string is used.
 /// For binary formats a byte array is used. Leading zeros are included.
+///
+/// The human readable form is always a string, never a number, so [`Uint`] can
+/// be used as a map key in formats like JSON that require string keys.
 impl<const BITS: usize, const LIMBS: usize> Serialize for Uint<BITS, LIMBS> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         if serializer.is_human_readable() {
@@ -77,6 +90,11 @@ impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Uint<BITS,
     }
 }
 
+/// Serialize a [`Bits`] value.
+///
+/// For human readable formats a `0x` prefixed lower case hex string of exactly
+/// `2 * Bits::BYTES` digits is used. For binary formats a byte array of exactly
+/// `Bits::BYTES` bytes is used. Like [`Uint`], it can be used as a map key.
 impl<const BITS: usize, const LIMBS: usize> Serialize for Bits<BITS, LIMBS> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         if serializer.is_human_readable() {
@@ -87,9 +105,46 @@ impl<const BITS: usize, const LIMBS: usize> Serialize for Bits<BITS, LIMBS> {
     }
 }
 
+/// Deserialize human readable hex strings or byte arrays into [`Bits`].
+///
+/// Unlike [`Uint`], hex strings must have the `0x` prefix and exactly
+/// `2 * Bits::BYTES` digits, the same as [`Bits::from_str`](str::FromStr).
+/// Byte arrays must be exactly `Bits::BYTES` long.
 impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Bits<BITS, LIMBS> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Uint::deserialize(deserializer).map(Self::from)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BitsHrVisitor)
+        } else {
+            deserializer.deserialize_bytes(ByteVisitor).map(Self::from)
+        }
+    }
+}
+
+/// Serde Visitor for human readable [`Bits`].
+///
+/// Only accepts fixed-width `0x` prefixed hex strings.
+struct BitsHrVisitor<const BITS: usize, const LIMBS: usize>;
+
+impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for BitsHrVisitor<BITS, LIMBS> {
+    type Value = Bits<BITS, LIMBS>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "a 0x prefixed hex string with {} digits",
+            2 * nbytes(BITS)
+        )
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        // `Bits<0, 0>` is serialized as `ZERO_STR`.
+        if BITS == 0 && value == ZERO_STR {
+            return Ok(Bits::ZERO);
+        }
+        value.parse().map_err(|err| match err {
+            ParseBitsError::InvalidLength { actual, .. } => Error::invalid_length(actual, &self),
+            _ => Error::invalid_value(Unexpected::Str(value), &self),
+        })
     }
 }
 
@@ -124,6 +179,9 @@ impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for HrVisitor<BITS, LIMB
         if BITS == 0 {
             return Err(Error::invalid_value(Unexpected::Str(value), &self));
         }
+        if value.len() > max_str_len(BITS) {
+            return Err(Error::invalid_length(value.len(), &self));
+        }
 
         value
             .parse()
@@ -131,6 +189,491 @@ impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for HrVisitor<BITS, LIMB
     }
 }
 
+/// Strict deserialization of human readable hex strings, for use with
+/// `#[serde(with = "ruint::support::serde::strict")]`.
+///
+/// Human readable formats only accept a lower case `0x` prefix followed by at
+/// most `2 * Uint::BYTES` hex digits. Numbers, decimal strings, other prefixes
+/// and overlong strings, including those padded with extra leading zeros, are
+/// rejected. Serialization and binary formats are the same as the default
+/// implementation.
+///
+/// ```
+/// # use ruint::{aliases::U16, support::serde::strict};
+/// let parse = |s| strict::deserialize::<16, 1, _>(&mut serde_json::Deserializer::from_str(s));
+/// assert!(parse(r#""0x00ff""#).is_ok());
+/// assert!(parse(r#""0x000ff""#).is_err());
+/// assert!(parse(r#""0X00ff""#).is_err());
+/// ```
+pub mod strict {
+    use super::{ByteVisitor, ZERO_STR};
+    use crate::Uint;
+    use core::fmt;
+    use serde_core::{
+        Deserializer, Serialize, Serializer,
+        de::{Error, Unexpected, Visitor},
+    };
+
+    /// Serialize a [`Uint`] the same way as its [`Serialize`] implementation.
+    pub fn serialize<const BITS: usize, const LIMBS: usize, S: Serializer>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    /// Deserialize a [`Uint`], rejecting non-canonical hex strings.
+    pub fn deserialize<'de, const BITS: usize, const LIMBS: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrictHrVisitor)
+        } else {
+            deserializer.deserialize_bytes(ByteVisitor)
+        }
+    }
+
+    /// Serde Visitor for strict human readable formats.
+    struct StrictHrVisitor<const BITS: usize, const LIMBS: usize>;
+
+    impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for StrictHrVisitor<BITS, LIMBS> {
+        type Value = Uint<BITS, LIMBS>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                formatter,
+                "a 0x prefixed hex string with at most {} digits",
+                2 * Uint::<BITS, LIMBS>::BYTES
+            )
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+            // `ZERO_STR` is the canonical serialization of `Uint<0, 0>`.
+            if value == ZERO_STR {
+                return Ok(Uint::ZERO);
+            }
+            let digits = value
+                .strip_prefix("0x")
+                .filter(|digits| {
+                    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_hexdigit())
+                })
+                .ok_or_else(|| Error::invalid_value(Unexpected::Str(value), &self))?;
+            if digits.len() > 2 * Uint::<BITS, LIMBS>::BYTES {
+                return Err(Error::invalid_length(digits.len(), &self));
+            }
+            Uint::from_str_radix(digits, 16).map_err(|_| {
+                Error::invalid_value(
+                    Unexpected::Other(&format!("{value}, too large for Uint<{BITS}>")),
+                    &self,
+                )
+            })
+        }
+    }
+}
+
+/// Serialize a [`Uint`] as a JSON number, for use with
+/// `#[serde(with = "ruint::support::serde::json_number")]`.
+///
+/// With the `serde-arbitrary-precision` feature, which enables the
+/// `arbitrary_precision` feature of `serde_json`, numbers of any size are
+/// written as unquoted decimal digits and read back exactly, also through a
+/// `serde_json::Value`.
+///
+/// Without it, only values that fit in a [`u64`] can be serialized or
+/// deserialized. Larger values are an error rather than a float that
+/// silently loses precision.
+///
+/// ```
+/// # use ruint::{aliases::U256, support::serde::json_number};
+/// let value = U256::from(42);
+/// let json = json_number::serialize(&value, serde_json::value::Serializer).unwrap();
+/// assert!(json.is_number());
+/// assert_eq!(json_number::deserialize::<256, 4, _>(json).unwrap(), value);
+/// ```
+pub mod json_number {
+    use crate::Uint;
+    #[cfg(feature = "serde-arbitrary-precision")]
+    use crate::fmt::StackString;
+    #[cfg(feature = "serde-arbitrary-precision")]
+    use alloc::string::ToString;
+    #[cfg(not(feature = "serde-arbitrary-precision"))]
+    use core::fmt;
+    #[cfg(feature = "serde-arbitrary-precision")]
+    use core::fmt::Write;
+    #[cfg(not(feature = "serde-arbitrary-precision"))]
+    use serde_core::de::{Error, Unexpected, Visitor};
+    #[cfg(feature = "serde-arbitrary-precision")]
+    use serde_core::{Deserialize, Serialize, de::Error as _};
+    use serde_core::{Deserializer, Serializer, ser::Error as _};
+
+    /// Serialize a [`Uint`] as a JSON number.
+    ///
+    /// # Errors
+    ///
+    /// Without the `serde-arbitrary-precision` feature, returns an error if
+    /// the value does not fit in a [`u64`].
+    pub fn serialize<const BITS: usize, const LIMBS: usize, S: Serializer>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if let Ok(value) = u64::try_from(value) {
+            return serializer.serialize_u64(value);
+        }
+        #[cfg(feature = "serde-arbitrary-precision")]
+        {
+            // Decimal digits are fewer than bits for values beyond `u64`.
+            let mut digits = StackString::<BITS>::new();
+            write!(digits, "{value}").map_err(S::Error::custom)?;
+            let number: serde_json::Number = digits.as_str().parse().map_err(S::Error::custom)?;
+            number.serialize(serializer)
+        }
+        #[cfg(not(feature = "serde-arbitrary-precision"))]
+        Err(S::Error::custom(format_args!(
+            "{value} does not fit a JSON number without the serde-arbitrary-precision feature"
+        )))
+    }
+
+    /// Deserialize a [`Uint`] from a JSON number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number is negative, has a fraction or exponent,
+    /// or is too large for the [`Uint`]. Without the
+    /// `serde-arbitrary-precision` feature, numbers that do not fit in a
+    /// [`u64`] are also an error.
+    pub fn deserialize<'de, const BITS: usize, const LIMBS: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        #[cfg(feature = "serde-arbitrary-precision")]
+        {
+            let number = serde_json::Number::deserialize(deserializer)?;
+            Uint::from_dec_str(&number.to_string()).map_err(|_| {
+                D::Error::custom(format_args!("invalid number {number} for Uint<{BITS}>"))
+            })
+        }
+        #[cfg(not(feature = "serde-arbitrary-precision"))]
+        {
+            deserializer.deserialize_u64(NumberVisitor)
+        }
+    }
+
+    /// Serde Visitor for JSON numbers that fit in a [`u64`].
+    #[cfg(not(feature = "serde-arbitrary-precision"))]
+    struct NumberVisitor<const BITS: usize, const LIMBS: usize>;
+
+    #[cfg(not(feature = "serde-arbitrary-precision"))]
+    impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for NumberVisitor<BITS, LIMBS> {
+        type Value = Uint<BITS, LIMBS>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(formatter, "a number that fits Uint<{BITS}> and u64")
+        }
+
+        fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+            Uint::try_from(v).map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+    }
+}
+
+/// A [`Uint`] that is always serialized as a decimal string.
+struct DecimalStr<T>(T);
+
+/// A [`Uint`] that is always serialized as a `0x` prefixed hex string.
+struct HexStr<T>(T);
+
+impl<const BITS: usize, const LIMBS: usize> Serialize for DecimalStr<&Uint<BITS, LIMBS>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self.0)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Serialize for HexStr<&Uint<BITS, LIMBS>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:#x}", self.0))
+    }
+}
+
+/// A [`Uint`] deserialized from a decimal or hex string.
+struct RadixStr<const RADIX: u64, const BITS: usize, const LIMBS: usize>(Uint<BITS, LIMBS>);
+
+impl<'de, const RADIX: u64, const BITS: usize, const LIMBS: usize> Deserialize<'de>
+    for RadixStr<RADIX, BITS, LIMBS>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_str(RadixVisitor::<RADIX, BITS, LIMBS>)
+            .map(Self)
+    }
+}
+
+/// Serde Visitor for decimal or hex strings.
+///
+/// Hex strings may have a `0x` prefix, decimal strings have none.
+struct RadixVisitor<const RADIX: u64, const BITS: usize, const LIMBS: usize>;
+
+impl<const RADIX: u64, const BITS: usize, const LIMBS: usize> Visitor<'_>
+    for RadixVisitor<RADIX, BITS, LIMBS>
+{
+    type Value = Uint<BITS, LIMBS>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if RADIX == 16 {
+            write!(formatter, "a hex string that fits Uint<{BITS}>")
+        } else {
+            write!(formatter, "a decimal string that fits Uint<{BITS}>")
+        }
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        let digits = if RADIX == 16 {
+            value.strip_prefix("0x").unwrap_or(value)
+        } else {
+            value
+        };
+        if digits.is_empty() {
+            return Err(Error::invalid_value(Unexpected::Str(value), &self));
+        }
+        if value.len() > max_str_len(BITS) {
+            return Err(Error::invalid_length(value.len(), &self));
+        }
+        Uint::from_str_radix(digits, RADIX)
+            .map_err(|_| Error::invalid_value(Unexpected::Str(value), &self))
+    }
+}
+
+/// Serde Visitor for sequences of [`RadixStr`].
+struct SeqVisitor<const RADIX: u64, C, const BITS: usize, const LIMBS: usize>(
+    PhantomData<fn() -> C>,
+);
+
+impl<'de, const RADIX: u64, C, const BITS: usize, const LIMBS: usize> Visitor<'de>
+    for SeqVisitor<RADIX, C, BITS, LIMBS>
+where
+    C: FromIterator<Uint<BITS, LIMBS>>,
+{
+    type Value = C;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence of Uint strings")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut error = None;
+        let collection = iter::from_fn(|| {
+            seq.next_element::<RadixStr<RADIX, BITS, LIMBS>>()
+                .map_err(|err| error = Some(err))
+                .ok()
+                .flatten()
+                .map(|value| value.0)
+        })
+        .collect();
+        error.map_or(Ok(collection), Err)
+    }
+}
+
+/// Serialize a collection of [`Uint`]s as a sequence of decimal strings, for
+/// use with `#[serde(with = "ruint::support::serde::vec_decimal")]`.
+///
+/// Works with any collection that can be iterated by reference and collected,
+/// like `Vec`, `VecDeque` or `BTreeSet`. The strings are used in binary formats
+/// too.
+///
+/// ```
+/// # use ruint::aliases::U256;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Balances {
+///     #[serde(with = "ruint::support::serde::vec_decimal")]
+///     values: Vec<U256>,
+/// }
+///
+/// let balances = Balances {
+///     values: vec![U256::from(10), U256::MAX],
+/// };
+/// let json = serde_json::to_string(&balances).unwrap();
+/// assert!(json.starts_with(r#"{"values":["10","1157920892373161954"#));
+/// let balances: Balances = serde_json::from_str(&json).unwrap();
+/// assert_eq!(balances.values[1], U256::MAX);
+/// ```
+pub mod vec_decimal {
+    use super::{DecimalStr, SeqVisitor};
+    use crate::Uint;
+    use core::marker::PhantomData;
+    use serde_core::{Deserializer, Serializer};
+
+    /// Serialize the [`Uint`]s as a sequence of decimal strings.
+    pub fn serialize<'a, const BITS: usize, const LIMBS: usize, C, S>(
+        values: &'a C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a C: IntoIterator<Item = &'a Uint<BITS, LIMBS>>,
+        S: Serializer,
+    {
+        serializer.collect_seq(values.into_iter().map(DecimalStr))
+    }
+
+    /// Deserialize a sequence of decimal strings into a collection of
+    /// [`Uint`]s.
+    pub fn deserialize<'de, const BITS: usize, const LIMBS: usize, C, D>(
+        deserializer: D,
+    ) -> Result<C, D::Error>
+    where
+        C: FromIterator<Uint<BITS, LIMBS>>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeqVisitor::<10, C, BITS, LIMBS>(PhantomData))
+    }
+}
+
+/// Serialize a collection of [`Uint`]s as a sequence of `0x` prefixed hex
+/// strings, for use with `#[serde(with = "ruint::support::serde::vec_hex")]`.
+///
+/// Unlike the default implementation, the strings are used in binary formats
+/// too. Deserialization accepts hex digits with or without the `0x` prefix.
+pub mod vec_hex {
+    use super::{HexStr, SeqVisitor};
+    use crate::Uint;
+    use core::marker::PhantomData;
+    use serde_core::{Deserializer, Serializer};
+
+    /// Serialize the [`Uint`]s as a sequence of hex strings.
+    pub fn serialize<'a, const BITS: usize, const LIMBS: usize, C, S>(
+        values: &'a C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a C: IntoIterator<Item = &'a Uint<BITS, LIMBS>>,
+        S: Serializer,
+    {
+        serializer.collect_seq(values.into_iter().map(HexStr))
+    }
+
+    /// Deserialize a sequence of hex strings into a collection of [`Uint`]s.
+    pub fn deserialize<'de, const BITS: usize, const LIMBS: usize, C, D>(
+        deserializer: D,
+    ) -> Result<C, D::Error>
+    where
+        C: FromIterator<Uint<BITS, LIMBS>>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeqVisitor::<16, C, BITS, LIMBS>(PhantomData))
+    }
+}
+
+/// Serialize an `Option<Uint>` as a decimal string or `None`, for use with
+/// `#[serde(with = "ruint::support::serde::opt_decimal")]`.
+pub mod opt_decimal {
+    use super::{DecimalStr, RadixStr};
+    use crate::Uint;
+    use serde_core::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize the [`Uint`], if any, as a decimal string.
+    pub fn serialize<const BITS: usize, const LIMBS: usize, S: Serializer>(
+        value: &Option<Uint<BITS, LIMBS>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_some(&DecimalStr(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an optional decimal string into an `Option<Uint>`.
+    pub fn deserialize<'de, const BITS: usize, const LIMBS: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Uint<BITS, LIMBS>>, D::Error> {
+        let value = Option::<RadixStr<10, BITS, LIMBS>>::deserialize(deserializer)?;
+        Ok(value.map(|value| value.0))
+    }
+}
+
+/// Serialize the values of a map of [`Uint`]s as decimal strings, for use with
+/// `#[serde(with = "ruint::support::serde::map_values_decimal")]`.
+///
+/// Works with any map that can be iterated by reference and collected, like
+/// `HashMap` or `BTreeMap`. The keys use their own [`Serialize`]
+/// implementation.
+///
+/// ```
+/// # use ruint::aliases::U256;
+/// # use std::collections::BTreeMap;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Supply {
+///     #[serde(with = "ruint::support::serde::map_values_decimal")]
+///     tokens: BTreeMap<String, U256>,
+/// }
+///
+/// let supply = Supply {
+///     tokens: BTreeMap::from([("eth".to_string(), U256::from(42))]),
+/// };
+/// let json = serde_json::to_string(&supply).unwrap();
+/// assert_eq!(json, r#"{"tokens":{"eth":"42"}}"#);
+/// ```
+pub mod map_values_decimal {
+    use super::{DecimalStr, RadixStr};
+    use crate::Uint;
+    use core::{fmt, iter, marker::PhantomData};
+    use serde_core::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{MapAccess, Visitor},
+    };
+
+    /// Serialize the map with the [`Uint`] values as decimal strings.
+    pub fn serialize<'a, const BITS: usize, const LIMBS: usize, K, M, S>(
+        map: &'a M,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a M: IntoIterator<Item = (&'a K, &'a Uint<BITS, LIMBS>)>,
+        K: Serialize + 'a,
+        S: Serializer,
+    {
+        serializer.collect_map(map.into_iter().map(|(key, value)| (key, DecimalStr(value))))
+    }
+
+    /// Deserialize a map with decimal string values into a map of [`Uint`]s.
+    pub fn deserialize<'de, const BITS: usize, const LIMBS: usize, K, M, D>(
+        deserializer: D,
+    ) -> Result<M, D::Error>
+    where
+        K: Deserialize<'de>,
+        M: FromIterator<(K, Uint<BITS, LIMBS>)>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MapVisitor::<K, M, BITS, LIMBS>(PhantomData))
+    }
+
+    /// Serde Visitor for maps with decimal string values.
+    struct MapVisitor<K, M, const BITS: usize, const LIMBS: usize>(PhantomData<fn() -> (K, M)>);
+
+    impl<'de, K, M, const BITS: usize, const LIMBS: usize> Visitor<'de>
+        for MapVisitor<K, M, BITS, LIMBS>
+    where
+        K: Deserialize<'de>,
+        M: FromIterator<(K, Uint<BITS, LIMBS>)>,
+    {
+        type Value = M;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map with decimal string values")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+            let mut error = None;
+            let map = iter::from_fn(|| {
+                access
+                    .next_entry::<K, RadixStr<10, BITS, LIMBS>>()
+                    .map_err(|err| error = Some(err))
+                    .ok()
+                    .flatten()
+                    .map(|(key, value)| (key, value.0))
+            })
+            .collect();
+            error.map_or(Ok(map), Err)
+        }
+    }
+}
+
 /// Serde Visitor for non-human readable formats
 struct ByteVisitor<const BITS: usize, const LIMBS: usize>;
 
@@ -206,6 +749,275 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_serde_length_limit() {
+        type U = Uint<64, 1>;
+        let limit = max_str_len(64);
+        let padded =
+            |len: usize, digits: &str| format!("\"{}{digits}\"", "0".repeat(len - digits.len()));
+        assert_eq!(
+            serde_json::from_str::<U>(&padded(limit, "1")).unwrap(),
+            U::ONE
+        );
+        assert_eq!(
+            serde_json::from_str::<U>(&padded(limit + 1, "1"))
+                .unwrap_err()
+                .to_string(),
+            format!(
+                "invalid length {}, expected a 8 byte hex string at line 1 column {}",
+                limit + 1,
+                limit + 3
+            )
+        );
+        let binary = format!("\"0b{}\"", ["1111"; 16].join("_"));
+        assert_eq!(serde_json::from_str::<U>(&binary).unwrap(), U::MAX);
+
+        // Megabytes of leading zeros, which would parse, are rejected up front.
+        let long = padded(1 << 20, "1");
+        assert!(serde_json::from_str::<U>(&long).is_err());
+        assert!(serde_json::from_str::<U>(&format!("\"0x{}", &long[1..])).is_err());
+        let list = format!("[{long}]");
+        let mut de = serde_json::Deserializer::from_str(&list);
+        assert!(vec_decimal::deserialize::<64, 1, Vec<_>, _>(&mut de).is_err());
+        let mut de = serde_json::Deserializer::from_str(&list);
+        assert!(vec_hex::deserialize::<64, 1, Vec<_>, _>(&mut de).is_err());
+
+        // Larger types allow proportionally longer strings.
+        let max = Uint::<4096, 64>::MAX;
+        let binary = format!("\"{max:#b}\"");
+        assert_eq!(
+            serde_json::from_str::<Uint<4096, 64>>(&binary).unwrap(),
+            max
+        );
+    }
+
+    #[test]
+    fn test_serde_strict() {
+        fn parse<const BITS: usize, const LIMBS: usize>(
+            s: &str,
+        ) -> Result<Uint<BITS, LIMBS>, String> {
+            strict::deserialize(&mut serde_json::Deserializer::from_str(s))
+                .map_err(|e| e.to_string())
+        }
+
+        // Exactly canonical and minimal input is accepted.
+        assert_eq!(parse::<16, 1>(r#""0x00ff""#), Ok(Uint::from(0xff)));
+        assert_eq!(parse::<16, 1>(r#""0xff""#), Ok(Uint::from(0xff)));
+        assert_eq!(parse::<16, 1>(r#""0xFF""#), Ok(Uint::from(0xff)));
+        assert_eq!(parse::<12, 1>(r#""0x0fff""#), Ok(Uint::from(0xfff)));
+        assert_eq!(parse::<0, 0>(r#""0x0""#), Ok(Uint::ZERO));
+
+        // Overlong, including zero padding beyond the canonical length.
+        assert_eq!(
+            parse::<16, 1>(r#""0x000ff""#).unwrap_err(),
+            "invalid length 5, expected a 0x prefixed hex string with at most 4 digits at line 1 \
+             column 9"
+        );
+        assert!(parse::<16, 1>(&format!("\"0x{}1\"", "0".repeat(300))).is_err());
+        assert!(parse::<0, 0>(r#""0x00""#).is_err());
+
+        // Prefix errors.
+        assert_eq!(
+            parse::<16, 1>(r#""00ff""#).unwrap_err(),
+            "invalid value: string \"00ff\", expected a 0x prefixed hex string with at most 4 \
+             digits at line 1 column 6"
+        );
+        assert!(parse::<16, 1>(r#""0X00ff""#).is_err());
+        assert!(parse::<16, 1>(r#""0x""#).is_err());
+        assert!(parse::<16, 1>(r#""0x_ff""#).is_err());
+        assert!(parse::<16, 1>(r#""0b1""#).is_err());
+        assert!(parse::<16, 1>("255").is_err());
+
+        // Fits the byte length, but not the bit size.
+        assert!(parse::<12, 1>(r#""0x1fff""#).is_err());
+
+        // Canonical serialization round trips.
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Bits<BITS, LIMBS>)| {
+                let serialized = serde_json::to_string(&value).unwrap();
+                assert_eq!(parse::<BITS, LIMBS>(&serialized), Ok(value.into_inner()));
+            });
+        });
+
+        // The lenient default still accepts the rejected forms.
+        assert!(serde_json::from_str::<Uint<16, 1>>(r#""0X00ff""#).is_ok());
+        assert!(serde_json::from_str::<Uint<16, 1>>(r#""0x000ff""#).is_ok());
+    }
+
+    #[test]
+    fn test_serde_bits_strict() {
+        type B = Bits<16, 1>;
+        let parse = |s: &str| serde_json::from_str::<B>(s).map_err(|e| e.to_string());
+        assert_eq!(parse(r#""0x00ff""#), Ok(B::from_limbs([0xff])));
+        assert_eq!(parse(r#""0x00FF""#), Ok(B::from_limbs([0xff])));
+        assert_eq!(
+            parse(r#""0xff""#).unwrap_err(),
+            "invalid length 2, expected a 0x prefixed hex string with 4 digits at line 1 column 6"
+        );
+        assert!(parse(r#""0x000ff""#).is_err());
+        assert_eq!(
+            parse(r#""00ff""#).unwrap_err(),
+            "invalid value: string \"00ff\", expected a 0x prefixed hex string with 4 digits at \
+             line 1 column 6"
+        );
+        assert!(parse(r#""0x00fg""#).is_err());
+        assert!(parse("255").is_err());
+        assert_eq!(
+            serde_json::from_str::<Bits<0, 0>>(r#""0x0""#).unwrap(),
+            Bits::ZERO
+        );
+        assert_eq!(
+            serde_json::from_str::<Bits<0, 0>>(r#""0x""#).unwrap(),
+            Bits::ZERO
+        );
+        assert!(bincode::deserialize::<B>(&bincode::serialize(&[0xff_u8][..]).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_json_number() {
+        type U = Uint<128, 2>;
+        let to_json = |value: &U| {
+            let mut buf = Vec::new();
+            json_number::serialize(value, &mut serde_json::Serializer::new(&mut buf))
+                .map(|()| String::from_utf8(buf).unwrap())
+        };
+        let from_json = |s: &str| {
+            let mut deserializer = serde_json::Deserializer::from_str(s);
+            let value = json_number::deserialize::<128, 2, _>(&mut deserializer)?;
+            deserializer.end().map(|()| value)
+        };
+
+        let max = U::from(u64::MAX);
+        assert_eq!(to_json(&max).unwrap(), "18446744073709551615");
+        assert_eq!(from_json("18446744073709551615").unwrap(), max);
+        assert_eq!(from_json("0").unwrap(), U::ZERO);
+        assert!(from_json("-1").is_err());
+        assert!(from_json("1.5").is_err());
+        assert!(from_json("1e3").is_err());
+        assert!(from_json(r#""1""#).is_err());
+        assert!(
+            json_number::deserialize::<8, 1, _>(&mut serde_json::Deserializer::from_str("256"))
+                .is_err()
+        );
+
+        let large = U::ONE << 100;
+        if cfg!(feature = "serde-arbitrary-precision") {
+            assert_eq!(to_json(&large).unwrap(), "1267650600228229401496703205376");
+            assert_eq!(from_json("1267650600228229401496703205376").unwrap(), large);
+            assert_eq!(from_json(&to_json(&U::MAX).unwrap()).unwrap(), U::MAX);
+            assert!(from_json("340282366920938463463374607431768211456").is_err());
+
+            let value = json_number::serialize(&large, serde_json::value::Serializer).unwrap();
+            assert!(value.is_number());
+            assert_eq!(value.to_string(), "1267650600228229401496703205376");
+            assert_eq!(json_number::deserialize::<128, 2, _>(value).unwrap(), large);
+        } else {
+            assert!(to_json(&large).is_err());
+            assert!(to_json(&(max + U::ONE)).is_err());
+            assert!(from_json("1267650600228229401496703205376").is_err());
+            assert!(from_json("18446744073709551616").is_err());
+        }
+    }
+
+    #[test]
+    fn test_serde_collections() {
+        use crate::aliases::U256;
+        use std::collections::{BTreeMap, HashMap, VecDeque};
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Collections {
+            #[serde(with = "vec_decimal")]
+            decimal:  Vec<U256>,
+            #[serde(with = "vec_hex")]
+            hex:      VecDeque<U256>,
+            #[serde(with = "opt_decimal")]
+            optional: Option<U256>,
+            #[serde(with = "map_values_decimal")]
+            map:      HashMap<String, U256>,
+            #[serde(with = "map_values_decimal")]
+            keys:     BTreeMap<U256, U256>,
+        }
+
+        let value = Collections {
+            decimal:  vec![U256::ZERO, U256::from(10), U256::MAX],
+            hex:      VecDeque::from([U256::from(0xff), U256::ZERO]),
+            optional: Some(U256::from(12345)),
+            map:      HashMap::from([("a".to_string(), U256::from(7))]),
+            keys:     BTreeMap::from([(U256::from(0xff), U256::from(255))]),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"decimal":["0","10","{}"],"hex":["0xff","0x0"],"optional":"12345",{}"#,
+                U256::MAX,
+                r#""map":{"a":"7"},"keys":{"0xff":"255"}}"#
+            )
+        );
+        assert_eq!(serde_json::from_str::<Collections>(&json).unwrap(), value);
+        let binary = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<Collections>(&binary).unwrap(), value);
+
+        let empty = Collections {
+            decimal:  Vec::new(),
+            hex:      VecDeque::new(),
+            optional: None,
+            map:      HashMap::new(),
+            keys:     BTreeMap::new(),
+        };
+        let json = serde_json::to_string(&empty).unwrap();
+        assert_eq!(
+            json,
+            r#"{"decimal":[],"hex":[],"optional":null,"map":{},"keys":{}}"#
+        );
+        assert_eq!(serde_json::from_str::<Collections>(&json).unwrap(), empty);
+        let binary = bincode::serialize(&empty).unwrap();
+        assert_eq!(bincode::deserialize::<Collections>(&binary).unwrap(), empty);
+
+        proptest!(|(values: Vec<Uint<100, 2>>, optional: Option<Uint<100, 2>>)| {
+            let mut buf = Vec::new();
+            vec_decimal::serialize(&values, &mut serde_json::Serializer::new(&mut buf)).unwrap();
+            let mut de = serde_json::Deserializer::from_slice(&buf);
+            assert_eq!(vec_decimal::deserialize::<100, 2, Vec<_>, _>(&mut de).unwrap(), values);
+
+            let mut buf = Vec::new();
+            vec_hex::serialize(&values, &mut serde_json::Serializer::new(&mut buf)).unwrap();
+            let mut de = serde_json::Deserializer::from_slice(&buf);
+            assert_eq!(vec_hex::deserialize::<100, 2, Vec<_>, _>(&mut de).unwrap(), values);
+
+            let json = opt_decimal::serialize(&optional, serde_json::value::Serializer).unwrap();
+            assert_eq!(opt_decimal::deserialize::<100, 2, _>(json).unwrap(), optional);
+        });
+
+        // Hex digits without prefix are accepted, other forms are rejected.
+        let parse_hex = |s: &str| {
+            vec_hex::deserialize::<8, 1, Vec<_>, _>(&mut serde_json::Deserializer::from_str(s))
+        };
+        assert_eq!(parse_hex(r#"["ff","0xFF"]"#).unwrap(), vec![
+            Uint::from(0xff);
+            2
+        ]);
+        assert!(parse_hex(r#"["0x100"]"#).is_err());
+        assert!(parse_hex(r#"["0x"]"#).is_err());
+        assert!(parse_hex("[255]").is_err());
+        let parse_dec = |s: &str| {
+            vec_decimal::deserialize::<8, 1, Vec<_>, _>(&mut serde_json::Deserializer::from_str(s))
+        };
+        assert_eq!(parse_dec(r#"["255"]"#).unwrap(), vec![Uint::from(255)]);
+        assert_eq!(
+            parse_dec(r#"["256"]"#).unwrap_err().to_string(),
+            "invalid value: string \"256\", expected a decimal string that fits Uint<8> at line 1 \
+             column 6"
+        );
+        assert!(parse_dec(r#"["0xff"]"#).is_err());
+        assert!(parse_dec(r#"[""]"#).is_err());
+        assert!(parse_dec(r#"[255]"#).is_err());
+        let mut de = serde_json::Deserializer::from_str(r#"{"a":"1","b":"x"}"#);
+        let map = map_values_decimal::deserialize::<8, 1, String, HashMap<_, _>, _>(&mut de);
+        assert!(map.is_err());
+    }
+
     #[test]
     fn test_serde_machine_readable() {
         const_for!(BITS in SIZES {
@@ -223,6 +1035,39 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_serialize_as_map_key() {
+        use crate::aliases::{B256, U256};
+        use std::collections::HashMap;
+
+        proptest!(|(map: HashMap<U256, u32>)| {
+            let json = serde_json::to_string(&map).unwrap();
+            let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+            let object = value.as_object().unwrap();
+            assert_eq!(object.len(), map.len());
+            for (key, value) in &map {
+                assert_eq!(object[&format!("{key:#x}")], *value);
+            }
+            assert_eq!(serde_json::from_str::<HashMap<U256, u32>>(&json).unwrap(), map);
+
+            let binary = bincode::serialize(&map).unwrap();
+            assert_eq!(bincode::deserialize::<HashMap<U256, u32>>(&binary).unwrap(), map);
+        });
+        proptest!(|(map: HashMap<B256, u32>)| {
+            let json = serde_json::to_string(&map).unwrap();
+            assert_eq!(serde_json::from_str::<HashMap<B256, u32>>(&json).unwrap(), map);
+
+            let binary = bincode::serialize(&map).unwrap();
+            assert_eq!(bincode::deserialize::<HashMap<B256, u32>>(&binary).unwrap(), map);
+        });
+
+        let map = HashMap::from([(U256::ZERO, 0), (U256::from(0xff), 1)]);
+        let json = serde_json::to_string(&std::collections::BTreeMap::from_iter(map)).unwrap();
+        assert_eq!(json, r#"{"0x0":0,"0xff":1}"#);
+        let map = HashMap::from([(Uint::<0, 0>::ZERO, 0)]);
+        assert_eq!(serde_json::to_string(&map).unwrap(), r#"{"0x0":0}"#);
+    }
+
     #[test]
     fn test_serde_invalid_size_error() {
         // Test that if we add a character to a value that is already the max length for
@@ -4,6 +4,25 @@ use ssz::{Decode, DecodeError, Encode};
 
 use crate::{Uint, nbytes};
 
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// The length of the SSZ encoding of any value of this type.
+    ///
+    /// Same as [`Encode::ssz_fixed_len`], but usable in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::{U160, U256};
+    /// assert_eq!(U256::ssz_fixed_len(), 32);
+    /// assert_eq!(U160::ssz_fixed_len(), 20);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ssz_fixed_len() -> usize {
+        nbytes(BITS)
+    }
+}
+
 impl<const BITS: usize, const LIMBS: usize> Encode for Uint<BITS, LIMBS> {
     fn is_ssz_fixed_len() -> bool {
         true
@@ -62,6 +81,21 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_ssz_fixed_len() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let len = U::ssz_fixed_len();
+            assert_eq!(<U as ssz::Encode>::ssz_fixed_len(), len);
+            assert_eq!(ssz::Encode::as_ssz_bytes(&U::ZERO).len(), len);
+            assert_eq!(ssz::Encode::as_ssz_bytes(&U::MAX).len(), len);
+            proptest!(|(value: U)| {
+                assert_eq!(ssz::Encode::as_ssz_bytes(&value).len(), len);
+            });
+        });
+    }
+
     #[test]
     fn test_ssz_decode_error_length() {
         const_for!(BITS in SIZES {
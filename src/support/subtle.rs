@@ -6,8 +6,13 @@
 use crate::Uint;
 use subtle::{
     Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+    CtOption,
 };
 
+/// The window size in bits of [`Uint::pow_mod_ct`]. It divides 64, so windows
+/// never straddle limbs.
+const WINDOW: usize = 4;
+
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Returns a [`Choice`] if the bit at index is set.
     ///
@@ -22,6 +27,192 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         let (limbs, bits) = (index / 64, index % 64);
         (self.limbs[limbs] & (1 << bits)).ct_eq(&(1 << bits))
     }
+
+    /// The number of iterations of [`Uint::inv_mod_ct`].
+    ///
+    /// Each iteration shortens `a` or `b` by at least one bit until `a` is
+    /// zero, and they start with at most `2 * BITS` bits between them.
+    const INV_MOD_CT_ITERATIONS: usize = (2 * BITS).saturating_sub(1);
+
+    /// Compute $\mod{\mathtt{self}^{-1}}_{\mathtt{modulus}}$ without branches
+    /// or memory accesses that depend on `self`.
+    ///
+    /// The `modulus` must be odd, which covers all prime moduli used in
+    /// cryptography. The result is none if the modulus is even or one, or if
+    /// the inverse does not exist. Whether the result is some is the only
+    /// information that depends on `self`, and it is returned as a
+    /// [`CtOption`].
+    ///
+    /// This is the constant-time binary extended Euclidean algorithm (see
+    /// Pornin, [Optimized Binary GCD for Modular Inversion][bingcd]), running
+    /// a fixed `2 * BITS - 1` iterations. It is considerably slower than
+    /// [`Uint::inv_mod`].
+    ///
+    /// Note that the timing is only as constant as the compiler and CPU allow.
+    /// No guarantee is made, and the code has not been audited.
+    ///
+    /// [bingcd]: https://eprint.iacr.org/2020/972
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// let n = 0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141_U256;
+    /// let k = 0x3b9aca07_U256;
+    /// let k_inv = k.inv_mod_ct(n).unwrap();
+    /// assert_eq!(k.mul_mod(k_inv, n), 1_U256);
+    /// assert!(bool::from(0_U256.inv_mod_ct(n).is_none()));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn inv_mod_ct(self, modulus: Self) -> CtOption<Self> {
+        if BITS == 0 {
+            return CtOption::new(Self::ZERO, Choice::from(0));
+        }
+        let half_modulus = (modulus >> 1_usize) + Self::ONE;
+
+        // Invariants: `a = u * self` and `b = v * self` modulo `modulus`, and
+        // `b` is odd.
+        let (mut a, mut b) = (self, modulus);
+        let (mut u, mut v) = (Self::ONE, Self::ZERO);
+        for _ in 0..Self::INV_MOD_CT_ITERATIONS {
+            // If `a` is odd, make sure `a >= b` and subtract `b`.
+            let a_odd = a.bit_ct(0);
+            let swap = a_odd & a.ct_lt(&b);
+            Self::conditional_swap(&mut a, &mut b, swap);
+            Self::conditional_swap(&mut u, &mut v, swap);
+            a = a.wrapping_sub(Self::conditional_select(&Self::ZERO, &b, a_odd));
+            let (diff, borrow) =
+                u.overflowing_sub(Self::conditional_select(&Self::ZERO, &v, a_odd));
+            let borrow = Choice::from(u8::from(borrow));
+            u = diff.wrapping_add(Self::conditional_select(&Self::ZERO, &modulus, borrow));
+
+            // `a` is now even, halve it. Halving `u` modulo the odd `modulus`
+            // is `(u + modulus) / 2` if `u` is odd.
+            a >>= 1_usize;
+            let u_odd = u.bit_ct(0);
+            u = (u >> 1_usize) + Self::conditional_select(&Self::ZERO, &half_modulus, u_odd);
+        }
+
+        // Now `a` is zero and `b` is the gcd.
+        let is_some = b.ct_eq(&Self::ONE) & modulus.bit_ct(0) & !modulus.ct_eq(&Self::ONE);
+        CtOption::new(v, is_some)
+    }
+
+    /// Compute $\mod{\mathtt{self}^{\mathtt{exp}}}_{\mathtt{modulus}}$
+    /// without branches or memory accesses that depend on `exp`.
+    ///
+    /// Returns zero if the modulus is zero or one, like [`Uint::pow_mod`].
+    /// Only the exponent is protected: `self` is reduced with the variable
+    /// time [`Uint::reduce_mod`] and the set-up depends on the modulus.
+    ///
+    /// This uses Montgomery multiplication and a fixed window of 4 bits.
+    /// Every window of the exponent, including leading zero windows, costs
+    /// the same squarings and one multiplication, and its table entry is
+    /// picked by reading all entries and keeping one with
+    /// [`ConditionallySelectable`]. The sequence of operations and the
+    /// addresses read are therefore the same for all exponents of a type, and
+    /// the only secret-dependent values flow through limb arithmetic and
+    /// masked selects. The Montgomery multiplication ends with a select
+    /// rather than a branch on its final subtraction.
+    ///
+    /// Note that the timing is only as constant as the compiler and CPU allow.
+    /// No guarantee is made, and the code has not been audited. See
+    /// [`Uint::pow_mod_ct_blinded`] to also randomize the exponent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is even and larger than one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint! {
+    /// let p = 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f_U256;
+    /// let secret = 0x3b9aca07_U256;
+    /// assert_eq!(3_U256.pow_mod_ct(&secret, &p), 3_U256.pow_mod(secret, p));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn pow_mod_ct(self, exp: &Self, modulus: &Self) -> Self {
+        self.pow_mod_ct_blinded(exp, &Self::ZERO, modulus)
+    }
+
+    /// Compute $\mod{\mathtt{self}^{\mathtt{exp} +
+    /// \mathtt{blind}}}_{\mathtt{modulus}}$ like [`Uint::pow_mod_ct`].
+    ///
+    /// This is the hook for exponent blinding: if `blind` is a random multiple
+    /// of the order of `self` (e.g. `k ⋅ (p - 1)` for a prime modulus `p`),
+    /// the result equals `self^exp`, but the exponent actually processed is
+    /// different on every call. The sum is computed with one extra bit, so any
+    /// `exp` and `blind` can be used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is even and larger than one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U128};
+    /// # uint! {
+    /// let p = 0x1fffffffffffffff_U128; // 2^61 - 1
+    /// let secret = 0xdeadbeef_U128;
+    /// let blind = 0x1234_5678_U128 * (p - 1_U128);
+    /// assert_eq!(
+    ///     7_U128.pow_mod_ct_blinded(&secret, &blind, &p),
+    ///     7_U128.pow_mod(secret, p)
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn pow_mod_ct_blinded(self, exp: &Self, blind: &Self, modulus: &Self) -> Self {
+        if BITS == 0 || *modulus <= Self::ONE {
+            return Self::ZERO;
+        }
+        assert!(modulus.bit(0), "modulus must be odd");
+
+        // Montgomery set-up, `R = 2^(64 * LIMBS)`. This only depends on the
+        // modulus.
+        let inv = (0..6)
+            .fold(1_u64, |inv, _| {
+                inv.wrapping_mul(2_u64.wrapping_sub(modulus.limbs[0].wrapping_mul(inv)))
+            })
+            .wrapping_neg();
+        let one = (0..64 * LIMBS).fold(Self::ONE, |r, _| r.add_mod(r, *modulus));
+        let r2 = one.mul_mod(one, *modulus);
+        let base = self.reduce_mod(*modulus).mul_redc(r2, *modulus, inv);
+
+        let mut table = [one; 1 << WINDOW];
+        for i in 1..table.len() {
+            table[i] = table[i - 1].mul_redc(base, *modulus, inv);
+        }
+
+        // The exponent has `BITS + 1` bits, the top one is `carry`.
+        let (exp, carry) = exp.overflowing_add(*blind);
+        let windows = (BITS + 1).div_ceil(WINDOW);
+        let mut result = one;
+        for window in (0..windows).rev() {
+            let bit = window * WINDOW;
+            let mut index = exp.limbs.get(bit / 64).map_or(0, |limb| limb >> (bit % 64));
+            if (bit..bit + WINDOW).contains(&BITS) {
+                index |= u64::from(carry) << (BITS - bit);
+            }
+            let index = index & ((1 << WINDOW) - 1);
+
+            let mut entry = one;
+            for (i, candidate) in table.iter().enumerate() {
+                entry.conditional_assign(candidate, (i as u64).ct_eq(&index));
+            }
+            for _ in 0..WINDOW {
+                result = result.square_redc(*modulus, inv);
+            }
+            result = result.mul_redc(entry, *modulus, inv);
+        }
+        result.mul_redc(Self::ONE, *modulus, inv)
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> ConditionallySelectable for Uint<BITS, LIMBS> {
@@ -102,6 +293,122 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_inv_mod_ct_iterations() {
+        assert_eq!(Uint::<0, 0>::INV_MOD_CT_ITERATIONS, 0);
+        assert_eq!(Uint::<1, 1>::INV_MOD_CT_ITERATIONS, 1);
+        assert_eq!(Uint::<64, 1>::INV_MOD_CT_ITERATIONS, 127);
+        assert_eq!(Uint::<256, 4>::INV_MOD_CT_ITERATIONS, 511);
+    }
+
+    #[test]
+    fn test_inv_mod_ct() {
+        assert!(bool::from(
+            Uint::<0, 0>::ZERO.inv_mod_ct(Uint::ZERO).is_none()
+        ));
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+
+            // Too slow.
+            if LIMBS > 8 {
+                return;
+            }
+
+            proptest!(|(a: U, m: U)| {
+                let m = m | U::ONE;
+                assert_eq!(Option::<U>::from(a.inv_mod_ct(m)), a.inv_mod(m));
+                assert!(bool::from(a.inv_mod_ct(m ^ U::ONE).is_none()));
+            });
+        });
+    }
+
+    #[test]
+    fn test_inv_mod_ct_boundaries() {
+        use crate::aliases::U256;
+        let n = U256::from_str_radix(
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            16,
+        )
+        .unwrap();
+        for k in [
+            U256::ONE,
+            U256::from(2),
+            n - U256::ONE,
+            U256::MAX,
+            n + U256::ONE,
+        ] {
+            let k_inv = k.inv_mod_ct(n).unwrap();
+            assert_eq!(k.mul_mod(k_inv, n), U256::ONE);
+            assert_eq!(Some(k_inv), k.inv_mod(n));
+        }
+        for k in [U256::ZERO, n] {
+            assert!(bool::from(k.inv_mod_ct(n).is_none()));
+        }
+        assert!(bool::from(U256::ONE.inv_mod_ct(U256::ONE).is_none()));
+        assert!(bool::from(U256::ONE.inv_mod_ct(U256::ZERO).is_none()));
+        assert_eq!(
+            Option::from(U256::from(3).inv_mod_ct(U256::MAX)),
+            U256::from(3).inv_mod(U256::MAX)
+        );
+    }
+
+    #[test]
+    fn test_pow_mod_ct() {
+        assert_eq!(
+            Uint::<0, 0>::ZERO.pow_mod_ct(&Uint::ZERO, &Uint::ZERO),
+            Uint::ZERO
+        );
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+
+            // Too slow.
+            if LIMBS > 8 {
+                return;
+            }
+
+            proptest!(|(b: U, e: U, m: U)| {
+                let m = m | U::ONE;
+                assert_eq!(b.pow_mod_ct(&e, &m), b.pow_mod(e, m));
+            });
+            for m in [U::ZERO, U::ONE] {
+                assert_eq!(U::MAX.pow_mod_ct(&U::MAX, &m), U::ZERO);
+            }
+            assert_eq!(U::MAX.pow_mod_ct(&U::ZERO, &U::MAX), U::MAX.pow_mod(U::ZERO, U::MAX));
+        });
+    }
+
+    #[test]
+    fn test_pow_mod_ct_blinded() {
+        use crate::aliases::U128;
+
+        // The multiplicative group modulo the prime 2^61 - 1 has order 2^61 - 2.
+        let p = U128::from((1_u64 << 61) - 1);
+        proptest!(|(b in 1..(1_u64 << 61) - 1, e: u64, k in 0_u64..1 << 60)| {
+            let (b, e) = (U128::from(b), U128::from(e));
+            let blind = U128::from(k) * (p - U128::ONE);
+            assert_eq!(b.pow_mod_ct_blinded(&e, &blind, &p), b.pow_mod(e, p));
+        });
+
+        // The sum of exponent and blind overflows into the extra bit.
+        const_for!(BITS in [1, 2, 4, 63, 64, 65, 128] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let m = U::MAX;
+            proptest!(|(b: U, e: U, blind: U)| {
+                let expected = b.pow_mod(e, m).mul_mod(b.pow_mod(blind, m), m);
+                assert_eq!(b.pow_mod_ct_blinded(&e, &blind, &m), expected);
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be odd")]
+    fn test_pow_mod_ct_even() {
+        let _ = Uint::<64, 1>::from(3).pow_mod_ct(&Uint::from(5), &Uint::from(10));
+    }
+
     #[test]
     fn test_select() {
         const_for!(BITS in SIZES {
@@ -0,0 +1,78 @@
+//! Checks that parsing and formatting without the `alloc` feature would
+//! work, by counting heap allocations while they run.
+
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    str::FromStr,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+use ruint::{Uint, aliases::U256};
+use std::alloc::System;
+
+struct CountingAllocator;
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if COUNTING.load(Ordering::SeqCst) {
+            ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made by `f`. There is a single test in
+/// this file so that no other test thread allocates concurrently.
+fn allocations(f: impl FnOnce()) -> usize {
+    ALLOCATIONS.store(0, Ordering::SeqCst);
+    COUNTING.store(true, Ordering::SeqCst);
+    f();
+    COUNTING.store(false, Ordering::SeqCst);
+    ALLOCATIONS.load(Ordering::SeqCst)
+}
+
+#[test]
+fn test_does_not_allocate() {
+    let corpus = [
+        "0",
+        "1_000_000",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+        "0x0123456789abcdefABCDEF",
+        "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "0o17",
+        "0b1_01",
+        "12a",
+        "0x",
+    ];
+    let mut results = [None; 10];
+    let count = allocations(|| {
+        for (src, result) in corpus.iter().zip(&mut results) {
+            *result = Some(U256::from_str(src));
+        }
+        let _ = U256::from_str_radix("zz", 36);
+        let _ = U256::from_dec_str("42");
+        let _ = U256::from_hex_str("2a");
+        let _ = Uint::<1000, 16>::from_str_radix("A+/", 64);
+    });
+    assert_eq!(count, 0);
+    assert_eq!(results[1], Some(Ok(U256::from(1_000_000))));
+    assert!(results[3].unwrap().is_err());
+    assert!(results[8].unwrap().is_err());
+
+    let mut buf = [0_u8; 80];
+    let count = allocations(|| {
+        let _ = U256::MAX.to_dec_str(&mut buf);
+        let _ = U256::MAX.to_hex_str(&mut buf);
+    });
+    assert_eq!(count, 0);
+}